@@ -0,0 +1,25 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::address_filter::AddressFilter,
+    solana_sdk::{borsh::try_from_slice_unchecked, pubkey::Pubkey},
+};
+
+pub fn cmd_check_address_filter(
+    config: &Config,
+    file: String,
+    address: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let address_filter: AddressFilter = try_from_slice_unchecked(&std::fs::read(&file)?)?;
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), address_filter.network);
+    }
+
+    if address_filter.contains(address) {
+        println!("{}: {}", "May be reported".yellow(), address);
+    } else {
+        println!("{}: {}", "Not reported".green(), address);
+    }
+
+    Ok(())
+}
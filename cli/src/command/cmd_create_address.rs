@@ -2,14 +2,14 @@ use {
     crate::{tools::*, Config},
     colored::*,
     hapi_core_solana::{
-        instruction,
+        instruction::{self, AddressReport},
         state::{
             address::get_address_address, case::get_case_address, community::get_community_address,
-            enums::Category, network::get_network_address,
+            network::get_network_address,
         },
     },
     solana_client::rpc_client::RpcClient,
-    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    solana_sdk::signature::Signer,
 };
 
 pub fn cmd_create_address(
@@ -17,10 +17,8 @@ pub fn cmd_create_address(
     config: &Config,
     community_name: String,
     network_name: String,
-    address: &Pubkey,
     case_id: u64,
-    risk: u8,
-    category: Category,
+    report: &AddressReport,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let community_account = get_community_address(&community_name);
     let network_account = &get_network_address(&community_account, &network_name);
@@ -35,25 +33,21 @@ pub fn cmd_create_address(
 
     assert_is_existing_account(rpc_client, &case_address)?;
 
-    let address_address = get_address_address(&network_account, address);
+    let address_address = get_address_address(&network_account, &report.address);
 
     assert_is_empty_account(rpc_client, &address_address)?;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[instruction::create_address(
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::create_address(
             &config.keypair.pubkey(),
             &format!("{}/{}", &community_name, &network_name),
-            address,
             case_id,
-            risk,
-            category,
+            report,
         )
-        .unwrap()],
-        Some(&config.keypair.pubkey()),
-    );
-    let blockhash = rpc_client.get_recent_blockhash()?.0;
-    transaction.try_sign(&[&config.keypair], blockhash)?;
-    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+        .unwrap(),
+    )?;
 
     println!("{}: {}", "Address reported".green(), address_address);
 
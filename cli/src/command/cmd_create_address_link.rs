@@ -0,0 +1,60 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{
+            address_link::get_address_link_address, community::get_community_address,
+            enums::AddressLinkKind, network::get_network_address,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{pubkey::Pubkey, signature::Signer},
+};
+
+pub fn cmd_create_address_link(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    source: &Pubkey,
+    destination: &Pubkey,
+    kind: AddressLinkKind,
+    confidence: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = &get_network_address(&community_account, &network_name);
+
+    assert_is_existing_account(rpc_client, &network_account)?;
+
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), network_name);
+    }
+
+    let address_link_address =
+        get_address_link_address(&network_account, source, destination, kind);
+
+    assert_is_empty_account(rpc_client, &address_link_address)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::create_address_link(
+            &config.keypair.pubkey(),
+            &format!("{}/{}", &community_name, &network_name),
+            source,
+            destination,
+            kind,
+            confidence,
+        )
+        .unwrap(),
+    )?;
+
+    println!(
+        "{}: {}",
+        "Address link created".green(),
+        address_link_address
+    );
+
+    Ok(())
+}
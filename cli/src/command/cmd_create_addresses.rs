@@ -0,0 +1,97 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction::{self, AddressReport},
+        state::{case::get_case_address, community::get_community_address, enums::CategorySet},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{clock::UnixTimestamp, pubkey::Pubkey, signature::Signer},
+    std::str::FromStr,
+};
+
+pub fn cmd_create_addresses(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    case_id: u64,
+    file: String,
+    expires_at: Option<UnixTimestamp>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let case_address = get_case_address(&community_account, &case_id.to_le_bytes());
+
+    assert_is_existing_account(rpc_client, &case_address)?;
+
+    let addresses = address_reports_from_string(&std::fs::read_to_string(&file)?, expires_at)?;
+    if config.verbose {
+        println!("{}: {}", "Addresses".bright_black(), addresses.len());
+    }
+
+    let instructions = instruction::create_addresses_chunked(
+        &config.keypair.pubkey(),
+        &format!("{}/{}", &community_name, &network_name),
+        case_id,
+        &addresses,
+    )?;
+
+    let mut reported = 0;
+    for (instruction, chunk) in instructions
+        .into_iter()
+        .zip(addresses.chunks(instruction::MAX_ADDRESSES_PER_INSTRUCTION))
+    {
+        send_instruction(rpc_client, config, instruction)?;
+
+        reported += chunk.len();
+        println!(
+            "{}: {}/{}",
+            "Addresses reported".green(),
+            reported,
+            addresses.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Parses address reports, one per line: `<address> <risk> [<category>,...] [<primary category>]`.
+/// Blank lines and lines starting with `#` are skipped.
+pub fn address_reports_from_string(
+    input: &str,
+    expires_at: Option<UnixTimestamp>,
+) -> Result<Vec<AddressReport>, Box<dyn std::error::Error>> {
+    let mut addresses = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 2 || fields.len() > 4 {
+            return Err(format!("Invalid address report on line {}", index + 1).into());
+        }
+
+        let mut categories: CategorySet = 0u32;
+        if let Some(list) = fields.get(2) {
+            for category in list.split(',') {
+                categories = categories | category_from_string(category)?;
+            }
+        }
+
+        addresses.push(AddressReport {
+            address: Pubkey::from_str(fields[0])?,
+            risk: fields[1].parse()?,
+            categories,
+            primary_category: fields
+                .get(3)
+                .map(|category| category_from_string(category))
+                .transpose()?,
+            expires_at,
+        });
+    }
+
+    Ok(addresses)
+}
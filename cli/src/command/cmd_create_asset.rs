@@ -0,0 +1,55 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction::{self, AssetReport},
+        state::{
+            asset::get_asset_address, case::get_case_address, community::get_community_address,
+            network::get_network_address,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::signature::Signer,
+};
+
+pub fn cmd_create_asset(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    case_id: u64,
+    report: &AssetReport,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = &get_network_address(&community_account, &network_name);
+
+    assert_is_existing_account(rpc_client, &network_account)?;
+
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), network_name);
+    }
+
+    let case_address = get_case_address(&community_account, &case_id.to_le_bytes());
+
+    assert_is_existing_account(rpc_client, &case_address)?;
+
+    let asset_address = get_asset_address(&network_account, &report.asset, &report.token_id);
+
+    assert_is_empty_account(rpc_client, &asset_address)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::create_asset(
+            &config.keypair.pubkey(),
+            &format!("{}/{}", &community_name, &network_name),
+            case_id,
+            report,
+        )
+        .unwrap(),
+    )?;
+
+    println!("{}: {}", "Asset reported".green(), asset_address);
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use hapi_core_solana::state::enums::CaseStatus;
 
 use {
-    crate::{tools::assert_is_empty_account, Config},
+    crate::{
+        tools::{assert_is_empty_account, send_instruction},
+        Config,
+    },
     colored::*,
     hapi_core_solana::{
         instruction,
@@ -12,7 +15,7 @@ use {
         },
     },
     solana_client::rpc_client::RpcClient,
-    solana_sdk::{borsh::try_from_slice_unchecked, signature::Signer, transaction::Transaction},
+    solana_sdk::{borsh::try_from_slice_unchecked, clock::UnixTimestamp, signature::Signer},
 };
 
 pub fn cmd_create_case(
@@ -22,6 +25,7 @@ pub fn cmd_create_case(
     case_name: String,
     status: CaseStatus,
     categories: CategorySet,
+    expires_at: Option<UnixTimestamp>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let community_account = get_community_address(&community_name);
     let community_data = rpc_client.get_account_data(&community_account)?;
@@ -39,21 +43,20 @@ pub fn cmd_create_case(
 
     assert_is_empty_account(rpc_client, &case_address)?;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[instruction::create_case(
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::create_case(
             &config.keypair.pubkey(),
             &community_name,
             community.next_case_id,
             &case_name,
             status,
             &categories,
+            expires_at,
         )
-        .unwrap()],
-        Some(&config.keypair.pubkey()),
-    );
-    let blockhash = rpc_client.get_recent_blockhash()?.0;
-    transaction.try_sign(&[&config.keypair], blockhash)?;
-    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+        .unwrap(),
+    )?;
 
     println!("{}: {}", "Case reported".green(), case_address);
 
@@ -0,0 +1,61 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{community::get_community_address, entity::get_entity_address, enums::Category},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::signature::Signer,
+};
+
+pub fn cmd_create_entity(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    entity_name: String,
+    website: String,
+    category: Category,
+    risk: u8,
+    parent_name: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if config.verbose {
+        println!("{}: {}", "Community".bright_black(), community_name);
+    }
+    let community_account = get_community_address(&community_name);
+    assert_is_existing_account(rpc_client, &community_account)?;
+
+    if let Some(parent_name) = &parent_name {
+        let parent_account = get_entity_address(&community_account, parent_name);
+        assert_is_existing_account(rpc_client, &parent_account)?;
+        if config.verbose {
+            println!(
+                "{}: {}",
+                "Parent entity account".bright_black(),
+                parent_account
+            );
+        }
+    }
+
+    let entity_account = get_entity_address(&community_account, &entity_name);
+    assert_is_empty_account(rpc_client, &entity_account)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::create_entity(
+            &config.keypair.pubkey(),
+            &community_name,
+            &entity_name,
+            &website,
+            category,
+            risk,
+            parent_name.as_deref(),
+        )
+        .unwrap(),
+    )?;
+
+    println!("{}: {}", "Entity created".green(), entity_account);
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use {
     crate::{
-        tools::{assert_is_empty_account, assert_is_existing_account},
+        tools::{assert_is_empty_account, assert_is_existing_account, send_instruction},
         Config,
     },
     colored::*,
@@ -8,7 +8,7 @@ use {
         instruction, state::community::get_community_address, state::network::get_network_address,
     },
     solana_client::rpc_client::RpcClient,
-    solana_sdk::{signature::Signer, transaction::Transaction},
+    solana_sdk::signature::Signer,
 };
 
 pub fn cmd_create_network(
@@ -21,23 +21,18 @@ pub fn cmd_create_network(
         println!("{}: {}", "Network".bright_black(), network_name.bold());
     }
 
+    let create_network_ix = instruction::create_network(
+        &config.keypair.pubkey(),
+        &format!("{}/{}", &community_name, &network_name),
+    )?;
+
     let community_account = get_community_address(&community_name);
     let network_account = get_network_address(&community_account, &network_name);
 
     assert_is_existing_account(rpc_client, &community_account)?;
     assert_is_empty_account(rpc_client, &network_account)?;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[instruction::create_network(
-            &config.keypair.pubkey(),
-            &format!("{}/{}", &community_name, &network_name),
-        )
-        .unwrap()],
-        Some(&config.keypair.pubkey()),
-    );
-    let blockhash = rpc_client.get_recent_blockhash()?.0;
-    transaction.try_sign(&[&config.keypair], blockhash)?;
-    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+    send_instruction(rpc_client, config, create_network_ix)?;
 
     println!("{}: {}", "Network created".green(), network_account);
 
@@ -1,6 +1,6 @@
 use {
     crate::{
-        tools::{assert_is_empty_account, assert_is_existing_account},
+        tools::{assert_is_empty_account, assert_is_existing_account, send_instruction},
         Config,
     },
     colored::*,
@@ -14,8 +14,7 @@ use {
     },
     solana_client::rpc_client::RpcClient,
     solana_sdk::{
-        borsh::try_from_slice_unchecked, pubkey::Pubkey, signature::Signer,
-        transaction::Transaction,
+        borsh::try_from_slice_unchecked, clock::UnixTimestamp, pubkey::Pubkey, signature::Signer,
     },
 };
 
@@ -26,6 +25,7 @@ pub fn cmd_create_reporter(
     reporter_pubkey: &Pubkey,
     name: String,
     reporter_type: ReporterType,
+    active_until: Option<UnixTimestamp>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let community_account = get_community_address(&community_name);
     let community_data = rpc_client.get_account_data(&community_account)?;
@@ -41,20 +41,19 @@ pub fn cmd_create_reporter(
 
     assert_is_empty_account(rpc_client, &reporter_address)?;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[instruction::create_reporter(
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::create_reporter(
             &config.keypair.pubkey(),
             &community_name,
             &name,
             reporter_pubkey,
             reporter_type,
+            active_until,
         )
-        .unwrap()],
-        Some(&config.keypair.pubkey()),
-    );
-    let blockhash = rpc_client.get_recent_blockhash()?.0;
-    transaction.try_sign(&[&config.keypair], blockhash)?;
-    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+        .unwrap(),
+    )?;
 
     println!("{}: {}", "Reporter added".green(), reporter_address);
 
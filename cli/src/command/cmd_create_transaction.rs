@@ -0,0 +1,59 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction::{self, TransactionReport},
+        state::{
+            case::get_case_address, community::get_community_address, network::get_network_address,
+            transaction::get_transaction_address,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::signature::Signer,
+};
+
+pub fn cmd_create_transaction(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    case_id: u64,
+    report: &TransactionReport,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = &get_network_address(&community_account, &network_name);
+
+    assert_is_existing_account(rpc_client, &network_account)?;
+
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), network_name);
+    }
+
+    let case_address = get_case_address(&community_account, &case_id.to_le_bytes());
+
+    assert_is_existing_account(rpc_client, &case_address)?;
+
+    let transaction_address = get_transaction_address(&network_account, &report.tx_hash);
+
+    assert_is_empty_account(rpc_client, &transaction_address)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::create_transaction(
+            &config.keypair.pubkey(),
+            &format!("{}/{}", &community_name, &network_name),
+            case_id,
+            report,
+        )
+        .unwrap(),
+    )?;
+
+    println!(
+        "{}: {}",
+        "Transaction reported".green(),
+        transaction_address
+    );
+
+    Ok(())
+}
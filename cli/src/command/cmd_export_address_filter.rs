@@ -0,0 +1,43 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::{
+        address_filter::{get_address_filter_address, AddressFilter},
+        community::get_community_address,
+        network::get_network_address,
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::borsh::try_from_slice_unchecked,
+};
+
+pub fn cmd_export_address_filter(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    file: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    let address_filter_account = get_address_filter_address(&network_account);
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Address filter account".bright_black(),
+            address_filter_account
+        );
+    }
+
+    let address_filter_data = rpc_client.get_account_data(&address_filter_account)?;
+    let address_filter: AddressFilter = try_from_slice_unchecked(&address_filter_data)?;
+    std::fs::write(&file, &address_filter_data)?;
+
+    println!(
+        "{}: {} ({} addresses)",
+        "Address filter exported".green(),
+        file,
+        address_filter.count
+    );
+
+    Ok(())
+}
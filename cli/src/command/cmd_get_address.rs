@@ -3,7 +3,7 @@ use {
     colored::*,
     hapi_core_solana::state::{
         address::{get_address_address, Address},
-        community::get_community_address,
+        community::{get_community_address, Community},
         network::get_network_address,
     },
     solana_client::rpc_client::RpcClient,
@@ -34,5 +34,16 @@ pub fn cmd_get_address(
     let address: Address = try_from_slice_unchecked(&address_data)?;
     println!("{:#?}", address);
 
+    let community_data = rpc_client.get_account_data(&community_account)?;
+    let community: Community = try_from_slice_unchecked(&community_data)?;
+
+    let now = chrono::Utc::now().timestamp();
+    println!("{}: {}", "Stored risk".bright_black(), address.risk);
+    println!(
+        "{}: {}",
+        "Effective risk".bright_black(),
+        address.effective_risk(now, community.risk_decay_period)
+    );
+
     Ok(())
 }
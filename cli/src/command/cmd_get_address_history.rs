@@ -0,0 +1,65 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::{
+        address::{get_address_address, Address},
+        address_revision::{get_address_revision_address, AddressRevision},
+        community::get_community_address,
+        network::get_network_address,
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, pubkey::Pubkey},
+};
+
+/// Maximum number of accounts to fetch in a single request
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+pub fn cmd_get_address_history(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    address: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    let address_account = get_address_address(&network_account, address);
+    if config.verbose {
+        println!("{}: {}", "Address account".bright_black(), address_account);
+    }
+
+    let address_data = rpc_client.get_account_data(&address_account)?;
+    let address_data: Address = try_from_slice_unchecked(&address_data)?;
+
+    let revision_accounts: Vec<Pubkey> = (0..address_data.revision)
+        .map(|revision| get_address_revision_address(&address_account, revision))
+        .collect();
+
+    for chunk in revision_accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        for (pubkey, account) in chunk.iter().zip(rpc_client.get_multiple_accounts(chunk)?) {
+            let account = account.ok_or(format!("Missing address revision {}", pubkey))?;
+            let revision: AddressRevision = try_from_slice_unchecked(&account.data)?;
+            println!(
+                "{} #{} {}: {} {}: {}\n{:#?}\n",
+                "Revision".green(),
+                revision.revision,
+                "replaced at".bright_black(),
+                revision.updated_at,
+                "by".bright_black(),
+                revision.updater_key,
+                revision
+            );
+        }
+    }
+
+    println!(
+        "{} #{} {}: {}\n{:#?}",
+        "Current".green(),
+        address_data.revision,
+        "updated at".bright_black(),
+        address_data.updated_at,
+        address_data
+    );
+
+    Ok(())
+}
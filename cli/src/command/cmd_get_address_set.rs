@@ -0,0 +1,33 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::{
+        address_set::{get_address_set_address, AddressSet},
+        case::get_case_address,
+        community::get_community_address,
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::borsh::try_from_slice_unchecked,
+};
+
+pub fn cmd_get_address_set(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    case_id: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let case_account = get_case_address(&community_account, &case_id.to_le_bytes());
+    let address_set_account = get_address_set_address(&case_account);
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Address set account".bright_black(),
+            address_set_account
+        );
+    }
+    let address_set_data = rpc_client.get_account_data(&address_set_account)?;
+    let address_set: AddressSet = try_from_slice_unchecked(&address_set_data)?;
+    println!("{:#?}", address_set);
+    Ok(())
+}
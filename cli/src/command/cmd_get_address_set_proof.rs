@@ -0,0 +1,66 @@
+use {
+    crate::{command::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        state::{
+            address_set::{get_address_set_address, AddressSet},
+            case::get_case_address,
+            community::get_community_address,
+            network::get_network_address,
+        },
+        tools::merkle::{get_merkle_proof, verify_merkle_proof},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, bs58, pubkey::Pubkey},
+};
+
+pub fn cmd_get_address_set_proof(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    case_id: u64,
+    file: String,
+    address: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    let case_account = get_case_address(&community_account, &case_id.to_le_bytes());
+    let address_set_account = get_address_set_address(&case_account);
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Address set account".bright_black(),
+            address_set_account
+        );
+    }
+
+    let address_set_data = rpc_client.get_account_data(&address_set_account)?;
+    let address_set: AddressSet = try_from_slice_unchecked(&address_set_data)?;
+
+    let entries = address_set_entries_from_string(&std::fs::read_to_string(&file)?)?;
+    let leaves = address_set_leaves(&network_account, &entries);
+
+    let index = entries
+        .iter()
+        .position(|entry| entry.address == *address)
+        .ok_or(format!("Address {} is not in the file", address))?;
+    let proof = get_merkle_proof(&leaves, index).unwrap();
+
+    if !verify_merkle_proof(&leaves[index], &proof, &address_set.root) {
+        return Err("Address set file doesn't match the committed root".into());
+    }
+
+    println!("{}: {}", "Risk".bright_black(), entries[index].risk);
+    println!(
+        "{}: {:?}",
+        "Category".bright_black(),
+        entries[index].category
+    );
+    println!("{}:", "Proof".green());
+    for node in proof {
+        println!("{}", bs58::encode(node).into_string());
+    }
+
+    Ok(())
+}
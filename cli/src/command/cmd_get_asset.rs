@@ -0,0 +1,39 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::{
+        asset::{get_asset_address, Asset, TokenId},
+        community::get_community_address,
+        network::get_network_address,
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, pubkey::Pubkey},
+};
+
+pub fn cmd_get_asset(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    asset: &Pubkey,
+    token_id: &Option<TokenId>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), network_name);
+    }
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    if config.verbose {
+        println!("{}: {}", "Network account".bright_black(), network_account);
+    }
+
+    let asset_account = get_asset_address(&network_account, asset, token_id);
+    if config.verbose {
+        println!("{}: {}", "Asset account".bright_black(), asset_account);
+    }
+    let asset_data = rpc_client.get_account_data(&asset_account)?;
+    let asset: Asset = try_from_slice_unchecked(&asset_data)?;
+    println!("{:#?}", asset);
+
+    Ok(())
+}
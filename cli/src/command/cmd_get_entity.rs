@@ -0,0 +1,31 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::{
+        community::get_community_address,
+        entity::{get_entity_address, Entity},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::borsh::try_from_slice_unchecked,
+};
+
+pub fn cmd_get_entity(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    entity_name: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if config.verbose {
+        println!("{}: {}", "Community".bright_black(), community_name);
+    }
+    let community_account = get_community_address(&community_name);
+    let entity_account = get_entity_address(&community_account, &entity_name);
+    if config.verbose {
+        println!("{}: {}", "Entity account".bright_black(), entity_account);
+    }
+    let entity_data = rpc_client.get_account_data(&entity_account)?;
+    let entity: Entity = try_from_slice_unchecked(&entity_data)?;
+    println!("{:#?}", entity);
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::{
+        community::{get_community_address, Community},
+        reporter::{get_reporter_address, Reporter},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, pubkey::Pubkey},
+};
+
+pub fn cmd_get_reporter_stats(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    reporter_pubkey: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Community account".bright_black(),
+            community_account
+        );
+    }
+
+    let reporter_account = get_reporter_address(&community_account, &reporter_pubkey);
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Reporter address".bright_black(),
+            reporter_account
+        );
+    }
+
+    let reporter_data = rpc_client.get_account_data(&reporter_account)?;
+    let reporter: Reporter = try_from_slice_unchecked(&reporter_data)?;
+
+    println!("{}: {}", "Reporter".bright_black(), reporter.name);
+    println!(
+        "{}: {}",
+        "Cases created".bright_black(),
+        reporter.cases_created
+    );
+    println!(
+        "{}: {}",
+        "Addresses created".bright_black(),
+        reporter.addresses_created
+    );
+    println!(
+        "{}: {}",
+        "Addresses updated".bright_black(),
+        reporter.addresses_updated
+    );
+    println!(
+        "{}: {}",
+        "Disputes lost".bright_black(),
+        reporter.disputes_lost
+    );
+    println!(
+        "{}: {}",
+        "Last activity".bright_black(),
+        reporter.last_activity_at
+    );
+    println!("{}: {}", "Reputation".bright_black(), reporter.reputation());
+
+    let community_data = rpc_client.get_account_data(&community_account)?;
+    let community: Community = try_from_slice_unchecked(&community_data)?;
+
+    let now = chrono::Utc::now().timestamp();
+    match reporter.remaining_quota(&community, now) {
+        Some(remaining) => println!(
+            "{}: {} of {}",
+            "Remaining quota".bright_black(),
+            remaining,
+            community.report_quota
+        ),
+        None => println!("{}: unlimited", "Remaining quota".bright_black()),
+    }
+
+    Ok(())
+}
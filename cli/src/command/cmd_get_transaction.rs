@@ -0,0 +1,42 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::state::{
+        community::get_community_address,
+        network::get_network_address,
+        transaction::{get_transaction_address, Transaction, TransactionHash},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::borsh::try_from_slice_unchecked,
+};
+
+pub fn cmd_get_transaction(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    tx_hash: &TransactionHash,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), network_name);
+    }
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    if config.verbose {
+        println!("{}: {}", "Network account".bright_black(), network_account);
+    }
+
+    let transaction_account = get_transaction_address(&network_account, tx_hash);
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Transaction account".bright_black(),
+            transaction_account
+        );
+    }
+    let transaction_data = rpc_client.get_account_data(&transaction_account)?;
+    let transaction: Transaction = try_from_slice_unchecked(&transaction_data)?;
+    println!("{:#?}", transaction);
+
+    Ok(())
+}
@@ -4,8 +4,10 @@ use {
     hapi_core_solana::{
         id,
         state::{
-            address::Address, case::Case, enums::HapiAccountType, network::Network,
-            reporter::Reporter,
+            address::Address, address_link::AddressLink, address_revision::AddressRevision,
+            address_set::AddressSet, asset::Asset, case::Case, community::Community,
+            entity::Entity, enums::HapiAccountType, network::Network, reporter::Reporter,
+            transaction::Transaction,
         },
     },
     solana_client::rpc_client::RpcClient,
@@ -23,6 +25,9 @@ pub fn cmd_list_accounts(
             .split_at(std::mem::size_of::<HapiAccountType>());
         let account_type: HapiAccountType = try_from_slice_unchecked(account_type).unwrap();
         let account_data: Box<dyn std::fmt::Debug> = match account_type {
+            HapiAccountType::Community => {
+                Box::new(try_from_slice_unchecked::<Community>(&account.data)?)
+            }
             HapiAccountType::Reporter => {
                 Box::new(try_from_slice_unchecked::<Reporter>(&account.data)?)
             }
@@ -33,6 +38,20 @@ pub fn cmd_list_accounts(
             HapiAccountType::Address => {
                 Box::new(try_from_slice_unchecked::<Address>(&account.data)?)
             }
+            HapiAccountType::Entity => Box::new(try_from_slice_unchecked::<Entity>(&account.data)?),
+            HapiAccountType::Transaction => {
+                Box::new(try_from_slice_unchecked::<Transaction>(&account.data)?)
+            }
+            HapiAccountType::Asset => Box::new(try_from_slice_unchecked::<Asset>(&account.data)?),
+            HapiAccountType::AddressLink => {
+                Box::new(try_from_slice_unchecked::<AddressLink>(&account.data)?)
+            }
+            HapiAccountType::AddressRevision => {
+                Box::new(try_from_slice_unchecked::<AddressRevision>(&account.data)?)
+            }
+            HapiAccountType::AddressSet => {
+                Box::new(try_from_slice_unchecked::<AddressSet>(&account.data)?)
+            }
             _ => unreachable!("Unknown account type"),
         };
 
@@ -0,0 +1,103 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::{
+        id,
+        state::{
+            address_link::{
+                AddressLink, ADDRESS_LINK_DESTINATION_OFFSET, ADDRESS_LINK_NETWORK_OFFSET,
+                ADDRESS_LINK_SOURCE_OFFSET,
+            },
+            community::get_community_address,
+            enums::HapiAccountType,
+            network::get_network_address,
+        },
+    },
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    },
+    solana_sdk::{account::Account, borsh::try_from_slice_unchecked, bs58, pubkey::Pubkey},
+};
+
+pub fn cmd_list_address_links(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    address: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    if config.verbose {
+        println!("{}: {}", "Network account".bright_black(), network_account);
+    }
+
+    let outgoing = get_address_links(
+        rpc_client,
+        &network_account,
+        Some((ADDRESS_LINK_SOURCE_OFFSET, address)),
+    )?;
+    println!("{} ({})", "Outgoing links".green(), outgoing.len());
+    for (pubkey, account) in outgoing {
+        let link: AddressLink = try_from_slice_unchecked(&account.data)?;
+        println!("{}\n{:#?}\n", pubkey.to_string().blue(), link);
+    }
+
+    let incoming = get_address_links(
+        rpc_client,
+        &network_account,
+        Some((ADDRESS_LINK_DESTINATION_OFFSET, address)),
+    )?;
+    println!("{} ({})", "Incoming links".green(), incoming.len());
+    for (pubkey, account) in incoming {
+        let link: AddressLink = try_from_slice_unchecked(&account.data)?;
+        println!("{}\n{:#?}\n", pubkey.to_string().blue(), link);
+    }
+
+    Ok(())
+}
+
+/// Fetches address link accounts of the network, optionally only those with an address at the given offset
+pub fn get_address_links(
+    rpc_client: &RpcClient,
+    network_account: &Pubkey,
+    endpoint: Option<(usize, &Pubkey)>,
+) -> Result<Vec<(Pubkey, Account)>, Box<dyn std::error::Error>> {
+    let mut filters = vec![
+        RpcFilterType::Memcmp(Memcmp {
+            offset: 0,
+            bytes: MemcmpEncodedBytes::Binary(
+                bs58::encode([HapiAccountType::AddressLink as u8]).into_string(),
+            ),
+            encoding: None,
+        }),
+        RpcFilterType::Memcmp(Memcmp {
+            offset: ADDRESS_LINK_NETWORK_OFFSET,
+            bytes: MemcmpEncodedBytes::Binary(network_account.to_string()),
+            encoding: None,
+        }),
+    ];
+
+    if let Some((offset, address)) = endpoint {
+        filters.push(RpcFilterType::Memcmp(Memcmp {
+            offset,
+            bytes: MemcmpEncodedBytes::Binary(address.to_string()),
+            encoding: None,
+        }));
+    }
+
+    Ok(rpc_client.get_program_accounts_with_config(
+        &id(),
+        RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: None,
+        },
+    )?)
+}
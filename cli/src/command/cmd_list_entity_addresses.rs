@@ -0,0 +1,69 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::{
+        id,
+        state::{
+            address::{Address, ADDRESS_ENTITY_OFFSET},
+            community::get_community_address,
+            entity::get_entity_address,
+            enums::HapiAccountType,
+        },
+    },
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    },
+    solana_sdk::{borsh::try_from_slice_unchecked, bs58},
+};
+
+pub fn cmd_list_entity_addresses(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    entity_name: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let entity_account = get_entity_address(&community_account, &entity_name);
+    if config.verbose {
+        println!("{}: {}", "Entity account".bright_black(), entity_account);
+    }
+
+    // Address accounts with `entity` set to `Some(entity_account)`
+    let mut entity_bytes = vec![1u8];
+    entity_bytes.extend_from_slice(entity_account.as_ref());
+
+    let accounts = rpc_client.get_program_accounts_with_config(
+        &id(),
+        RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::Memcmp(Memcmp {
+                    offset: 0,
+                    bytes: MemcmpEncodedBytes::Binary(
+                        bs58::encode([HapiAccountType::Address as u8]).into_string(),
+                    ),
+                    encoding: None,
+                }),
+                RpcFilterType::Memcmp(Memcmp {
+                    offset: ADDRESS_ENTITY_OFFSET,
+                    bytes: MemcmpEncodedBytes::Binary(bs58::encode(entity_bytes).into_string()),
+                    encoding: None,
+                }),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: None,
+        },
+    )?;
+
+    for (pubkey, account) in accounts {
+        let address: Address = try_from_slice_unchecked(&account.data)?;
+        println!("{}\n{:#?}\n", pubkey.to_string().blue(), address);
+    }
+
+    Ok(())
+}
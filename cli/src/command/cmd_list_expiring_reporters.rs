@@ -0,0 +1,85 @@
+use {
+    crate::Config,
+    colored::*,
+    hapi_core_solana::{
+        id,
+        state::{
+            community::get_community_address,
+            enums::HapiAccountType,
+            reporter::{get_reporter_address, Reporter},
+        },
+    },
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    },
+    solana_sdk::{borsh::try_from_slice_unchecked, bs58},
+};
+
+pub fn cmd_list_expiring_reporters(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    within: i64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Community account".bright_black(),
+            community_account
+        );
+    }
+
+    let accounts = rpc_client.get_program_accounts_with_config(
+        &id(),
+        RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp {
+                offset: 0,
+                bytes: MemcmpEncodedBytes::Binary(
+                    bs58::encode([HapiAccountType::Reporter as u8]).into_string(),
+                ),
+                encoding: None,
+            })]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: None,
+        },
+    )?;
+
+    let deadline = chrono::Utc::now().timestamp().saturating_add(within);
+
+    // Reporter accounts don't store the community, so match them by address
+    let mut reporters = vec![];
+    for (pubkey, account) in accounts {
+        let reporter: Reporter = try_from_slice_unchecked(&account.data)?;
+        if pubkey != get_reporter_address(&community_account, &reporter.key) {
+            continue;
+        }
+        if let Some(active_until) = reporter.active_until {
+            if active_until <= deadline {
+                reporters.push((active_until, reporter));
+            }
+        }
+    }
+    reporters.sort_by_key(|(active_until, _)| *active_until);
+
+    println!("{} ({})", "Expiring reporters".green(), reporters.len());
+    for (active_until, reporter) in reporters {
+        let active_until = chrono::NaiveDateTime::from_timestamp_opt(active_until, 0)
+            .map_or_else(|| active_until.to_string(), |time| time.to_string());
+        println!(
+            "{} {} ({:?}) {}",
+            active_until.bright_black(),
+            reporter.key.to_string().blue(),
+            reporter.reporter_type,
+            reporter.name
+        );
+    }
+
+    Ok(())
+}
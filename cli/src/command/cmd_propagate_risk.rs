@@ -0,0 +1,276 @@
+use {
+    crate::{command::get_address_links, Config},
+    colored::*,
+    hapi_core_solana::state::{
+        address::{get_address_address, Address},
+        address_link::AddressLink,
+        community::{get_community_address, Community},
+        network::get_network_address,
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, clock::UnixTimestamp, pubkey::Pubkey},
+    std::collections::{HashMap, HashSet},
+};
+
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Risk propagated to an unreported address
+#[derive(Debug)]
+pub struct PropagatedRisk {
+    pub address: Pubkey,
+    pub risk: f64,
+    pub depth: u8,
+    pub origin: Pubkey,
+}
+
+pub fn cmd_propagate_risk(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    decay: u8,
+    max_depth: u8,
+    min_risk: f64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    if config.verbose {
+        println!("{}: {}", "Network account".bright_black(), network_account);
+    }
+
+    let links = get_address_links(rpc_client, &network_account, None)?
+        .into_iter()
+        .map(|(_, account)| try_from_slice_unchecked::<AddressLink>(&account.data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let community_data = rpc_client.get_account_data(&community_account)?;
+    let community: Community = try_from_slice_unchecked(&community_data)?;
+
+    let addresses: HashSet<Pubkey> = links
+        .iter()
+        .flat_map(|link| vec![link.source, link.destination])
+        .collect();
+    let reported = get_address_risks(
+        rpc_client,
+        &network_account,
+        &addresses.into_iter().collect::<Vec<_>>(),
+        chrono::Utc::now().timestamp(),
+        community.risk_decay_period,
+    )?;
+
+    if config.verbose {
+        println!(
+            "{}: {} links, {} reported addresses",
+            "Graph".bright_black(),
+            links.len(),
+            reported.len()
+        );
+    }
+
+    let report = propagate_risk(&reported, &links, decay, max_depth);
+
+    for (rank, item) in report.iter().filter(|r| r.risk >= min_risk).enumerate() {
+        println!(
+            "{:>4}. {} {}: {:.2} {}: {} {}: {}",
+            rank + 1,
+            item.address.to_string().blue(),
+            "risk".bright_black(),
+            item.risk,
+            "depth".bright_black(),
+            item.depth,
+            "via".bright_black(),
+            item.origin,
+        );
+    }
+
+    Ok(())
+}
+
+/// Spreads risk of reported addresses over address links in both directions.
+///
+/// Each hop multiplies the risk by `decay` and the link confidence (both in percent),
+/// an unreported address keeps the highest risk among the paths of at most `max_depth` hops.
+/// Returns unreported addresses ranked by propagated risk.
+pub fn propagate_risk(
+    reported: &HashMap<Pubkey, u8>,
+    links: &[AddressLink],
+    decay: u8,
+    max_depth: u8,
+) -> Vec<PropagatedRisk> {
+    let mut neighbors: HashMap<Pubkey, Vec<(Pubkey, u8)>> = HashMap::new();
+    for link in links {
+        neighbors
+            .entry(link.source)
+            .or_default()
+            .push((link.destination, link.confidence));
+        neighbors
+            .entry(link.destination)
+            .or_default()
+            .push((link.source, link.confidence));
+    }
+
+    let mut propagated: HashMap<Pubkey, PropagatedRisk> = HashMap::new();
+    let mut frontier: Vec<(Pubkey, f64, Pubkey)> = reported
+        .iter()
+        .map(|(address, risk)| (*address, *risk as f64, *address))
+        .collect();
+
+    for depth in 1..=max_depth {
+        let mut next_frontier = vec![];
+
+        for (address, risk, origin) in frontier {
+            for (neighbor, confidence) in neighbors.get(&address).into_iter().flatten() {
+                if reported.contains_key(neighbor) {
+                    continue;
+                }
+
+                let neighbor_risk = risk * decay as f64 / 100.0 * *confidence as f64 / 100.0;
+                if neighbor_risk <= 0.0 {
+                    continue;
+                }
+
+                if let Some(existing) = propagated.get(neighbor) {
+                    if existing.risk >= neighbor_risk {
+                        continue;
+                    }
+                }
+
+                propagated.insert(
+                    *neighbor,
+                    PropagatedRisk {
+                        address: *neighbor,
+                        risk: neighbor_risk,
+                        depth,
+                        origin,
+                    },
+                );
+                next_frontier.push((*neighbor, neighbor_risk, origin));
+            }
+        }
+
+        frontier = next_frontier;
+    }
+
+    let mut report: Vec<PropagatedRisk> = propagated.into_values().collect();
+    report.sort_by(|a, b| b.risk.partial_cmp(&a.risk).unwrap());
+    report
+}
+
+/// Fetches effective risk scores of the reported addresses in the network.
+/// Address accounts don't store the address itself, so they are fetched by their PDA,
+/// reports without effective risk (stale, expired or fully decayed) are skipped as if the addresses were not reported
+fn get_address_risks(
+    rpc_client: &RpcClient,
+    network_account: &Pubkey,
+    addresses: &[Pubkey],
+    now: UnixTimestamp,
+    risk_decay_period: u64,
+) -> Result<HashMap<Pubkey, u8>, Box<dyn std::error::Error>> {
+    let mut risks = HashMap::new();
+    for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let address_accounts: Vec<Pubkey> = chunk
+            .iter()
+            .map(|address| get_address_address(network_account, address))
+            .collect();
+
+        for (address, account) in chunk
+            .iter()
+            .zip(rpc_client.get_multiple_accounts(&address_accounts)?)
+        {
+            if let Some(account) = account {
+                let address_data: Address = try_from_slice_unchecked(&account.data)?;
+                let effective_risk = address_data.effective_risk(now, risk_decay_period);
+                if effective_risk > 0 {
+                    risks.insert(*address, effective_risk);
+                }
+            }
+        }
+    }
+
+    Ok(risks)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        hapi_core_solana::state::enums::{AddressLinkKind, HapiAccountType},
+    };
+
+    fn link(source: &Pubkey, destination: &Pubkey, confidence: u8) -> AddressLink {
+        AddressLink {
+            account_type: HapiAccountType::AddressLink,
+            network: Pubkey::default(),
+            source: *source,
+            destination: *destination,
+            kind: AddressLinkKind::SameOwner,
+            confidence,
+            reporter_key: Pubkey::default(),
+            bump_seed: 0,
+        }
+    }
+
+    #[test]
+    fn test_risk_propagated_over_links() {
+        // Arrange: reported -> a -> b -> c, d -> a
+        let reported = Pubkey::new_unique();
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let c = Pubkey::new_unique();
+        let d = Pubkey::new_unique();
+        let links = [
+            link(&reported, &a, 100),
+            link(&a, &b, 50),
+            link(&b, &c, 100),
+            link(&d, &a, 100),
+        ];
+        let reported_risks: HashMap<Pubkey, u8> = vec![(reported, 10)].into_iter().collect();
+
+        // Act
+        let report = propagate_risk(&reported_risks, &links, 50, 2);
+
+        // Assert
+        let risks: HashMap<Pubkey, (f64, u8)> = report
+            .iter()
+            .map(|item| (item.address, (item.risk, item.depth)))
+            .collect();
+        assert_eq!(3, report.len(), "Only addresses within 2 hops get risk");
+        assert_eq!(Some(&(5.0, 1)), risks.get(&a));
+        assert_eq!(
+            Some(&(2.5, 2)),
+            risks.get(&d),
+            "Links spread risk both ways"
+        );
+        assert_eq!(Some(&(1.25, 2)), risks.get(&b));
+        assert!(!risks.contains_key(&c), "Address is beyond max depth");
+        assert!(report.iter().all(|item| item.origin == reported));
+        assert_eq!(a, report[0].address, "Report is ranked by risk");
+    }
+
+    #[test]
+    fn test_highest_risk_path_kept() {
+        // Arrange: low -> a <- high, low -> high
+        let low = Pubkey::new_unique();
+        let high = Pubkey::new_unique();
+        let a = Pubkey::new_unique();
+        let links = [
+            link(&low, &a, 100),
+            link(&high, &a, 100),
+            link(&low, &high, 100),
+        ];
+        let reported_risks: HashMap<Pubkey, u8> = vec![(low, 2), (high, 8)].into_iter().collect();
+
+        // Act
+        let report = propagate_risk(&reported_risks, &links, 100, 3);
+
+        // Assert
+        assert_eq!(
+            1,
+            report.len(),
+            "Reported addresses don't get propagated risk"
+        );
+        assert_eq!(a, report[0].address);
+        assert_eq!(8.0, report[0].risk);
+        assert_eq!(high, report[0].origin);
+    }
+}
@@ -0,0 +1,63 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{
+            address_filter::get_address_filter_address, community::get_community_address,
+            network::get_network_address,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{pubkey::Pubkey, signature::Signer},
+    std::str::FromStr,
+};
+
+pub fn cmd_rebuild_address_filter(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    file: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    assert_is_existing_account(rpc_client, &network_account)?;
+
+    let addresses = addresses_from_string(&std::fs::read_to_string(&file)?)?;
+    if config.verbose {
+        println!("{}: {}", "Addresses".bright_black(), addresses.len());
+    }
+
+    let instructions = instruction::rebuild_address_filter_chunked(
+        &config.keypair.pubkey(),
+        &format!("{}/{}", &community_name, &network_name),
+        &addresses,
+    )?;
+
+    for instruction in instructions {
+        send_instruction(rpc_client, config, instruction)?;
+    }
+
+    println!(
+        "{}: {}",
+        "Address filter rebuilt".green(),
+        get_address_filter_address(&network_account)
+    );
+
+    Ok(())
+}
+
+/// Parses addresses from the first column of each line, so address report files can be used.
+/// Blank lines and lines starting with `#` are skipped.
+pub fn addresses_from_string(input: &str) -> Result<Vec<Pubkey>, Box<dyn std::error::Error>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let address = line.split_whitespace().next().unwrap();
+            Pubkey::from_str(address).map_err(|e| e.into())
+        })
+        .collect()
+}
@@ -0,0 +1,58 @@
+use {
+    crate::{
+        tools::{assert_is_existing_account, send_instruction},
+        Config,
+    },
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{
+            community::{get_community_address, Community},
+            reporter::get_reporter_address,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, pubkey::Pubkey, signature::Signer},
+};
+
+pub fn cmd_record_lost_dispute(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    reporter_pubkey: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let community_data = rpc_client.get_account_data(&community_account)?;
+    let community: Community = try_from_slice_unchecked(&community_data)?;
+
+    if config.verbose {
+        println!("{}: {}", "Community".bright_black(), community.name);
+    }
+
+    let reporter_account = get_reporter_address(&community_account, reporter_pubkey);
+
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Reporter account".bright_black(),
+            reporter_account
+        );
+    }
+
+    assert_is_existing_account(rpc_client, &reporter_account)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::record_lost_dispute(
+            &config.keypair.pubkey(),
+            &community_name,
+            reporter_pubkey,
+        )
+        .unwrap(),
+    )?;
+
+    println!("{} {}", "Lost dispute recorded:".green(), reporter_account);
+
+    Ok(())
+}
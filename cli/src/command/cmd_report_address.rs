@@ -0,0 +1,61 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction::{self, AddressReport},
+        state::{
+            address::{get_address_address, Address},
+            case::get_case_address,
+            community::get_community_address,
+            network::get_network_address,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, signature::Signer},
+};
+
+pub fn cmd_report_address(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    case_id: u64,
+    report: &AddressReport,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = &get_network_address(&community_account, &network_name);
+    assert_is_existing_account(rpc_client, &network_account)?;
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), network_name);
+    }
+
+    let case_account = get_case_address(&community_account, &case_id.to_le_bytes());
+    assert_is_existing_account(rpc_client, &case_account)?;
+    if config.verbose {
+        println!("{}: {}", "Case account".bright_black(), case_account);
+    }
+
+    // Replaced values of an existing address are recorded in its next revision
+    let address_account = get_address_address(&network_account, &report.address);
+    let revision = match rpc_client.get_account_data(&address_account) {
+        Ok(address_data) => try_from_slice_unchecked::<Address>(&address_data)?.revision,
+        Err(_) => 0,
+    };
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::report_address(
+            &config.keypair.pubkey(),
+            &format!("{}/{}", &community_name, &network_name),
+            case_id,
+            report,
+            revision,
+        )
+        .unwrap(),
+    )?;
+
+    println!("{}: {}", "Address reported".green(), address_account);
+
+    Ok(())
+}
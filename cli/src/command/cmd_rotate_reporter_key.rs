@@ -0,0 +1,58 @@
+use {
+    crate::{
+        tools::{assert_is_existing_account, send_instruction},
+        Config,
+    },
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{community::get_community_address, reporter::get_reporter_address},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{pubkey::Pubkey, signature::Signer},
+};
+
+pub fn cmd_rotate_reporter_key(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    reporter_pubkey: &Pubkey,
+    new_reporter_pubkey: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if config.verbose {
+        println!("{}: {}", "Community".bright_black(), community_name);
+    }
+
+    let community_account = get_community_address(&community_name);
+    let reporter_account = get_reporter_address(&community_account, reporter_pubkey);
+
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Reporter account".bright_black(),
+            reporter_account
+        );
+    }
+
+    assert_is_existing_account(rpc_client, &reporter_account)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::rotate_reporter_key(
+            &config.keypair.pubkey(),
+            &community_name,
+            reporter_pubkey,
+            new_reporter_pubkey,
+        )
+        .unwrap(),
+    )?;
+
+    println!(
+        "{} {}",
+        "Reporter key rotated:".green(),
+        get_reporter_address(&community_account, new_reporter_pubkey)
+    );
+
+    Ok(())
+}
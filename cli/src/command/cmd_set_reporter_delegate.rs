@@ -0,0 +1,48 @@
+use {
+    crate::{tools::send_instruction, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{community::get_community_address, reporter::get_reporter_address},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{clock::UnixTimestamp, pubkey::Pubkey, signature::Signer},
+};
+
+pub fn cmd_set_reporter_delegate(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    delegate: Option<&Pubkey>,
+    valid_until: UnixTimestamp,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let reporter_account = get_reporter_address(&community_account, &config.keypair.pubkey());
+
+    if config.verbose {
+        println!(
+            "{}: {}",
+            "Reporter account".bright_black(),
+            reporter_account
+        );
+    }
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::set_reporter_delegate(
+            &config.keypair.pubkey(),
+            &community_name,
+            delegate,
+            valid_until,
+        )
+        .unwrap(),
+    )?;
+
+    match delegate {
+        Some(delegate) => println!("{} {}", "Reporter delegate set:".green(), delegate),
+        None => println!("{}", "Reporter delegate revoked".green()),
+    }
+
+    Ok(())
+}
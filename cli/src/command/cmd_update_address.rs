@@ -2,14 +2,16 @@ use {
     crate::{tools::*, Config},
     colored::*,
     hapi_core_solana::{
-        instruction,
+        instruction::{self, AddressReport},
         state::{
-            address::get_address_address, case::get_case_address, community::get_community_address,
-            enums::Category, network::get_network_address,
+            address::{get_address_address, Address},
+            case::get_case_address,
+            community::get_community_address,
+            network::get_network_address,
         },
     },
     solana_client::rpc_client::RpcClient,
-    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    solana_sdk::{borsh::try_from_slice_unchecked, signature::Signer},
 };
 
 pub fn cmd_update_address(
@@ -17,10 +19,8 @@ pub fn cmd_update_address(
     config: &Config,
     community_name: String,
     network_name: String,
-    address: &Pubkey,
     case_id: u64,
-    risk: u8,
-    category: Category,
+    report: &AddressReport,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let community_account = get_community_address(&community_name);
     let network_account = &get_network_address(&community_account, &network_name);
@@ -29,30 +29,28 @@ pub fn cmd_update_address(
         println!("{}: {}", "Network".bright_black(), network_name);
     }
 
-    let case_account = get_case_address(&network_account, &case_id.to_le_bytes());
+    let case_account = get_case_address(&community_account, &case_id.to_le_bytes());
     assert_is_existing_account(rpc_client, &case_account)?;
     if config.verbose {
         println!("{}: {}", "Case account".bright_black(), case_account);
     }
 
-    let address_account = get_address_address(&network_account, address);
-    assert_is_existing_account(rpc_client, &address_account)?;
+    let address_account = get_address_address(&network_account, &report.address);
+    let address_data = rpc_client.get_account_data(&address_account)?;
+    let address_data: Address = try_from_slice_unchecked(&address_data)?;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[instruction::update_address(
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::update_address(
             &config.keypair.pubkey(),
             &format!("{}/{}", &community_name, &network_name),
-            address,
             case_id,
-            risk,
-            category,
+            report,
+            address_data.revision,
         )
-        .unwrap()],
-        Some(&config.keypair.pubkey()),
-    );
-    let blockhash = rpc_client.get_recent_blockhash()?.0;
-    transaction.try_sign(&[&config.keypair], blockhash)?;
-    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+        .unwrap(),
+    )?;
 
     println!("{}: {}", "Address updated".green(), address_account);
 
@@ -0,0 +1,55 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{
+            address::get_address_address, community::get_community_address,
+            entity::get_entity_address, network::get_network_address,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{pubkey::Pubkey, signature::Signer},
+};
+
+pub fn cmd_update_address_entity(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    address: &Pubkey,
+    entity_name: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    if config.verbose {
+        println!("{}: {}", "Network".bright_black(), network_name);
+    }
+
+    let address_account = get_address_address(&network_account, address);
+    assert_is_existing_account(rpc_client, &address_account)?;
+
+    if let Some(entity_name) = &entity_name {
+        let entity_account = get_entity_address(&community_account, entity_name);
+        assert_is_existing_account(rpc_client, &entity_account)?;
+        if config.verbose {
+            println!("{}: {}", "Entity account".bright_black(), entity_account);
+        }
+    }
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::update_address_entity(
+            &config.keypair.pubkey(),
+            &format!("{}/{}", &community_name, &network_name),
+            address,
+            entity_name.as_deref(),
+        )
+        .unwrap(),
+    )?;
+
+    println!("{}: {}", "Address entity updated".green(), address_account);
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{
+            address_set::{get_address_set_address, get_address_set_leaf},
+            case::get_case_address,
+            community::get_community_address,
+            enums::Category,
+            network::get_network_address,
+        },
+        tools::merkle::{get_merkle_root, MerkleHash},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{bs58, pubkey::Pubkey, signature::Signer},
+    std::str::FromStr,
+};
+
+/// Address of an address set with its risk and category
+pub struct AddressSetEntry {
+    pub address: Pubkey,
+    pub risk: u8,
+    pub category: Category,
+}
+
+pub fn cmd_update_address_set(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    network_name: String,
+    case_id: u64,
+    file: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let community_account = get_community_address(&community_name);
+    let network_account = get_network_address(&community_account, &network_name);
+    assert_is_existing_account(rpc_client, &network_account)?;
+
+    let case_account = get_case_address(&community_account, &case_id.to_le_bytes());
+    assert_is_existing_account(rpc_client, &case_account)?;
+    if config.verbose {
+        println!("{}: {}", "Case account".bright_black(), case_account);
+    }
+
+    let entries = address_set_entries_from_string(&std::fs::read_to_string(&file)?)?;
+    let root = get_merkle_root(&address_set_leaves(&network_account, &entries));
+    if config.verbose {
+        println!("{}: {}", "Addresses".bright_black(), entries.len());
+    }
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::update_address_set(
+            &config.keypair.pubkey(),
+            &community_name,
+            case_id,
+            &root,
+            entries.len() as u64,
+        )
+        .unwrap(),
+    )?;
+
+    println!(
+        "{}: {}",
+        "Address set updated".green(),
+        get_address_set_address(&case_account)
+    );
+    println!(
+        "{}: {}",
+        "Root".bright_black(),
+        bs58::encode(root).into_string()
+    );
+
+    Ok(())
+}
+
+/// Parses address set entries, one per line: `<address> <risk> <category>`.
+/// Blank lines and lines starting with `#` are skipped.
+pub fn address_set_entries_from_string(
+    input: &str,
+) -> Result<Vec<AddressSetEntry>, Box<dyn std::error::Error>> {
+    let mut entries = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 3 {
+            return Err(format!("Invalid address set entry on line {}", index + 1).into());
+        }
+
+        entries.push(AddressSetEntry {
+            address: Pubkey::from_str(fields[0])?,
+            risk: fields[1].parse()?,
+            category: category_from_string(fields[2])?,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Returns address set leaves of the entries in file order
+pub fn address_set_leaves(network: &Pubkey, entries: &[AddressSetEntry]) -> Vec<MerkleHash> {
+    entries
+        .iter()
+        .map(|entry| get_address_set_leaf(network, &entry.address, entry.risk, entry.category))
+        .collect()
+}
@@ -1,14 +1,21 @@
 use hapi_core_solana::state::enums::CaseStatus;
 
 use {
-    crate::{tools::assert_is_existing_account, Config},
+    crate::{
+        tools::{assert_is_existing_account, send_instruction},
+        Config,
+    },
     colored::*,
     hapi_core_solana::{
         instruction,
-        state::{case::get_case_address, community::get_community_address, enums::CategorySet},
+        state::{
+            case::{get_case_address, Case},
+            community::get_community_address,
+            enums::CategorySet,
+        },
     },
     solana_client::rpc_client::RpcClient,
-    solana_sdk::{signature::Signer, transaction::Transaction},
+    solana_sdk::{borsh::try_from_slice_unchecked, clock::UnixTimestamp, signature::Signer},
 };
 
 pub fn cmd_update_case(
@@ -16,8 +23,10 @@ pub fn cmd_update_case(
     config: &Config,
     community_name: String,
     case_id: u64,
+    case_name: Option<String>,
     status: CaseStatus,
     categories: CategorySet,
+    expires_at: Option<UnixTimestamp>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     if config.verbose {
         println!("{}: {}", "Community".bright_black(), community_name);
@@ -38,20 +47,29 @@ pub fn cmd_update_case(
         println!("{}: {}", "Case account".bright_black(), case_account);
     }
 
-    let mut transaction = Transaction::new_with_payer(
-        &[instruction::update_case(
+    let case_name = match case_name {
+        Some(case_name) => case_name,
+        None => {
+            let case: Case =
+                try_from_slice_unchecked(&rpc_client.get_account_data(&case_account)?)?;
+            case.name
+        }
+    };
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::update_case(
             &config.keypair.pubkey(),
             &community_name,
             case_id,
+            &case_name,
             status,
             &categories,
+            expires_at,
         )
-        .unwrap()],
-        Some(&config.keypair.pubkey()),
-    );
-    let blockhash = rpc_client.get_recent_blockhash()?.0;
-    transaction.try_sign(&[&config.keypair], blockhash)?;
-    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+        .unwrap(),
+    )?;
 
     println!("{}: {}", "Case updated".green(), case_account);
 
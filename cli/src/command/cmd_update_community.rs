@@ -0,0 +1,52 @@
+use {
+    crate::{
+        tools::{assert_is_existing_account, send_instruction},
+        Config,
+    },
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::community::{get_community_address, Community},
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, pubkey::Pubkey, signature::Signer},
+};
+
+pub fn cmd_update_community(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    risk_decay_period: u64,
+    report_quota: Option<u64>,
+    report_quota_period: Option<u64>,
+    new_authority: Option<&Pubkey>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if config.verbose {
+        println!("{}: {}", "Community".bright_black(), community_name.bold());
+    }
+
+    let community_account = get_community_address(&community_name);
+
+    assert_is_existing_account(rpc_client, &community_account)?;
+
+    let community_data = rpc_client.get_account_data(&community_account)?;
+    let community: Community = try_from_slice_unchecked(&community_data)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::update_community(
+            &config.keypair.pubkey(),
+            new_authority,
+            &community_name,
+            risk_decay_period,
+            report_quota.unwrap_or(community.report_quota),
+            report_quota_period.unwrap_or(community.report_quota_period),
+        )
+        .unwrap(),
+    )?;
+
+    println!("{}: {}", "Community updated".green(), community_account);
+
+    Ok(())
+}
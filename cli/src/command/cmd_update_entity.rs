@@ -0,0 +1,81 @@
+use {
+    crate::{tools::*, Config},
+    colored::*,
+    hapi_core_solana::{
+        instruction,
+        state::{
+            community::get_community_address,
+            entity::{get_entity_address, Entity, MAX_ENTITY_ANCESTORS},
+            enums::Category,
+        },
+    },
+    solana_client::rpc_client::RpcClient,
+    solana_sdk::{borsh::try_from_slice_unchecked, signature::Signer},
+};
+
+pub fn cmd_update_entity(
+    rpc_client: &RpcClient,
+    config: &Config,
+    community_name: String,
+    entity_name: String,
+    website: String,
+    category: Category,
+    risk: u8,
+    parent_name: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if config.verbose {
+        println!("{}: {}", "Community".bright_black(), community_name);
+    }
+    let community_account = get_community_address(&community_name);
+
+    // Ancestors of the parent follow it, so the program can check the entity isn't among them
+    let mut parent_names = vec![];
+    if let Some(parent_name) = parent_name {
+        let parent_account = get_entity_address(&community_account, &parent_name);
+        assert_is_existing_account(rpc_client, &parent_account)?;
+        if config.verbose {
+            println!(
+                "{}: {}",
+                "Parent entity account".bright_black(),
+                parent_account
+            );
+        }
+
+        let parent_data = rpc_client.get_account_data(&parent_account)?;
+        let mut ancestor_account = try_from_slice_unchecked::<Entity>(&parent_data)?.parent;
+        parent_names.push(parent_name);
+
+        // The program walks no more than the maximum number of ancestors
+        while let Some(account) = ancestor_account {
+            if parent_names.len() > MAX_ENTITY_ANCESTORS {
+                break;
+            }
+            let ancestor_data = rpc_client.get_account_data(&account)?;
+            let ancestor: Entity = try_from_slice_unchecked(&ancestor_data)?;
+            parent_names.push(ancestor.name);
+            ancestor_account = ancestor.parent;
+        }
+    }
+
+    let entity_account = get_entity_address(&community_account, &entity_name);
+    assert_is_existing_account(rpc_client, &entity_account)?;
+
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::update_entity(
+            &config.keypair.pubkey(),
+            &community_name,
+            &entity_name,
+            &website,
+            category,
+            risk,
+            &parent_names.iter().map(String::as_str).collect::<Vec<_>>(),
+        )
+        .unwrap(),
+    )?;
+
+    println!("{}: {}", "Entity updated".green(), entity_account);
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use {
-    crate::{tools::assert_is_existing_account, Config},
+    crate::{
+        tools::{assert_is_existing_account, send_instruction},
+        Config,
+    },
     colored::*,
     hapi_core_solana::{
         instruction,
@@ -11,8 +14,7 @@ use {
     },
     solana_client::rpc_client::RpcClient,
     solana_sdk::{
-        borsh::try_from_slice_unchecked, pubkey::Pubkey, signature::Signer,
-        transaction::Transaction,
+        borsh::try_from_slice_unchecked, clock::UnixTimestamp, pubkey::Pubkey, signature::Signer,
     },
 };
 
@@ -23,6 +25,7 @@ pub fn cmd_update_reporter(
     reporter_pubkey: &Pubkey,
     name: String,
     reporter_type: ReporterType,
+    active_until: Option<UnixTimestamp>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let community_account = get_community_address(&community_name);
     let community_data = rpc_client.get_account_data(&community_account)?;
@@ -46,20 +49,19 @@ pub fn cmd_update_reporter(
 
     assert_is_existing_account(rpc_client, &reporter_account)?;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[instruction::update_reporter(
+    send_instruction(
+        rpc_client,
+        config,
+        instruction::update_reporter(
             &config.keypair.pubkey(),
             &community_name,
             &name,
-            &reporter_account,
+            reporter_pubkey,
             reporter_type,
+            active_until,
         )
-        .unwrap()],
-        Some(&config.keypair.pubkey()),
-    );
-    let blockhash = rpc_client.get_recent_blockhash()?.0;
-    transaction.try_sign(&[&config.keypair], blockhash)?;
-    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+        .unwrap(),
+    )?;
 
     println!("{} {}", "Reporter updated:".green(), reporter_account);
 
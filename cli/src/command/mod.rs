@@ -1,29 +1,87 @@
-mod cmd_create_reporter;
-mod cmd_create_community;
-mod cmd_create_network;
-mod cmd_list_accounts;
+mod cmd_check_address_filter;
 mod cmd_create_address;
+mod cmd_create_address_link;
+mod cmd_create_addresses;
+mod cmd_create_asset;
 mod cmd_create_case;
-mod cmd_update_address;
-mod cmd_update_case;
-mod cmd_update_reporter;
+mod cmd_create_community;
+mod cmd_create_entity;
+mod cmd_create_network;
+mod cmd_create_reporter;
+mod cmd_create_transaction;
+mod cmd_export_address_filter;
 mod cmd_get_address;
+mod cmd_get_address_history;
+mod cmd_get_address_set;
+mod cmd_get_address_set_proof;
+mod cmd_get_asset;
 mod cmd_get_case;
+mod cmd_get_community;
+mod cmd_get_entity;
 mod cmd_get_network;
 mod cmd_get_reporter;
-mod cmd_get_community;
+mod cmd_get_reporter_stats;
+mod cmd_get_transaction;
+mod cmd_list_accounts;
+mod cmd_list_address_links;
+mod cmd_list_entity_addresses;
+mod cmd_list_expiring_reporters;
+mod cmd_propagate_risk;
+mod cmd_rebuild_address_filter;
+mod cmd_record_lost_dispute;
+mod cmd_refresh_address;
+mod cmd_report_address;
+mod cmd_rotate_reporter_key;
+mod cmd_set_reporter_delegate;
+mod cmd_update_address;
+mod cmd_update_address_entity;
+mod cmd_update_address_set;
+mod cmd_update_asset;
+mod cmd_update_case;
+mod cmd_update_community;
+mod cmd_update_entity;
+mod cmd_update_reporter;
 
-pub use cmd_create_reporter::*;
-pub use cmd_create_community::*;
-pub use cmd_create_network::*;
-pub use cmd_list_accounts::*;
+pub use cmd_check_address_filter::*;
 pub use cmd_create_address::*;
+pub use cmd_create_address_link::*;
+pub use cmd_create_addresses::*;
+pub use cmd_create_asset::*;
 pub use cmd_create_case::*;
-pub use cmd_update_address::*;
-pub use cmd_update_case::*;
-pub use cmd_update_reporter::*;
+pub use cmd_create_community::*;
+pub use cmd_create_entity::*;
+pub use cmd_create_network::*;
+pub use cmd_create_reporter::*;
+pub use cmd_create_transaction::*;
+pub use cmd_export_address_filter::*;
 pub use cmd_get_address::*;
+pub use cmd_get_address_history::*;
+pub use cmd_get_address_set::*;
+pub use cmd_get_address_set_proof::*;
+pub use cmd_get_asset::*;
 pub use cmd_get_case::*;
+pub use cmd_get_community::*;
+pub use cmd_get_entity::*;
 pub use cmd_get_network::*;
 pub use cmd_get_reporter::*;
-pub use cmd_get_community::*;
+pub use cmd_get_reporter_stats::*;
+pub use cmd_get_transaction::*;
+pub use cmd_list_accounts::*;
+pub use cmd_list_address_links::*;
+pub use cmd_list_entity_addresses::*;
+pub use cmd_list_expiring_reporters::*;
+pub use cmd_propagate_risk::*;
+pub use cmd_rebuild_address_filter::*;
+pub use cmd_record_lost_dispute::*;
+pub use cmd_refresh_address::*;
+pub use cmd_report_address::*;
+pub use cmd_rotate_reporter_key::*;
+pub use cmd_set_reporter_delegate::*;
+pub use cmd_update_address::*;
+pub use cmd_update_address_entity::*;
+pub use cmd_update_address_set::*;
+pub use cmd_update_asset::*;
+pub use cmd_update_case::*;
+pub use cmd_update_community::*;
+pub use cmd_update_entity::*;
+pub use cmd_update_reporter::*;
@@ -7,8 +7,9 @@ use {
         SubCommand,
     },
     colored::*,
+    hapi_core_solana::instruction::{AddressReport, AssetReport, TransactionReport},
     solana_clap_utils::{
-        input_parsers::pubkey_of,
+        input_parsers::{pubkey_of, value_of},
         input_validators::{is_keypair, is_url, is_valid_pubkey},
     },
     solana_client::rpc_client::RpcClient,
@@ -20,6 +21,7 @@ use {
 
 pub struct Config {
     keypair: Keypair,
+    fee_payer: Option<Keypair>,
     json_rpc_url: String,
     verbose: bool,
 }
@@ -58,6 +60,38 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         .possible_values(REPORTER_TYPE_VALUES)
         .help("The type of the new reporter");
 
+    let arg_active_until = Arg::with_name("active_until")
+        .long("active-until")
+        .value_name("ACTIVE_UNTIL")
+        .takes_value(true)
+        .help("End of the reporter term (RFC 3339 date and time or Unix timestamp)");
+
+    let arg_within = Arg::with_name("within")
+        .long("within")
+        .value_name("SECONDS")
+        .takes_value(true)
+        .default_value("2592000")
+        .help("Period in seconds from now to look for expiring reporter terms");
+
+    let arg_new_reporter_pubkey = Arg::with_name("new_reporter_pubkey")
+        .value_name("NEW_REPORTER_PUBKEY")
+        .validator(is_valid_pubkey)
+        .takes_value(true)
+        .help("The new public key of the reporter");
+
+    let arg_delegate_pubkey = Arg::with_name("delegate_pubkey")
+        .value_name("DELEGATE_PUBKEY")
+        .validator(is_valid_pubkey)
+        .takes_value(true)
+        .help("The public key allowed to sign reports on behalf of the reporter (default: revoke the delegate)");
+
+    let arg_valid_until = Arg::with_name("valid_until")
+        .long("valid-until")
+        .value_name("VALID_UNTIL")
+        .takes_value(true)
+        .requires("delegate_pubkey")
+        .help("Delegate expiration date (RFC 3339 date and time or Unix timestamp)");
+
     let arg_case_name = Arg::with_name("case_name")
         .long("case-name")
         .value_name("CASE_NAME")
@@ -85,6 +119,11 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         .value_name("ADDRESS")
         .help("Blockchain address (account)");
 
+    let arg_file = Arg::with_name("file")
+        .value_name("FILE")
+        .takes_value(true)
+        .help("Path to the file with address reports, one per line: <ADDRESS> <RISK> [<CATEGORY>,...] [<PRIMARY_CATEGORY>]");
+
     let arg_risk = Arg::with_name("risk")
         .long("risk")
         .value_name("RISK")
@@ -96,6 +135,124 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         .possible_values(CATEGORY_VALUES)
         .help("Illicitness category");
 
+    let arg_entity_name = Arg::with_name("entity_name")
+        .long("entity-name")
+        .value_name("ENTITY_NAME")
+        .help("The name of the entity");
+
+    let arg_website = Arg::with_name("website")
+        .long("website")
+        .value_name("WEBSITE")
+        .help("Entity website");
+
+    let arg_parent_entity = Arg::with_name("parent_entity")
+        .long("parent-entity")
+        .value_name("PARENT_ENTITY")
+        .takes_value(true)
+        .help("The name of the parent entity");
+
+    let arg_address_categories = Arg::with_name("category")
+        .multiple(true)
+        .long("category")
+        .value_name("CATEGORY")
+        .takes_value(true)
+        .required(true)
+        .possible_values(CATEGORY_VALUES)
+        .help("Illicitness categories, must be reported in the case");
+
+    let arg_primary_category = Arg::with_name("primary_category")
+        .long("primary-category")
+        .value_name("PRIMARY_CATEGORY")
+        .takes_value(true)
+        .possible_values(CATEGORY_VALUES)
+        .help("Primary illicitness category, must be one of the categories");
+
+    let arg_tx_hash = Arg::with_name("tx_hash")
+        .long("tx-hash")
+        .value_name("TX_HASH")
+        .help("Transaction hash (base58 or 0x-prefixed hex)");
+
+    let arg_source = Arg::with_name("source")
+        .long("source")
+        .value_name("SOURCE")
+        .takes_value(true)
+        .validator(is_valid_pubkey)
+        .help("Source address of the transaction");
+
+    let arg_destination = Arg::with_name("destination")
+        .long("destination")
+        .value_name("DESTINATION")
+        .takes_value(true)
+        .validator(is_valid_pubkey)
+        .help("Destination address of the transaction");
+
+    let arg_asset = Arg::with_name("asset")
+        .long("asset")
+        .value_name("ASSET")
+        .validator(is_valid_pubkey)
+        .help("Asset address (token mint, collection or contract)");
+
+    let arg_token_id = Arg::with_name("token_id")
+        .long("token-id")
+        .value_name("TOKEN_ID")
+        .takes_value(true)
+        .help("Token ID within the asset (decimal or 0x-prefixed hex)");
+
+    let arg_link_kind = Arg::with_name("link_kind")
+        .long("link-kind")
+        .value_name("LINK_KIND")
+        .possible_values(ADDRESS_LINK_KIND_VALUES)
+        .help("Relation of the source address to the destination address");
+
+    let arg_confidence = Arg::with_name("confidence")
+        .long("confidence")
+        .value_name("CONFIDENCE")
+        .help("Confidence of the relation from 0 to 100");
+
+    let arg_decay = Arg::with_name("decay")
+        .long("decay")
+        .value_name("DECAY")
+        .takes_value(true)
+        .default_value("50")
+        .help("Percent of risk passed over a single link");
+
+    let arg_max_depth = Arg::with_name("max_depth")
+        .long("max-depth")
+        .value_name("MAX_DEPTH")
+        .takes_value(true)
+        .default_value("3")
+        .help("Maximum number of links between a reported address and its neighbor");
+
+    let arg_min_risk = Arg::with_name("min_risk")
+        .long("min-risk")
+        .value_name("MIN_RISK")
+        .takes_value(true)
+        .default_value("0.5")
+        .help("Minimum propagated risk to include in the report");
+
+    let arg_expires_at = Arg::with_name("expires_at")
+        .long("expires-at")
+        .value_name("EXPIRES_AT")
+        .takes_value(true)
+        .help("Review-by date (RFC 3339 date and time or Unix timestamp)");
+
+    let arg_risk_decay_period = Arg::with_name("risk_decay_period")
+        .long("risk-decay-period")
+        .value_name("RISK_DECAY_PERIOD")
+        .help("Period in seconds for the address risk to decay by half, zero disables the decay");
+
+    let arg_report_quota = Arg::with_name("report_quota")
+        .long("report-quota")
+        .value_name("REPORT_QUOTA")
+        .takes_value(true)
+        .help("Maximum number of reports per reporter within a quota period, zero disables the quota (default: unchanged)");
+
+    let arg_report_quota_period = Arg::with_name("report_quota_period")
+        .long("report-quota-period")
+        .value_name("REPORT_QUOTA_PERIOD")
+        .takes_value(true)
+        .help("Quota period in seconds (default: unchanged)");
+
     let subcommand_community = SubCommand::with_name("community")
         .about("Manage communities")
         .subcommand(
@@ -103,6 +260,21 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .about("Create a new HAPI community")
                 .arg(arg_community_name.clone().index(1).required(true)),
         )
+        .subcommand(
+            SubCommand::with_name("update")
+                .about("Update an existing HAPI community")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_risk_decay_period.clone().index(2).required(true))
+                .arg(
+                    arg_community_authority
+                        .clone()
+                        .index(3)
+                        .required(false)
+                        .help("New authority public key (default: unchanged)"),
+                )
+                .arg(arg_report_quota.clone())
+                .arg(arg_report_quota_period.clone()),
+        )
         .subcommand(
             SubCommand::with_name("get")
                 .about("View community data")
@@ -133,7 +305,8 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(arg_community_name.clone().index(1).required(true))
                 .arg(arg_reporter_pubkey.clone().index(2).required(true))
                 .arg(arg_reporter_name.clone().index(3).required(true))
-                .arg(arg_reporter_type.clone().index(4).required(true)),
+                .arg(arg_reporter_type.clone().index(4).required(true))
+                .arg(arg_active_until.clone()),
         )
         .subcommand(
             SubCommand::with_name("update")
@@ -141,13 +314,52 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(arg_community_name.clone().index(1).required(true))
                 .arg(arg_reporter_pubkey.clone().index(2).required(true))
                 .arg(arg_reporter_name.clone().index(3).required(true))
-                .arg(arg_reporter_type.clone().index(4).required(true)),
+                .arg(arg_reporter_type.clone().index(4).required(true))
+                .arg(arg_active_until.clone()),
         )
         .subcommand(
             SubCommand::with_name("get")
                 .about("View reporter data")
                 .arg(arg_community_name.clone().index(1).required(true))
                 .arg(arg_reporter_pubkey.clone().index(2).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("stats")
+                .about("View reporter activity and reputation")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_reporter_pubkey.clone().index(2).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("expiring")
+                .about("List reporters with terms expiring soon")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_within.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("dispute")
+                .about("Record a dispute lost by the reporter")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_reporter_pubkey.clone().index(2).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("rotate")
+                .about("Move a reporter to a new public key")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_reporter_pubkey.clone().index(2).required(true))
+                .arg(arg_new_reporter_pubkey.clone().index(3).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("delegate")
+                .about("Allow another key to sign reports on behalf of the reporter")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(
+                    arg_delegate_pubkey
+                        .clone()
+                        .index(2)
+                        .required(false)
+                        .requires("valid_until"),
+                )
+                .arg(arg_valid_until.clone()),
         );
 
     let subcommand_case = SubCommand::with_name("case")
@@ -158,7 +370,8 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(arg_community_name.clone().index(1).required(true))
                 .arg(arg_case_name.clone().index(2).required(true))
                 .arg(arg_case_status.clone().index(3).required(true))
-                .arg(arg_case_categories.clone()),
+                .arg(arg_case_categories.clone())
+                .arg(arg_expires_at.clone()),
         )
         .subcommand(
             SubCommand::with_name("update")
@@ -166,7 +379,13 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(arg_community_name.clone().index(1).required(true))
                 .arg(arg_case_id.clone().index(2).required(true))
                 .arg(arg_case_status.clone().index(3).required(true))
-                .arg(arg_case_categories.clone()),
+                .arg(arg_case_categories.clone())
+                .arg(arg_expires_at.clone())
+                .arg(
+                    arg_case_name
+                        .clone()
+                        .help("New short memo about case (default: unchanged)"),
+                ),
         )
         .subcommand(
             SubCommand::with_name("get")
@@ -185,7 +404,18 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(arg_address.clone().index(3).required(true))
                 .arg(arg_case_id.clone().index(4).required(true))
                 .arg(arg_risk.clone().index(5).required(true))
-                .arg(arg_category.clone().index(6).required(true)),
+                .arg(arg_address_categories.clone())
+                .arg(arg_primary_category.clone())
+                .arg(arg_expires_at.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("import")
+                .about("Report addresses from a file in batches")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_case_id.clone().index(3).required(true))
+                .arg(arg_file.clone().index(4).required(true))
+                .arg(arg_expires_at.clone()),
         )
         .subcommand(
             SubCommand::with_name("update")
@@ -195,7 +425,35 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(arg_address.clone().index(3).required(true))
                 .arg(arg_case_id.clone().index(4).required(true))
                 .arg(arg_risk.clone().index(5).required(true))
-                .arg(arg_category.clone().index(6).required(true)),
+                .arg(arg_address_categories.clone())
+                .arg(arg_primary_category.clone())
+                .arg(arg_expires_at.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("report")
+                .about("Report an address, creating or updating it as needed")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_address.clone().index(3).required(true))
+                .arg(arg_case_id.clone().index(4).required(true))
+                .arg(arg_risk.clone().index(5).required(true))
+                .arg(arg_address_categories.clone())
+                .arg(arg_primary_category.clone())
+                .arg(arg_expires_at.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("history")
+                .about("View the timeline of address updates")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_address.clone().index(3).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("refresh")
+                .about("Mark an expired address report as stale")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_address.clone().index(3).required(true)),
         )
         .subcommand(
             SubCommand::with_name("get")
@@ -203,6 +461,216 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .arg(arg_community_name.clone().index(1).required(true))
                 .arg(arg_network_name.clone().index(2).required(true))
                 .arg(arg_address.clone().index(3).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("set-entity")
+                .about("Attach an address to an entity (detach if entity is omitted)")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_address.clone().index(3).required(true))
+                .arg(arg_entity_name.clone().index(4).required(false)),
+        );
+
+    let subcommand_transaction = SubCommand::with_name("transaction")
+        .about("Manage transactions")
+        .subcommand(
+            SubCommand::with_name("create")
+                .about("Report a new transaction")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_tx_hash.clone().index(3).required(true))
+                .arg(arg_case_id.clone().index(4).required(true))
+                .arg(arg_risk.clone().index(5).required(true))
+                .arg(arg_category.clone().index(6).required(true))
+                .arg(arg_source.clone())
+                .arg(arg_destination.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("get")
+                .about("View transaction data")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_tx_hash.clone().index(3).required(true)),
+        );
+
+    let subcommand_asset = SubCommand::with_name("asset")
+        .about("Manage assets")
+        .subcommand(
+            SubCommand::with_name("create")
+                .about("Report a new asset")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_asset.clone().index(3).required(true))
+                .arg(arg_case_id.clone().index(4).required(true))
+                .arg(arg_risk.clone().index(5).required(true))
+                .arg(arg_category.clone().index(6).required(true))
+                .arg(arg_token_id.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("update")
+                .about("Update an existing asset")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_asset.clone().index(3).required(true))
+                .arg(arg_case_id.clone().index(4).required(true))
+                .arg(arg_risk.clone().index(5).required(true))
+                .arg(arg_category.clone().index(6).required(true))
+                .arg(arg_token_id.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("get")
+                .about("View asset data")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_asset.clone().index(3).required(true))
+                .arg(arg_token_id.clone()),
+        );
+
+    let subcommand_link = SubCommand::with_name("link")
+        .about("Manage address links")
+        .subcommand(
+            SubCommand::with_name("create")
+                .about("Link two addresses of a network")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(
+                    arg_source
+                        .clone()
+                        .index(3)
+                        .required(true)
+                        .help("Source address of the link"),
+                )
+                .arg(
+                    arg_destination
+                        .clone()
+                        .index(4)
+                        .required(true)
+                        .help("Destination address of the link"),
+                )
+                .arg(arg_link_kind.clone().index(5).required(true))
+                .arg(arg_confidence.clone().index(6).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("neighbors")
+                .about("List all links from and to an address")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_address.clone().index(3).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("propagate")
+                .about("Rank unreported addresses by risk propagated from their linked neighbors")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_decay.clone())
+                .arg(arg_max_depth.clone())
+                .arg(arg_min_risk.clone()),
+        );
+
+    let subcommand_entity = SubCommand::with_name("entity")
+        .about("Manage entities")
+        .subcommand(
+            SubCommand::with_name("create")
+                .about("Create a new entity")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_entity_name.clone().index(2).required(true))
+                .arg(arg_website.clone().index(3).required(true))
+                .arg(arg_category.clone().index(4).required(true))
+                .arg(arg_risk.clone().index(5).required(true))
+                .arg(arg_parent_entity.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("update")
+                .about("Update an existing entity")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_entity_name.clone().index(2).required(true))
+                .arg(arg_website.clone().index(3).required(true))
+                .arg(arg_category.clone().index(4).required(true))
+                .arg(arg_risk.clone().index(5).required(true))
+                .arg(arg_parent_entity.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("get")
+                .about("View entity data")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_entity_name.clone().index(2).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("addresses")
+                .about("List all addresses of an entity")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_entity_name.clone().index(2).required(true)),
+        );
+
+    let arg_address_set_file = arg_file.clone().help(
+        "Path to the file with address set entries, one per line: <ADDRESS> <RISK> <CATEGORY>",
+    );
+
+    let subcommand_address_set = SubCommand::with_name("set")
+        .about("Manage address sets committed by Merkle roots")
+        .subcommand(
+            SubCommand::with_name("update")
+                .about("Commit the addresses of a case from a file by their Merkle root")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_case_id.clone().index(3).required(true))
+                .arg(arg_address_set_file.clone().index(4).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("proof")
+                .about("Produce the Merkle proof of an address in the address set file")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(arg_case_id.clone().index(3).required(true))
+                .arg(arg_address_set_file.clone().index(4).required(true))
+                .arg(arg_address.clone().index(5).required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("get")
+                .about("View address set data")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_case_id.clone().index(2).required(true)),
+        );
+
+    let subcommand_address_filter = SubCommand::with_name("filter")
+        .about("Manage network address filters for offline screening")
+        .subcommand(
+            SubCommand::with_name("rebuild")
+                .about("Reset the address filter of a network and add the addresses from a file")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(
+                    arg_file
+                        .clone()
+                        .index(3)
+                        .required(true)
+                        .help("Path to the file with addresses, one per line: <ADDRESS> [...]"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("export")
+                .about("Save the address filter of a network to a file")
+                .arg(arg_community_name.clone().index(1).required(true))
+                .arg(arg_network_name.clone().index(2).required(true))
+                .arg(
+                    arg_file
+                        .clone()
+                        .index(3)
+                        .required(true)
+                        .help("Path to the address filter file"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("check")
+                .about("Check an address against an exported address filter without network access")
+                .arg(
+                    arg_file
+                        .clone()
+                        .index(1)
+                        .required(true)
+                        .help("Path to the address filter file"),
+                )
+                .arg(arg_address.clone().index(2).required(true)),
         );
 
     let app_matches = App::new(crate_name!())
@@ -232,6 +700,15 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .global(true)
                 .help("Filepath or URL to a keypair [default: client keypair]"),
         )
+        .arg(
+            Arg::with_name("fee_payer")
+                .long("fee-payer")
+                .value_name("KEYPAIR")
+                .validator(is_keypair)
+                .takes_value(true)
+                .global(true)
+                .help("Filepath or URL to a keypair that pays transaction fees and rent of created accounts [default: client keypair]"),
+        )
         .arg(
             Arg::with_name("verbose")
                 .long("verbose")
@@ -255,6 +732,12 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         .subcommand(subcommand_reporter.clone())
         .subcommand(subcommand_case.clone())
         .subcommand(subcommand_address.clone())
+        .subcommand(subcommand_entity.clone())
+        .subcommand(subcommand_transaction.clone())
+        .subcommand(subcommand_asset.clone())
+        .subcommand(subcommand_link.clone())
+        .subcommand(subcommand_address_set.clone())
+        .subcommand(subcommand_address_filter.clone())
         .get_matches();
 
     let (sub_command, sub_matches) = app_matches.subcommand();
@@ -277,6 +760,10 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                     .value_of("keypair")
                     .unwrap_or(&cli_config.keypair_path),
             )?,
+            fee_payer: matches
+                .value_of("fee_payer")
+                .map(read_keypair_file)
+                .transpose()?,
             verbose: matches.is_present("verbose"),
         }
     };
@@ -287,6 +774,9 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
 
     if config.verbose {
         println!("{}: {}", "JSON RPC URL".bright_black(), config.json_rpc_url);
+        if let Some(fee_payer) = &config.fee_payer {
+            println!("{}: {}", "Fee payer".bright_black(), fee_payer.pubkey());
+        }
     }
 
     match (sub_command, sub_matches) {
@@ -301,95 +791,459 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                     let address = pubkey_of(arg_matches, "address").unwrap();
                     let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
                     let risk = value_t_or_exit!(arg_matches, "risk", u8);
-                    let category = parse_arg_category(arg_matches)?;
+                    let categories = parse_arg_categories(arg_matches)?;
+                    let primary_category = parse_arg_primary_category(arg_matches)?;
+                    let expires_at = parse_arg_expires_at(arg_matches)?;
 
                     cmd_create_address(
                         &rpc_client,
                         &config,
                         community_name,
                         network_name,
-                        &address,
                         case_id,
-                        risk,
-                        category,
+                        &AddressReport {
+                            address,
+                            risk,
+                            categories,
+                            primary_category,
+                            expires_at,
+                        },
                     )
                 }
 
-                ("update", Some(arg_matches)) => {
+                ("import", Some(arg_matches)) => {
                     let community_name = value_t_or_exit!(arg_matches, "community_name", String);
                     let network_name = value_t_or_exit!(arg_matches, "network_name", String);
-                    let address = pubkey_of(arg_matches, "address").unwrap();
                     let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
-                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
-                    let category = parse_arg_category(arg_matches)?;
+                    let file = value_t_or_exit!(arg_matches, "file", String);
+                    let expires_at = parse_arg_expires_at(arg_matches)?;
 
-                    cmd_update_address(
+                    cmd_create_addresses(
                         &rpc_client,
                         &config,
                         community_name,
                         network_name,
-                        &address,
                         case_id,
-                        risk,
-                        category,
+                        file,
+                        expires_at,
                     )
                 }
 
-                ("get", Some(arg_matches)) => {
+                ("update", Some(arg_matches)) => {
                     let community_name = value_t_or_exit!(arg_matches, "community_name", String);
                     let network_name = value_t_or_exit!(arg_matches, "network_name", String);
                     let address = pubkey_of(arg_matches, "address").unwrap();
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
+                    let categories = parse_arg_categories(arg_matches)?;
+                    let primary_category = parse_arg_primary_category(arg_matches)?;
+                    let expires_at = parse_arg_expires_at(arg_matches)?;
 
-                    cmd_get_address(&rpc_client, &config, community_name, network_name, &address)
-                }
-
-                _ => subcommand_address
-                    .clone()
-                    .print_long_help()
-                    .map(|_| println!())
-                    .map_err(|e| e.into()),
-            }
-        }
-
-        ("case", Some(arg_matches)) => {
-            let (sub_command, sub_matches) = arg_matches.subcommand();
-            match (sub_command, sub_matches) {
-                ("create", Some(arg_matches)) => {
-                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
-                    let case_name = value_t_or_exit!(arg_matches, "case_name", String);
-                    let case_status = parse_arg_case_status(&arg_matches)?;
-                    let categories = parse_arg_categories(&arg_matches)?;
-
-                    cmd_create_case(
+                    cmd_update_address(
                         &rpc_client,
                         &config,
                         community_name,
-                        case_name,
-                        case_status,
-                        categories,
+                        network_name,
+                        case_id,
+                        &AddressReport {
+                            address,
+                            risk,
+                            categories,
+                            primary_category,
+                            expires_at,
+                        },
                     )
                 }
 
-                ("update", Some(arg_matches)) => {
+                ("report", Some(arg_matches)) => {
                     let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
                     let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
-                    let case_status = parse_arg_case_status(&arg_matches)?;
-                    let categories = parse_arg_categories(&arg_matches)?;
+                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
+                    let categories = parse_arg_categories(arg_matches)?;
+                    let primary_category = parse_arg_primary_category(arg_matches)?;
+                    let expires_at = parse_arg_expires_at(arg_matches)?;
 
-                    cmd_update_case(
+                    cmd_report_address(
                         &rpc_client,
                         &config,
                         community_name,
+                        network_name,
                         case_id,
-                        case_status,
-                        categories,
+                        &AddressReport {
+                            address,
+                            risk,
+                            categories,
+                            primary_category,
+                            expires_at,
+                        },
                     )
                 }
 
                 ("get", Some(arg_matches)) => {
                     let community_name = value_t_or_exit!(arg_matches, "community_name", String);
-                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
-
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
+
+                    cmd_get_address(&rpc_client, &config, community_name, network_name, &address)
+                }
+
+                ("history", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
+
+                    cmd_get_address_history(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        &address,
+                    )
+                }
+
+                ("refresh", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
+
+                    cmd_refresh_address(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        &address,
+                    )
+                }
+
+                ("set-entity", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
+                    let entity_name = arg_matches.value_of("entity_name").map(String::from);
+
+                    cmd_update_address_entity(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        &address,
+                        entity_name,
+                    )
+                }
+
+                _ => subcommand_address
+                    .clone()
+                    .print_long_help()
+                    .map(|_| println!())
+                    .map_err(|e| e.into()),
+            }
+        }
+
+        ("transaction", Some(arg_matches)) => {
+            let (sub_command, sub_matches) = arg_matches.subcommand();
+            match (sub_command, sub_matches) {
+                ("create", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let tx_hash = parse_arg_tx_hash(arg_matches)?;
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
+                    let category = parse_arg_category(arg_matches)?;
+                    let source = pubkey_of(arg_matches, "source");
+                    let destination = pubkey_of(arg_matches, "destination");
+
+                    cmd_create_transaction(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        case_id,
+                        &TransactionReport {
+                            tx_hash,
+                            risk,
+                            category,
+                            source,
+                            destination,
+                        },
+                    )
+                }
+
+                ("get", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let tx_hash = parse_arg_tx_hash(arg_matches)?;
+
+                    cmd_get_transaction(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        &tx_hash,
+                    )
+                }
+
+                _ => subcommand_transaction
+                    .clone()
+                    .print_long_help()
+                    .map(|_| println!())
+                    .map_err(|e| e.into()),
+            }
+        }
+
+        ("asset", Some(arg_matches)) => {
+            let (sub_command, sub_matches) = arg_matches.subcommand();
+            match (sub_command, sub_matches) {
+                ("create", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let asset = pubkey_of(arg_matches, "asset").unwrap();
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
+                    let category = parse_arg_category(arg_matches)?;
+                    let token_id = parse_arg_token_id(arg_matches)?;
+
+                    cmd_create_asset(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        case_id,
+                        &AssetReport {
+                            asset,
+                            token_id,
+                            risk,
+                            category,
+                        },
+                    )
+                }
+
+                ("update", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let asset = pubkey_of(arg_matches, "asset").unwrap();
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
+                    let category = parse_arg_category(arg_matches)?;
+                    let token_id = parse_arg_token_id(arg_matches)?;
+
+                    cmd_update_asset(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        case_id,
+                        &AssetReport {
+                            asset,
+                            token_id,
+                            risk,
+                            category,
+                        },
+                    )
+                }
+
+                ("get", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let asset = pubkey_of(arg_matches, "asset").unwrap();
+                    let token_id = parse_arg_token_id(arg_matches)?;
+
+                    cmd_get_asset(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        &asset,
+                        &token_id,
+                    )
+                }
+
+                _ => subcommand_asset
+                    .clone()
+                    .print_long_help()
+                    .map(|_| println!())
+                    .map_err(|e| e.into()),
+            }
+        }
+
+        ("link", Some(arg_matches)) => {
+            let (sub_command, sub_matches) = arg_matches.subcommand();
+            match (sub_command, sub_matches) {
+                ("create", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let source = pubkey_of(arg_matches, "source").unwrap();
+                    let destination = pubkey_of(arg_matches, "destination").unwrap();
+                    let kind = parse_arg_address_link_kind(arg_matches)?;
+                    let confidence = value_t_or_exit!(arg_matches, "confidence", u8);
+
+                    cmd_create_address_link(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        &source,
+                        &destination,
+                        kind,
+                        confidence,
+                    )
+                }
+
+                ("neighbors", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
+
+                    cmd_list_address_links(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        &address,
+                    )
+                }
+
+                ("propagate", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let decay = value_t_or_exit!(arg_matches, "decay", u8);
+                    let max_depth = value_t_or_exit!(arg_matches, "max_depth", u8);
+                    let min_risk = value_t_or_exit!(arg_matches, "min_risk", f64);
+
+                    if decay > 100 {
+                        return Err("Decay must not exceed 100".into());
+                    }
+
+                    cmd_propagate_risk(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        decay,
+                        max_depth,
+                        min_risk,
+                    )
+                }
+
+                _ => subcommand_link
+                    .clone()
+                    .print_long_help()
+                    .map(|_| println!())
+                    .map_err(|e| e.into()),
+            }
+        }
+
+        ("entity", Some(arg_matches)) => {
+            let (sub_command, sub_matches) = arg_matches.subcommand();
+            match (sub_command, sub_matches) {
+                ("create", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let entity_name = value_t_or_exit!(arg_matches, "entity_name", String);
+                    let website = value_t_or_exit!(arg_matches, "website", String);
+                    let category = parse_arg_category(arg_matches)?;
+                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
+                    let parent_name = arg_matches.value_of("parent_entity").map(String::from);
+
+                    cmd_create_entity(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        entity_name,
+                        website,
+                        category,
+                        risk,
+                        parent_name,
+                    )
+                }
+
+                ("update", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let entity_name = value_t_or_exit!(arg_matches, "entity_name", String);
+                    let website = value_t_or_exit!(arg_matches, "website", String);
+                    let category = parse_arg_category(arg_matches)?;
+                    let risk = value_t_or_exit!(arg_matches, "risk", u8);
+                    let parent_name = arg_matches.value_of("parent_entity").map(String::from);
+
+                    cmd_update_entity(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        entity_name,
+                        website,
+                        category,
+                        risk,
+                        parent_name,
+                    )
+                }
+
+                ("get", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let entity_name = value_t_or_exit!(arg_matches, "entity_name", String);
+
+                    cmd_get_entity(&rpc_client, &config, community_name, entity_name)
+                }
+
+                ("addresses", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let entity_name = value_t_or_exit!(arg_matches, "entity_name", String);
+
+                    cmd_list_entity_addresses(&rpc_client, &config, community_name, entity_name)
+                }
+
+                _ => subcommand_entity
+                    .clone()
+                    .print_long_help()
+                    .map(|_| println!())
+                    .map_err(|e| e.into()),
+            }
+        }
+
+        ("case", Some(arg_matches)) => {
+            let (sub_command, sub_matches) = arg_matches.subcommand();
+            match (sub_command, sub_matches) {
+                ("create", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let case_name = value_t_or_exit!(arg_matches, "case_name", String);
+                    let case_status = parse_arg_case_status(&arg_matches)?;
+                    let categories = parse_arg_categories(&arg_matches)?;
+                    let expires_at = parse_arg_expires_at(&arg_matches)?;
+
+                    cmd_create_case(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        case_name,
+                        case_status,
+                        categories,
+                        expires_at,
+                    )
+                }
+
+                ("update", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+                    let case_name = arg_matches.value_of("case_name").map(String::from);
+                    let case_status = parse_arg_case_status(&arg_matches)?;
+                    let categories = parse_arg_categories(&arg_matches)?;
+                    let expires_at = parse_arg_expires_at(&arg_matches)?;
+
+                    cmd_update_case(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        case_id,
+                        case_name,
+                        case_status,
+                        categories,
+                        expires_at,
+                    )
+                }
+
+                ("get", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+
                     cmd_get_case(&rpc_client, &config, community_name, case_id)
                 }
 
@@ -414,6 +1268,24 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                     cmd_create_community(&rpc_client, &config, community_name, &community_authority)
                 }
 
+                ("update", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let risk_decay_period = value_t_or_exit!(arg_matches, "risk_decay_period", u64);
+                    let new_authority = pubkey_of(arg_matches, "community_authority");
+                    let report_quota = value_of::<u64>(arg_matches, "report_quota");
+                    let report_quota_period = value_of::<u64>(arg_matches, "report_quota_period");
+
+                    cmd_update_community(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        risk_decay_period,
+                        report_quota,
+                        report_quota_period,
+                        new_authority.as_ref(),
+                    )
+                }
+
                 ("get", Some(arg_matches)) => {
                     let community_name = value_t_or_exit!(arg_matches, "community_name", String);
 
@@ -461,6 +1333,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                     let reporter_pubkey = pubkey_of(arg_matches, "reporter_pubkey").unwrap();
                     let reporter_name = value_t_or_exit!(arg_matches, "reporter_name", String);
                     let reporter_type = parse_arg_reporter_type(&arg_matches)?;
+                    let active_until = parse_arg_active_until(arg_matches)?;
 
                     cmd_create_reporter(
                         &rpc_client,
@@ -469,6 +1342,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                         &reporter_pubkey,
                         reporter_name,
                         reporter_type,
+                        active_until,
                     )
                 }
 
@@ -477,6 +1351,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                     let reporter_pubkey = pubkey_of(arg_matches, "reporter_pubkey").unwrap();
                     let reporter_name = value_t_or_exit!(arg_matches, "reporter_name", String);
                     let reporter_type = parse_arg_reporter_type(&arg_matches)?;
+                    let active_until = parse_arg_active_until(arg_matches)?;
 
                     cmd_update_reporter(
                         &rpc_client,
@@ -485,6 +1360,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                         &reporter_pubkey,
                         reporter_name,
                         reporter_type,
+                        active_until,
                     )
                 }
 
@@ -495,6 +1371,60 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                     cmd_get_reporter(&rpc_client, &config, community_name, &reporter_pubkey)
                 }
 
+                ("stats", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let reporter_pubkey = pubkey_of(arg_matches, "reporter_pubkey").unwrap();
+
+                    cmd_get_reporter_stats(&rpc_client, &config, community_name, &reporter_pubkey)
+                }
+
+                ("dispute", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let reporter_pubkey = pubkey_of(arg_matches, "reporter_pubkey").unwrap();
+
+                    cmd_record_lost_dispute(&rpc_client, &config, community_name, &reporter_pubkey)
+                }
+
+                ("expiring", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let within = value_t_or_exit!(arg_matches, "within", i64);
+
+                    cmd_list_expiring_reporters(&rpc_client, &config, community_name, within)
+                }
+
+                ("rotate", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let reporter_pubkey = pubkey_of(arg_matches, "reporter_pubkey").unwrap();
+                    let new_reporter_pubkey =
+                        pubkey_of(arg_matches, "new_reporter_pubkey").unwrap();
+
+                    cmd_rotate_reporter_key(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        &reporter_pubkey,
+                        &new_reporter_pubkey,
+                    )
+                }
+
+                ("delegate", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let delegate_pubkey = pubkey_of(arg_matches, "delegate_pubkey");
+                    let valid_until = arg_matches
+                        .value_of("valid_until")
+                        .map(timestamp_from_string)
+                        .transpose()?
+                        .unwrap_or(0);
+
+                    cmd_set_reporter_delegate(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        delegate_pubkey.as_ref(),
+                        valid_until,
+                    )
+                }
+
                 _ => subcommand_reporter
                     .clone()
                     .print_long_help()
@@ -503,6 +1433,104 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
+        ("set", Some(arg_matches)) => {
+            let (sub_command, sub_matches) = arg_matches.subcommand();
+            match (sub_command, sub_matches) {
+                ("update", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+                    let file = value_t_or_exit!(arg_matches, "file", String);
+
+                    cmd_update_address_set(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        case_id,
+                        file,
+                    )
+                }
+
+                ("proof", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+                    let file = value_t_or_exit!(arg_matches, "file", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
+
+                    cmd_get_address_set_proof(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        case_id,
+                        file,
+                        &address,
+                    )
+                }
+
+                ("get", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let case_id = value_t_or_exit!(arg_matches, "case_id", u64);
+
+                    cmd_get_address_set(&rpc_client, &config, community_name, case_id)
+                }
+
+                _ => subcommand_address_set
+                    .clone()
+                    .print_long_help()
+                    .map(|_| println!())
+                    .map_err(|e| e.into()),
+            }
+        }
+
+        ("filter", Some(arg_matches)) => {
+            let (sub_command, sub_matches) = arg_matches.subcommand();
+            match (sub_command, sub_matches) {
+                ("rebuild", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let file = value_t_or_exit!(arg_matches, "file", String);
+
+                    cmd_rebuild_address_filter(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        file,
+                    )
+                }
+
+                ("export", Some(arg_matches)) => {
+                    let community_name = value_t_or_exit!(arg_matches, "community_name", String);
+                    let network_name = value_t_or_exit!(arg_matches, "network_name", String);
+                    let file = value_t_or_exit!(arg_matches, "file", String);
+
+                    cmd_export_address_filter(
+                        &rpc_client,
+                        &config,
+                        community_name,
+                        network_name,
+                        file,
+                    )
+                }
+
+                ("check", Some(arg_matches)) => {
+                    let file = value_t_or_exit!(arg_matches, "file", String);
+                    let address = pubkey_of(arg_matches, "address").unwrap();
+
+                    cmd_check_address_filter(&config, file, &address)
+                }
+
+                _ => subcommand_address_filter
+                    .clone()
+                    .print_long_help()
+                    .map(|_| println!())
+                    .map_err(|e| e.into()),
+            }
+        }
+
         _ => unreachable!(),
     }
 }
@@ -1,11 +1,47 @@
 use hapi_core_solana::state::enums::CaseStatus;
 
 use {
-    hapi_core_solana::state::enums::{Category, CategorySet, ReporterType},
+    crate::Config,
+    hapi_core_solana::{
+        instruction::with_payer,
+        state::{
+            asset::TokenId,
+            enums::{AddressLinkKind, Category, CategorySet, ReporterType},
+            transaction::TransactionHash,
+        },
+    },
     solana_client::rpc_client::RpcClient,
-    solana_sdk::{program_error::ProgramError, pubkey::Pubkey},
+    solana_sdk::{
+        bs58, clock::UnixTimestamp, instruction::Instruction, program_error::ProgramError,
+        pubkey::Pubkey, signature::Signer, transaction::Transaction,
+    },
 };
 
+/// Sends a transaction of the instruction signed by the keypair. The fee payer, if set,
+/// pays the transaction fee and the rent of the accounts created by the instruction
+pub fn send_instruction(
+    rpc_client: &RpcClient,
+    config: &Config,
+    instruction: Instruction,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut signers = vec![&config.keypair];
+
+    let (instruction, fee_payer) = match &config.fee_payer {
+        Some(fee_payer) => {
+            signers.push(fee_payer);
+            (with_payer(instruction, &fee_payer.pubkey()), fee_payer)
+        }
+        None => (instruction, &config.keypair),
+    };
+
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&fee_payer.pubkey()));
+    let blockhash = rpc_client.get_recent_blockhash()?.0;
+    transaction.try_sign(&signers, blockhash)?;
+    rpc_client.send_and_confirm_transaction_with_spinner(&transaction)?;
+
+    Ok(())
+}
+
 pub fn assert_is_empty_account(
     rpc_client: &RpcClient,
     account_address: &Pubkey,
@@ -113,6 +149,34 @@ pub fn parse_arg_category(
     category_from_string(matches.value_of("category").unwrap())
 }
 
+pub fn parse_arg_primary_category(
+    matches: &clap::ArgMatches,
+) -> Result<Option<Category>, Box<dyn std::error::Error>> {
+    matches
+        .value_of("primary_category")
+        .map(category_from_string)
+        .transpose()
+}
+
+pub const ADDRESS_LINK_KIND_VALUES: &[&str] = &["FundedBy", "SameOwner", "DepositAddress"];
+
+pub fn address_link_kind_from_string(
+    input: &str,
+) -> Result<AddressLinkKind, Box<dyn std::error::Error>> {
+    match input {
+        "FundedBy" => Ok(AddressLinkKind::FundedBy),
+        "SameOwner" => Ok(AddressLinkKind::SameOwner),
+        "DepositAddress" => Ok(AddressLinkKind::DepositAddress),
+        _ => Err("Unknown address link kind".into()),
+    }
+}
+
+pub fn parse_arg_address_link_kind(
+    matches: &clap::ArgMatches,
+) -> Result<AddressLinkKind, Box<dyn std::error::Error>> {
+    address_link_kind_from_string(matches.value_of("link_kind").unwrap())
+}
+
 pub fn parse_arg_reporter_type(
     matches: &clap::ArgMatches,
 ) -> Result<ReporterType, Box<dyn std::error::Error>> {
@@ -124,3 +188,88 @@ pub fn parse_arg_case_status(
 ) -> Result<CaseStatus, Box<dyn std::error::Error>> {
     case_status_from_string(matches.value_of("case_status").unwrap())
 }
+
+/// Parses an RFC 3339 date and time (i.e. 2021-12-31T00:00:00Z) or a Unix timestamp
+pub fn timestamp_from_string(input: &str) -> Result<UnixTimestamp, Box<dyn std::error::Error>> {
+    match input.parse::<UnixTimestamp>() {
+        Ok(timestamp) => Ok(timestamp),
+        Err(_) => Ok(chrono::DateTime::parse_from_rfc3339(input)?.timestamp()),
+    }
+}
+
+pub fn parse_arg_expires_at(
+    matches: &clap::ArgMatches,
+) -> Result<Option<UnixTimestamp>, Box<dyn std::error::Error>> {
+    matches
+        .value_of("expires_at")
+        .map(timestamp_from_string)
+        .transpose()
+}
+
+pub fn parse_arg_active_until(
+    matches: &clap::ArgMatches,
+) -> Result<Option<UnixTimestamp>, Box<dyn std::error::Error>> {
+    matches
+        .value_of("active_until")
+        .map(timestamp_from_string)
+        .transpose()
+}
+
+/// Parses a base58 (i.e. Solana signature) or 0x-prefixed hex (i.e. Ethereum hash) transaction hash
+pub fn tx_hash_from_string(input: &str) -> Result<TransactionHash, Box<dyn std::error::Error>> {
+    let bytes = if let Some(hex) = input.strip_prefix("0x") {
+        bytes_from_hex(hex)?
+    } else {
+        bs58::decode(input).into_vec()?
+    };
+
+    if bytes.is_empty() || bytes.len() > 64 {
+        return Err("Transaction hash must be from 1 to 64 bytes long".into());
+    }
+
+    let mut tx_hash = [0u8; 64];
+    tx_hash[..bytes.len()].copy_from_slice(&bytes);
+    Ok(tx_hash)
+}
+
+pub fn parse_arg_tx_hash(
+    matches: &clap::ArgMatches,
+) -> Result<TransactionHash, Box<dyn std::error::Error>> {
+    tx_hash_from_string(matches.value_of("tx_hash").unwrap())
+}
+
+/// Parses a 0x-prefixed hex or a decimal token ID, the value is stored as big-endian 256-bit number
+pub fn token_id_from_string(input: &str) -> Result<TokenId, Box<dyn std::error::Error>> {
+    let bytes = if let Some(hex) = input.strip_prefix("0x") {
+        bytes_from_hex(hex)?
+    } else {
+        input.parse::<u128>()?.to_be_bytes().to_vec()
+    };
+
+    if bytes.len() > 32 {
+        return Err("Token ID must not exceed 32 bytes".into());
+    }
+
+    let mut token_id = [0u8; 32];
+    token_id[32 - bytes.len()..].copy_from_slice(&bytes);
+    Ok(token_id)
+}
+
+pub fn parse_arg_token_id(
+    matches: &clap::ArgMatches,
+) -> Result<Option<TokenId>, Box<dyn std::error::Error>> {
+    matches
+        .value_of("token_id")
+        .map(token_id_from_string)
+        .transpose()
+}
+
+fn bytes_from_hex(hex: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    if hex.len() & 1 != 0 {
+        return Err("Hex string must have an even length".into());
+    }
+    Ok((0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()?)
+}
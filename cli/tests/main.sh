@@ -78,12 +78,21 @@ solana airdrop 10 $CAROL_KEYPAIR
   $CLI --keypair $AUTHORITY_KEYPAIR community create hapi.one ||
     exception "Can't create community hapi.one"
 
+  echo "==> Setting risk decay period and report quota of community hapi.one"
+  $CLI --keypair $AUTHORITY_KEYPAIR community update hapi.one 31536000 --report-quota 1000 --report-quota-period 86400 ||
+    exception "Can't update community hapi.one"
+
   echo "==> Creating network testcoin"
   $CLI --keypair $AUTHORITY_KEYPAIR network create hapi.one testcoin ||
     exception "Can't create network testcoin"
   $CLI --keypair $AUTHORITY_KEYPAIR network get hapi.one testcoin ||
     exception "Can't view network testcoin"
 
+  echo "==> Attempting to create network that differs only in case"
+  $CLI --keypair $AUTHORITY_KEYPAIR network create HAPI.ONE TestCoin 2>&1 >/dev/null &&
+    exception "Shouldn't be able to create network that differs only in case" ||
+    echo "Passed"
+
   echo "==> Attempting to create network without correct credentials"
   $CLI --keypair $UNINITIALIZED_KEYPAIR network create hapi.one testhereum 2>&1 >/dev/null &&
     exception "Shouldn't be able to create network from uninitialized account" ||
@@ -100,10 +109,14 @@ solana airdrop 10 $CAROL_KEYPAIR
     exception "Can't view reporter Bob"
 
   echo "==> Creating reporter Carol"
-  $CLI --keypair $AUTHORITY_KEYPAIR reporter create hapi.one $(solana-keygen pubkey $CAROL_KEYPAIR) Carol Tracer &&
+  $CLI --keypair $AUTHORITY_KEYPAIR reporter create hapi.one $(solana-keygen pubkey $CAROL_KEYPAIR) Carol Tracer --active-until 2100-01-01T00:00:00Z &&
     $CLI --keypair $AUTHORITY_KEYPAIR reporter get hapi.one $(solana-keygen pubkey $CAROL_KEYPAIR) ||
     exception "Can't view reporter Carol"
 
+  echo "==> Listing reporters with expiring terms"
+  $CLI --keypair $AUTHORITY_KEYPAIR reporter expiring hapi.one --within 3153600000 ||
+    exception "Can't list expiring reporters"
+
   echo "==> Creating case 0"
   $CLI --keypair $ALICE_KEYPAIR case create hapi.one case0 Open &&
     $CLI --keypair $ALICE_KEYPAIR case get hapi.one 0 ||
@@ -119,11 +132,69 @@ solana airdrop 10 $CAROL_KEYPAIR
     exception "Shouldn't be able to report case by tracer" ||
     echo "Passed"
 
+  echo "==> Creating address filter of testcoin"
+  FILTER_FILE=$(mktemp)
+  $CLI --keypair $AUTHORITY_KEYPAIR filter rebuild hapi.one testcoin /dev/null ||
+    exception "Can't create address filter"
+
   echo "==> Reporting address 2Yy2..FRew"
-  $CLI --keypair $CAROL_KEYPAIR address create hapi.one testcoin 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew 1 5 Theft &&
+  $CLI --keypair $CAROL_KEYPAIR address create hapi.one testcoin 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew 1 5 --category Theft --primary-category Theft &&
     $CLI --keypair $CAROL_KEYPAIR address get hapi.one testcoin 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew ||
     exception "Can't view address 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"
 
+  echo "==> Importing addresses of Alice and Bob"
+  IMPORT_FILE=$(mktemp)
+  echo "$(solana-keygen pubkey $ALICE_KEYPAIR) 7 Theft Theft
+$(solana-keygen pubkey $BOB_KEYPAIR) 3 Theft" >$IMPORT_FILE
+  $CLI --keypair $CAROL_KEYPAIR address import hapi.one testcoin 1 $IMPORT_FILE ||
+    exception "Can't import addresses"
+  rm $IMPORT_FILE
+
+  echo "==> Committing address set of case 1"
+  SET_FILE=$(mktemp)
+  echo "$(solana-keygen pubkey $NOBODY_KEYPAIR) 9 Theft
+$(solana-keygen pubkey $CAROL_KEYPAIR) 2 Theft" >$SET_FILE
+  $CLI --keypair $BOB_KEYPAIR set update hapi.one testcoin 1 $SET_FILE &&
+    $CLI --keypair $BOB_KEYPAIR set proof hapi.one testcoin 1 $SET_FILE $(solana-keygen pubkey $NOBODY_KEYPAIR) ||
+    exception "Can't commit address set of case 1"
+  rm $SET_FILE
+
+  echo "==> Reporting address of Alice again"
+  $CLI --keypair $CAROL_KEYPAIR address report hapi.one testcoin $(solana-keygen pubkey $ALICE_KEYPAIR) 1 9 --category Theft --primary-category Theft &&
+    $CLI --keypair $CAROL_KEYPAIR address history hapi.one testcoin $(solana-keygen pubkey $ALICE_KEYPAIR) ||
+    exception "Can't report address of Alice"
+
+  echo "==> Screening addresses with the exported address filter"
+  $CLI --keypair $CAROL_KEYPAIR filter export hapi.one testcoin $FILTER_FILE &&
+    $CLI filter check $FILTER_FILE 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew | grep "May be reported" &&
+    $CLI filter check $FILTER_FILE $(solana-keygen pubkey $NOBODY_KEYPAIR) ||
+    exception "Can't screen addresses with the address filter"
+  rm $FILTER_FILE
+
+  echo "==> Linking address 2Yy2..FRew to nobody"
+  $CLI --keypair $CAROL_KEYPAIR link create hapi.one testcoin 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew $(solana-keygen pubkey $NOBODY_KEYPAIR) FundedBy 80 &&
+    $CLI --keypair $CAROL_KEYPAIR link neighbors hapi.one testcoin 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew ||
+    exception "Can't view links of address 2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"
+
+  echo "==> Propagating risk over address links"
+  $CLI --keypair $CAROL_KEYPAIR link propagate hapi.one testcoin ||
+    exception "Can't propagate risk"
+
+  echo "==> Recording a lost dispute of Carol"
+  $CLI --keypair $AUTHORITY_KEYPAIR reporter dispute hapi.one $(solana-keygen pubkey $CAROL_KEYPAIR) &&
+    $CLI --keypair $AUTHORITY_KEYPAIR reporter stats hapi.one $(solana-keygen pubkey $CAROL_KEYPAIR) ||
+    exception "Can't view stats of reporter Carol"
+
+  echo "==> Delegating reports of Bob"
+  $CLI --keypair $BOB_KEYPAIR reporter delegate hapi.one $(solana-keygen pubkey $NOBODY_KEYPAIR) --valid-until 2100-01-01T00:00:00Z &&
+    $CLI --keypair $BOB_KEYPAIR reporter delegate hapi.one ||
+    exception "Can't delegate reports of Bob"
+
+  echo "==> Creating case 2 paid by the authority"
+  $CLI --keypair $ALICE_KEYPAIR --fee-payer $AUTHORITY_KEYPAIR case create hapi.one case2 Open &&
+    $CLI --keypair $ALICE_KEYPAIR case get hapi.one 2 ||
+    exception "Can't view case2"
+
   set -e
 )
 
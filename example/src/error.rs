@@ -0,0 +1,41 @@
+//! Error types
+
+use {
+    num_derive::FromPrimitive,
+    solana_program::{
+        decode_error::DecodeError,
+        msg,
+        program_error::{PrintProgramError, ProgramError},
+    },
+    thiserror::Error,
+};
+
+/// Errors that may be returned by the example program
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum ExampleError {
+    /// Destination is reported to HAPI as a risky address
+    #[error("RiskyDestination")]
+    RiskyDestination,
+
+    /// Network account is not the trusted HAPI network
+    #[error("InvalidHapiNetwork")]
+    InvalidHapiNetwork,
+}
+
+impl From<ExampleError> for ProgramError {
+    fn from(e: ExampleError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl<T> DecodeError<T> for ExampleError {
+    fn type_of() -> &'static str {
+        "ExampleError"
+    }
+}
+
+impl PrintProgramError for ExampleError {
+    fn print<E>(&self) {
+        msg!("EXAMPLE-ERROR: {}", &self.to_string());
+    }
+}
@@ -0,0 +1,52 @@
+//! Instruction types
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    hapi_core_solana::state::address::get_address_address,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_program,
+    },
+};
+
+use crate::{id, HAPI_NETWORK};
+
+/// Instructions supported by the example program
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum ExampleInstruction {
+    /// Transfers lamports if the destination is not risky
+    ///
+    /// 0. `[signer, writable]` Source account
+    /// 1. `[writable]` Destination account
+    /// 2. `[]` HAPI Network account. PDA seeds: ['network', community_address, network_name]
+    /// 3. `[]` HAPI Address account of the destination. PDA seeds: ['address', network_account, destination]
+    /// 4. `[]` System
+    ///
+    Transfer {
+        /// Amount of lamports to transfer
+        amount: u64,
+    },
+}
+
+/// Creates Transfer instruction
+pub fn transfer(source: &Pubkey, destination: &Pubkey, amount: u64) -> Instruction {
+    let address_address = get_address_address(&HAPI_NETWORK, destination);
+
+    let accounts = vec![
+        AccountMeta::new(*source, true),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(HAPI_NETWORK, false),
+        AccountMeta::new_readonly(address_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let instruction = ExampleInstruction::Transfer { amount };
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -0,0 +1,28 @@
+#![deny(missing_docs)]
+
+//! Example program that transfers lamports only to addresses that are not risky according to HAPI
+
+pub mod entrypoint;
+pub mod error;
+pub mod instruction;
+pub mod processor;
+
+pub use solana_program;
+
+use solana_program::pubkey::Pubkey;
+
+solana_program::declare_id!("HMBGoWnTeNxstidWgsuVVxUiBWX8tvQYRhWiwDaUqYuw");
+
+/// Name of the HAPI community the program trusts
+pub const HAPI_COMMUNITY_NAME: &str = "hapi.one";
+
+/// Name of the HAPI network the program trusts
+pub const HAPI_NETWORK_NAME: &str = "solana";
+
+/// HAPI Network account the program trusts, the PDA of the community and network names above.
+/// It's computed off-chain to save the PDA derivation on every transfer
+pub const HAPI_NETWORK: Pubkey =
+    solana_program::pubkey!("TxLYqwnTFzatPET6pq22rrNQEdh7Z5KZvAAYnW5geg1");
+
+/// Destinations with HAPI risk score at or above the threshold are rejected
+pub const RISK_THRESHOLD: u8 = 5;
@@ -0,0 +1,71 @@
+//! Program processor
+
+use {
+    borsh::BorshDeserialize,
+    hapi_core_solana::{consumer::get_address_view, state::network::assert_is_valid_network},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        clock::Clock,
+        entrypoint::ProgramResult,
+        msg,
+        program::invoke,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        system_instruction,
+        sysvar::Sysvar,
+    },
+};
+
+use crate::{error::ExampleError, instruction::ExampleInstruction, HAPI_NETWORK, RISK_THRESHOLD};
+
+/// Processes an instruction
+pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    let instruction = ExampleInstruction::try_from_slice(input)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    msg!("EXAMPLE-INSTRUCTION: {:?}", instruction);
+
+    match instruction {
+        ExampleInstruction::Transfer { amount } => process_transfer(program_id, accounts, amount),
+    }
+}
+
+fn process_transfer(_program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let source_info = next_account_info(account_info_iter)?; // 0
+    let destination_info = next_account_info(account_info_iter)?; // 1
+    let network_info = next_account_info(account_info_iter)?; // 2
+    let address_info = next_account_info(account_info_iter)?; // 3
+    let system_info = next_account_info(account_info_iter)?; // 4
+
+    // Only the network of the trusted community can be used for the check
+    if *network_info.key != HAPI_NETWORK {
+        msg!("Network account is not the trusted HAPI network");
+        return Err(ExampleError::InvalidHapiNetwork.into());
+    }
+    assert_is_valid_network(network_info)?;
+
+    // Risk decay isn't applied as the community account is not passed, expired reports bear no risk
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(address_view) =
+        get_address_view(address_info, network_info.key, destination_info.key, now, 0)?
+    {
+        if address_view.is_risky(RISK_THRESHOLD) {
+            msg!(
+                "Destination is reported with risk {} in case {}",
+                address_view.effective_risk,
+                address_view.case_id
+            );
+            return Err(ExampleError::RiskyDestination.into());
+        }
+    }
+
+    invoke(
+        &system_instruction::transfer(source_info.key, destination_info.key, amount),
+        &[
+            source_info.clone(),
+            destination_info.clone(),
+            system_info.clone(),
+        ],
+    )
+}
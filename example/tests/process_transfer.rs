@@ -0,0 +1,235 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        instruction::{
+            create_address, create_case, create_community, create_network, create_reporter,
+        },
+        state::{
+            community::get_community_address,
+            enums::{CaseStatus, Category, ReporterType},
+            network::get_network_address,
+        },
+    },
+    hapi_core_solana_example::{
+        error::ExampleError, instruction::transfer, processor::process, HAPI_COMMUNITY_NAME,
+        HAPI_NETWORK, HAPI_NETWORK_NAME, RISK_THRESHOLD,
+    },
+    solana_program::{
+        instruction::{Instruction, InstructionError},
+        pubkey::Pubkey,
+        system_instruction,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+        transport::TransportError,
+    },
+};
+
+struct ExampleProgramTest {
+    context: ProgramTestContext,
+    reporter_keypair: Keypair,
+}
+
+impl ExampleProgramTest {
+    async fn start_new() -> Self {
+        let mut program_test = ProgramTest::new(
+            "hapi_core_solana_example",
+            hapi_core_solana_example::id(),
+            processor!(process),
+        );
+        program_test.add_program(
+            "hapi_core_solana",
+            hapi_core_solana::id(),
+            processor!(hapi_core_solana::processor::process),
+        );
+
+        let mut context = program_test.start_with_context().await;
+        let reporter_keypair = Keypair::new();
+
+        let authority = context.payer.pubkey();
+        let reporter = reporter_keypair.pubkey();
+
+        process_transaction(
+            &mut context,
+            &[
+                create_community(&authority, HAPI_COMMUNITY_NAME).unwrap(),
+                create_network(
+                    &authority,
+                    &format!("{}/{}", HAPI_COMMUNITY_NAME, HAPI_NETWORK_NAME),
+                )
+                .unwrap(),
+                create_reporter(
+                    &authority,
+                    HAPI_COMMUNITY_NAME,
+                    "Reporter",
+                    &reporter,
+                    ReporterType::Full,
+                    None,
+                )
+                .unwrap(),
+                system_instruction::transfer(&authority, &reporter, 1000000000),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+
+        let create_case_ix = create_case(
+            &reporter,
+            HAPI_COMMUNITY_NAME,
+            0,
+            "Case",
+            CaseStatus::Open,
+            &(Category::Theft as u32),
+            None,
+        )
+        .unwrap();
+
+        process_transaction(&mut context, &[create_case_ix], Some(&reporter_keypair))
+            .await
+            .unwrap();
+
+        Self {
+            context,
+            reporter_keypair,
+        }
+    }
+
+    async fn with_address(&mut self, risk: u8) -> Pubkey {
+        let address = Pubkey::new_unique();
+
+        let create_address_ix = create_address(
+            &self.reporter_keypair.pubkey(),
+            &format!("{}/{}", HAPI_COMMUNITY_NAME, HAPI_NETWORK_NAME),
+            &address,
+            0,
+            risk,
+            &(Category::Theft as u32),
+            Some(Category::Theft),
+            None,
+        )
+        .unwrap();
+
+        process_transaction(
+            &mut self.context,
+            &[create_address_ix],
+            Some(&self.reporter_keypair),
+        )
+        .await
+        .unwrap();
+
+        address
+    }
+
+    async fn transfer(&mut self, destination: &Pubkey, amount: u64) -> Result<(), TransportError> {
+        let transfer_ix = transfer(&self.context.payer.pubkey(), destination, amount);
+
+        process_transaction(&mut self.context, &[transfer_ix], None).await
+    }
+
+    async fn get_balance(&mut self, address: &Pubkey) -> u64 {
+        self.context
+            .banks_client
+            .get_balance(*address)
+            .await
+            .unwrap()
+    }
+}
+
+async fn process_transaction(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signer: Option<&Keypair>,
+) -> Result<(), TransportError> {
+    let mut transaction = Transaction::new_with_payer(instructions, Some(&context.payer.pubkey()));
+
+    let mut signers = vec![&context.payer];
+    if let Some(signer) = signer {
+        signers.push(signer);
+    }
+
+    let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+
+    transaction.sign(&signers, recent_blockhash);
+
+    context.banks_client.process_transaction(transaction).await
+}
+
+#[test]
+fn test_pinned_network_matches_names() {
+    let community_address = get_community_address(HAPI_COMMUNITY_NAME);
+
+    assert_eq!(
+        HAPI_NETWORK,
+        get_network_address(&community_address, HAPI_NETWORK_NAME),
+        "Pinned network must be the network of the trusted community"
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_to_safe_destination() {
+    // Arrange
+    let mut example_test = ExampleProgramTest::start_new().await;
+    let destination = example_test.with_address(RISK_THRESHOLD - 1).await;
+
+    // Act
+    example_test.transfer(&destination, 1000).await.unwrap();
+
+    // Assert
+    assert_eq!(
+        1000,
+        example_test.get_balance(&destination).await,
+        "Destination must receive the transfer"
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_to_unreported_destination() {
+    // Arrange
+    let mut example_test = ExampleProgramTest::start_new().await;
+    let destination = Pubkey::new_unique();
+
+    // Act
+    example_test.transfer(&destination, 1000).await.unwrap();
+
+    // Assert
+    assert_eq!(
+        1000,
+        example_test.get_balance(&destination).await,
+        "Destination must receive the transfer"
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_to_risky_destination_rejected() {
+    // Arrange
+    let mut example_test = ExampleProgramTest::start_new().await;
+    let destination = example_test.with_address(RISK_THRESHOLD).await;
+
+    // Act
+    let err = example_test
+        .transfer(&destination, 1000)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert!(
+        matches!(
+            err,
+            TransportError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(code),
+            )) if code == ExampleError::RiskyDestination as u32
+        ),
+        "Transfer to a risky destination must be rejected"
+    );
+    assert_eq!(
+        0,
+        example_test.get_balance(&destination).await,
+        "Destination must not receive the transfer"
+    );
+}
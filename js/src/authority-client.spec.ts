@@ -73,7 +73,7 @@ describe("AuthorityClient", () => {
 
     it("should throw - invalid name", async () => {
       await expect(() =>
-        client.createCommunity("loooooooooooooooooooooooooooooooooong")
+        client.createCommunity("hapi/one")
       ).rejects.toThrowErrorMatchingSnapshot();
     });
 
@@ -101,7 +101,7 @@ describe("AuthorityClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["Bwv5tFYijy58tNWKDv64Rs2i1Wun5C6LbxQEL2N35J59"],
+        ["3o6ufjMeCuTJ1VuAtTy7Byh4wysRBr9yV5NjcJFwwLRo"],
         {
           context: { slot: 33 },
           value: {
@@ -119,7 +119,7 @@ describe("AuthorityClient", () => {
 
       const { account, data } = await client.createCommunity("hapi.test");
       expect(account.toString()).toEqual(
-        "Bwv5tFYijy58tNWKDv64Rs2i1Wun5C6LbxQEL2N35J59"
+        "3o6ufjMeCuTJ1VuAtTy7Byh4wysRBr9yV5NjcJFwwLRo"
       );
       expect(stringify(data)).toEqual(
         stringify({
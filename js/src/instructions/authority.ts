@@ -5,6 +5,7 @@ import {
 } from "@solana/web3.js";
 
 import { Community, Network, Reporter, ReporterType } from "../state";
+import { isValidName, MAX_NAME_LENGTH, u64 } from "../utils";
 import {
   CreateCommunityIx,
   CreateNetworkIx,
@@ -22,8 +23,10 @@ export async function createCommunityInstruction({
   payer: PublicKey;
   communityName: string;
 }): Promise<TransactionInstruction> {
-  if (Buffer.from(communityName).length > 28) {
-    throw new Error("Community name length should not be over 28 bytes");
+  if (!isValidName(communityName)) {
+    throw new Error(
+      `Community name must be 1 to ${MAX_NAME_LENGTH} printable characters other than '/'`
+    );
   }
   const [communityAddress] = await Community.getAddress(
     programId,
@@ -58,8 +61,10 @@ export async function createNetworkInstructions({
   communityName: string;
   networkName: string;
 }): Promise<TransactionInstruction> {
-  if (Buffer.from(networkName).length > 28) {
-    throw new Error("Network name length should not be over 28 bytes");
+  if (!isValidName(networkName)) {
+    throw new Error(
+      `Network name must be 1 to ${MAX_NAME_LENGTH} printable characters other than '/'`
+    );
   }
 
   const [communityAddress] = await Community.getAddress(
@@ -98,6 +103,7 @@ export async function createReporterInstructions({
   reporterPubkey,
   reporterType,
   reporterName,
+  activeUntil,
 }: {
   programId: PublicKey;
   payer: PublicKey;
@@ -105,6 +111,7 @@ export async function createReporterInstructions({
   reporterPubkey: PublicKey;
   reporterType: ReporterType;
   reporterName: string;
+  activeUntil?: u64;
 }): Promise<TransactionInstruction> {
   if (Buffer.from(reporterName).length > 28) {
     throw new Error("Reporter name length should not be over 28 bytes");
@@ -124,6 +131,7 @@ export async function createReporterInstructions({
   const ix = new CreateReporterIx({
     name: reporterName,
     reporterType,
+    activeUntil,
   });
 
   const keys: AccountMeta[] = [
@@ -150,6 +158,7 @@ export async function updateReporterInstructions({
   reporterPubkey,
   reporterType,
   reporterName,
+  activeUntil,
 }: {
   programId: PublicKey;
   payer: PublicKey;
@@ -157,9 +166,12 @@ export async function updateReporterInstructions({
   reporterPubkey: PublicKey;
   reporterType: ReporterType;
   reporterName: string;
+  activeUntil?: u64;
 }): Promise<TransactionInstruction> {
-  if (Buffer.from(reporterName).length > 28) {
-    throw new Error("Reporter name length should not be over 28 bytes");
+  if (Buffer.from(reporterName).length > MAX_NAME_LENGTH) {
+    throw new Error(
+      `Reporter name length should not be over ${MAX_NAME_LENGTH} bytes`
+    );
   }
 
   const [communityAddress] = await Community.getAddress(
@@ -176,13 +188,15 @@ export async function updateReporterInstructions({
   const ix = new UpdateReporterIx({
     name: reporterName,
     reporterType,
+    activeUntil,
   });
 
   const keys: AccountMeta[] = [
-    { pubkey: payer, isSigner: true, isWritable: false },
-    { pubkey: communityAddress, isSigner: false, isWritable: true },
-    { pubkey: reporterPubkey, isSigner: false, isWritable: false },
+    { pubkey: payer, isSigner: true, isWritable: true },
+    { pubkey: communityAddress, isSigner: false, isWritable: false },
     { pubkey: reporterAddress, isSigner: false, isWritable: true },
+    { pubkey: reporterPubkey, isSigner: false, isWritable: false },
+    ...SYSTEM_RENT_KEYS,
   ];
 
   const instruction = new TransactionInstruction({
@@ -49,6 +49,31 @@ export function categoryToBinary(category: Category): u8 {
   return new u8(Categories.indexOf(category));
 }
 
+/**
+ * Check that address categories are known and the primary category is one of them
+ * @param categories Address categories
+ * @param primaryCategory Primary category of the address
+ **/
+export function assertAddressCategoriesAreValid(
+  categories: Category[],
+  primaryCategory?: Category
+): void {
+  categories.forEach((category) => {
+    if (Categories.indexOf(category) < 0) {
+      throw new Error(`Unknown category: ${category}`);
+    }
+  });
+
+  if (
+    primaryCategory !== undefined &&
+    categories.indexOf(primaryCategory) < 0
+  ) {
+    throw new Error(
+      `Primary category ${primaryCategory} must be one of the categories`
+    );
+  }
+}
+
 export const SYSTEM_RENT_KEYS: AccountMeta[] = [
   {
     pubkey: SystemProgram.programId,
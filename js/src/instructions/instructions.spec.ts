@@ -33,17 +33,23 @@ describe("instructions", () => {
   });
 
   it("UpdateCommunityIx - should encode/decode", () => {
-    const ix = new UpdateCommunityIx({ name: "unhapi.one" });
+    const ix = new UpdateCommunityIx({
+      riskDecayPeriod: new u64(86400),
+      reportQuota: new u64(10),
+      reportQuotaPeriod: new u64(3600),
+    });
 
     const encoded = ix.encode();
 
     expect(encoded.toString("base64")).toMatchInlineSnapshot(
-      `"AQoAAAB1bmhhcGkub25l"`
+      `"AYBRAQAAAAAACgAAAAAAAAAQDgAAAAAAAA=="`
     );
 
     expect(UpdateCommunityIx.decode(encoded)).toMatchInlineSnapshot(`
       UpdateCommunityIx {
-        "name": "unhapi.one",
+        "reportQuota": "0a",
+        "reportQuotaPeriod": "0e10",
+        "riskDecayPeriod": "015180",
         "tag": 1,
       }
     `);
@@ -75,11 +81,12 @@ describe("instructions", () => {
     const encoded = ix.encode();
 
     expect(encoded.toString("base64")).toMatchInlineSnapshot(
-      `"BAMIAAAAcmVwb3J0ZXI="`
+      `"BAMIAAAAcmVwb3J0ZXIA"`
     );
 
     expect(CreateReporterIx.decode(encoded)).toMatchInlineSnapshot(`
       CreateReporterIx {
+        "activeUntil": undefined,
         "name": "reporter",
         "reporterType": 3,
         "tag": 4,
@@ -91,16 +98,18 @@ describe("instructions", () => {
     const ix = new UpdateReporterIx({
       name: "updated reporter",
       reporterType: ReporterType.Full,
+      activeUntil: new u64(1700000000),
     });
 
     const encoded = ix.encode();
 
     expect(encoded.toString("base64")).toMatchInlineSnapshot(
-      `"BQIQAAAAdXBkYXRlZCByZXBvcnRlcg=="`
+      `"BQIQAAAAdXBkYXRlZCByZXBvcnRlcgEA8VNlAAAAAA=="`
     );
 
     expect(UpdateReporterIx.decode(encoded)).toMatchInlineSnapshot(`
       UpdateReporterIx {
+        "activeUntil": "6553f100",
         "name": "updated reporter",
         "reporterType": 2,
         "tag": 5,
@@ -119,13 +128,14 @@ describe("instructions", () => {
     const encoded = ix.encode();
 
     expect(encoded.toString("base64")).toMatchInlineSnapshot(
-      `"BgEAAAAAAAAALAEAAAAXAAAAb3BlbiBhbmQgc2h1dCwgSm9obnNvbiE="`
+      `"BgEAAAAAAAAALAEAAAAXAAAAb3BlbiBhbmQgc2h1dCwgSm9obnNvbiEA"`
     );
 
     expect(CreateCaseIx.decode(encoded)).toMatchInlineSnapshot(`
       CreateCaseIx {
         "caseId": "01",
         "categories": 300,
+        "expiresAt": undefined,
         "name": "open and shut, Johnson!",
         "status": 0,
         "tag": 6,
@@ -135,17 +145,25 @@ describe("instructions", () => {
 
   it("UpdateCaseIx - should encode/decode", () => {
     const ix = new UpdateCaseIx({
+      caseId: new u64(1),
       status: CaseStatus.Closed,
       categories: new u32(0),
+      name: "renamed case",
+      expiresAt: new u64(1700000000),
     });
 
     const encoded = ix.encode();
 
-    expect(encoded.toString("base64")).toMatchInlineSnapshot(`"BwAAAAAB"`);
+    expect(encoded.toString("base64")).toMatchInlineSnapshot(
+      `"BwEAAAAAAAAAAAAAAAEMAAAAcmVuYW1lZCBjYXNlAQDxU2UAAAAA"`
+    );
 
     expect(UpdateCaseIx.decode(encoded)).toMatchInlineSnapshot(`
       UpdateCaseIx {
+        "caseId": "01",
         "categories": 0,
+        "expiresAt": "6553f100",
+        "name": "renamed case",
         "status": 1,
         "tag": 7,
       }
@@ -157,13 +175,14 @@ describe("instructions", () => {
       address: Buffer.alloc(32),
       risk: 5,
       caseId: new u64(1),
-      category: new u8(Categories.indexOf(Category.Mixer)),
+      categories: new u32(Category.Mixer | Category.Scam),
+      primaryCategory: new u8(Categories.indexOf(Category.Mixer)),
     });
 
     const encoded = ix.encode();
 
     expect(encoded.toString("base64")).toMatchInlineSnapshot(
-      `"CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAACw=="`
+      `"CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAAABQAAAELAA=="`
     );
 
     expect(CreateAddressIx.decode(encoded)).toMatchInlineSnapshot(`
@@ -203,7 +222,9 @@ describe("instructions", () => {
           0,
         ],
         "caseId": "01",
-        "category": 11,
+        "categories": 5120,
+        "expiresAt": undefined,
+        "primaryCategory": 11,
         "risk": 5,
         "tag": 8,
       }
@@ -212,24 +233,62 @@ describe("instructions", () => {
 
   it("UpdateAddressIx - should encode/decode", () => {
     const ix = new UpdateAddressIx({
+      address: Buffer.alloc(32),
       risk: 5,
       caseId: new u64(1),
-      category: new u8(Categories.indexOf(Category.Mixer)),
+      categories: new u32(Category.Mixer),
+      expiresAt: new u64(1700000000),
     });
 
     const encoded = ix.encode();
 
     expect(encoded.toString("base64")).toMatchInlineSnapshot(
-      `"CQUBAAAAAAAAAAs="`
+      `"CQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAAAAQAAAABAPFTZQAAAAA="`
     );
 
     expect(UpdateAddressIx.decode(encoded)).toMatchInlineSnapshot(`
-UpdateAddressIx {
-  "caseId": "01",
-  "category": 11,
-  "risk": 5,
-  "tag": 9,
-}
-`);
+      UpdateAddressIx {
+        "address": Uint8Array [
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+          0,
+        ],
+        "caseId": "01",
+        "categories": 1024,
+        "expiresAt": "6553f100",
+        "primaryCategory": undefined,
+        "risk": 5,
+        "tag": 9,
+      }
+    `);
   });
 });
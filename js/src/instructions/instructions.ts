@@ -19,14 +19,18 @@ PROGRAM_SCHEMA.set(CreateCommunityIx, {
 
 export class UpdateCommunityIx extends Struct {
   protected tag = HapiInstruction.UpdateCommunity;
-  name: string;
+  riskDecayPeriod: u64;
+  reportQuota: u64;
+  reportQuotaPeriod: u64;
 }
 
 PROGRAM_SCHEMA.set(UpdateCommunityIx, {
   kind: "struct",
   fields: [
     ["tag", "u8"],
-    ["name", "string"],
+    ["riskDecayPeriod", "u64"],
+    ["reportQuota", "u64"],
+    ["reportQuotaPeriod", "u64"],
   ],
 });
 
@@ -47,6 +51,7 @@ export class CreateReporterIx extends Struct {
   protected tag = HapiInstruction.CreateReporter;
   reporterType: ReporterType;
   name: string;
+  activeUntil?: u64;
 }
 
 PROGRAM_SCHEMA.set(CreateReporterIx, {
@@ -55,6 +60,7 @@ PROGRAM_SCHEMA.set(CreateReporterIx, {
     ["tag", "u8"],
     ["reporterType", "u8"],
     ["name", "string"],
+    ["activeUntil", { kind: "option", type: "u64" }],
   ],
 });
 
@@ -62,6 +68,7 @@ export class UpdateReporterIx extends Struct {
   protected tag = HapiInstruction.UpdateReporter;
   reporterType: ReporterType;
   name: string;
+  activeUntil?: u64;
 }
 
 PROGRAM_SCHEMA.set(UpdateReporterIx, {
@@ -70,6 +77,7 @@ PROGRAM_SCHEMA.set(UpdateReporterIx, {
     ["tag", "u8"],
     ["reporterType", "u8"],
     ["name", "string"],
+    ["activeUntil", { kind: "option", type: "u64" }],
   ],
 });
 
@@ -79,6 +87,7 @@ export class CreateCaseIx extends Struct {
   categories: u32;
   status: number;
   name: string;
+  expiresAt?: u64;
 }
 
 PROGRAM_SCHEMA.set(CreateCaseIx, {
@@ -89,21 +98,28 @@ PROGRAM_SCHEMA.set(CreateCaseIx, {
     ["categories", "u32"],
     ["status", "u8"],
     ["name", "string"],
+    ["expiresAt", { kind: "option", type: "u64" }],
   ],
 });
 
 export class UpdateCaseIx extends Struct {
   protected tag = HapiInstruction.UpdateCase;
+  caseId: u64;
   status: number;
   categories: u32;
+  name: string;
+  expiresAt?: u64;
 }
 
 PROGRAM_SCHEMA.set(UpdateCaseIx, {
   kind: "struct",
   fields: [
     ["tag", "u8"],
+    ["caseId", "u64"],
     ["categories", "u32"],
     ["status", "u8"],
+    ["name", "string"],
+    ["expiresAt", { kind: "option", type: "u64" }],
   ],
 });
 
@@ -112,7 +128,9 @@ export class CreateAddressIx extends Struct {
   address: Uint8Array;
   risk: number;
   caseId: u64;
-  category: u8;
+  categories: u32;
+  primaryCategory?: u8;
+  expiresAt?: u64;
 }
 
 PROGRAM_SCHEMA.set(CreateAddressIx, {
@@ -122,23 +140,31 @@ PROGRAM_SCHEMA.set(CreateAddressIx, {
     ["address", [32]],
     ["risk", "u8"],
     ["caseId", "u64"],
-    ["category", "u8"],
+    ["categories", "u32"],
+    ["primaryCategory", { kind: "option", type: "u8" }],
+    ["expiresAt", { kind: "option", type: "u64" }],
   ],
 });
 
 export class UpdateAddressIx extends Struct {
   protected tag = HapiInstruction.UpdateAddress;
+  address: Uint8Array;
   risk: number;
   caseId: u64;
-  category: u8;
+  categories: u32;
+  primaryCategory?: u8;
+  expiresAt?: u64;
 }
 
 PROGRAM_SCHEMA.set(UpdateAddressIx, {
   kind: "struct",
   fields: [
     ["tag", "u8"],
+    ["address", [32]],
     ["risk", "u8"],
     ["caseId", "u64"],
-    ["category", "u8"],
+    ["categories", "u32"],
+    ["primaryCategory", { kind: "option", type: "u8" }],
+    ["expiresAt", { kind: "option", type: "u64" }],
   ],
 });
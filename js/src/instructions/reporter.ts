@@ -16,6 +16,7 @@ import {
   Categories,
 } from "../state";
 import {
+  assertAddressCategoriesAreValid,
   categoriesToBitmask,
   categoryToBinary,
   SYSTEM_RENT_KEYS,
@@ -35,6 +36,7 @@ export async function createCaseInstruction({
   caseName,
   status,
   categories,
+  expiresAt,
 }: {
   programId: PublicKey;
   payer: PublicKey;
@@ -43,6 +45,7 @@ export async function createCaseInstruction({
   caseName: string;
   status: CaseStatus;
   categories: Category[];
+  expiresAt?: u64;
 }): Promise<TransactionInstruction> {
   if (Buffer.from(caseName).length > 28) {
     throw new Error("Case name length should not be over 28 bytes");
@@ -80,12 +83,13 @@ export async function createCaseInstruction({
     status,
     name: caseName,
     categories: categoriesToBitmask(categories),
+    expiresAt,
   });
 
   const keys: AccountMeta[] = [
     { pubkey: payer, isSigner: true, isWritable: true },
     { pubkey: communityAddress, isSigner: false, isWritable: true },
-    { pubkey: reporterAddress, isSigner: false, isWritable: false },
+    { pubkey: reporterAddress, isSigner: false, isWritable: true },
     { pubkey: caseAddress, isSigner: false, isWritable: true },
     ...SYSTEM_RENT_KEYS,
   ];
@@ -106,6 +110,8 @@ export async function updateCaseInstruction({
   caseId,
   status,
   categories,
+  name,
+  expiresAt,
 }: {
   programId: PublicKey;
   payer: PublicKey;
@@ -113,6 +119,8 @@ export async function updateCaseInstruction({
   caseId: u64;
   status: CaseStatus;
   categories: Category[];
+  name: string;
+  expiresAt?: u64;
 }): Promise<TransactionInstruction> {
   categories.forEach((category) => {
     if (Categories.indexOf(category) < 0) {
@@ -142,8 +150,11 @@ export async function updateCaseInstruction({
   );
 
   const ix = new UpdateCaseIx({
+    caseId,
     status,
     categories: categoriesToBitmask(categories),
+    name,
+    expiresAt,
   });
 
   const keys: AccountMeta[] = [
@@ -151,6 +162,7 @@ export async function updateCaseInstruction({
     { pubkey: communityAddress, isSigner: false, isWritable: false },
     { pubkey: reporterAddress, isSigner: false, isWritable: false },
     { pubkey: caseAddress, isSigner: false, isWritable: true },
+    ...SYSTEM_RENT_KEYS,
   ];
 
   const instruction = new TransactionInstruction({
@@ -170,7 +182,9 @@ export async function createAddressInstruction({
   address,
   caseId,
   risk,
-  category,
+  categories,
+  primaryCategory,
+  expiresAt,
 }: {
   programId: PublicKey;
   payer: PublicKey;
@@ -179,11 +193,11 @@ export async function createAddressInstruction({
   address: PublicKey;
   caseId: u64;
   risk: number;
-  category: Category;
+  categories: Category[];
+  primaryCategory?: Category;
+  expiresAt?: u64;
 }): Promise<TransactionInstruction> {
-  if (Categories.indexOf(category) < 0) {
-    throw new Error(`Unknown category: ${category}`);
-  }
+  assertAddressCategoriesAreValid(categories, primaryCategory);
 
   if (risk < 0 || risk > 10) {
     throw new RangeError(`Risk must have a value between 0 and 10`);
@@ -218,21 +232,32 @@ export async function createAddressInstruction({
     address
   );
 
+  const [addressFilterAddress] = await Network.getAddressFilterAddress(
+    programId,
+    networkAddress
+  );
+
   const ix = new CreateAddressIx({
     address: address.toBytes(),
     risk,
     caseId,
-    category: categoryToBinary(category),
+    categories: categoriesToBitmask(categories),
+    primaryCategory:
+      primaryCategory !== undefined
+        ? categoryToBinary(primaryCategory)
+        : undefined,
+    expiresAt,
   });
 
   const keys: AccountMeta[] = [
     { pubkey: payer, isSigner: true, isWritable: true },
-    { pubkey: communityAddress, isSigner: false, isWritable: true },
+    { pubkey: communityAddress, isSigner: false, isWritable: false },
     { pubkey: networkAddress, isSigner: false, isWritable: false },
-    { pubkey: reporterAddress, isSigner: false, isWritable: false },
+    { pubkey: reporterAddress, isSigner: false, isWritable: true },
     { pubkey: caseAddress, isSigner: false, isWritable: false },
     { pubkey: addressAddress, isSigner: false, isWritable: true },
     ...SYSTEM_RENT_KEYS,
+    { pubkey: addressFilterAddress, isSigner: false, isWritable: true },
   ];
 
   const instruction = new TransactionInstruction({
@@ -252,7 +277,10 @@ export async function updateAddressInstruction({
   address,
   caseId,
   risk,
-  category,
+  categories,
+  primaryCategory,
+  expiresAt,
+  revision,
 }: {
   programId: PublicKey;
   payer: PublicKey;
@@ -261,11 +289,12 @@ export async function updateAddressInstruction({
   address: PublicKey;
   caseId: u64;
   risk: number;
-  category: Category;
+  categories: Category[];
+  primaryCategory?: Category;
+  expiresAt?: u64;
+  revision: u64;
 }): Promise<TransactionInstruction> {
-  if (Categories.indexOf(category) < 0) {
-    throw new Error(`Unknown category: ${category}`);
-  }
+  assertAddressCategoriesAreValid(categories, primaryCategory);
 
   if (risk < 0 || risk > 10) {
     throw new RangeError(`Risk must have a value between 0 and 10`);
@@ -300,19 +329,33 @@ export async function updateAddressInstruction({
     address
   );
 
+  const [addressRevisionAddress] = await Address.getRevisionAddress(
+    programId,
+    addressAddress,
+    revision
+  );
+
   const ix = new UpdateAddressIx({
+    address: address.toBytes(),
     risk,
     caseId,
-    category: categoryToBinary(category),
+    categories: categoriesToBitmask(categories),
+    primaryCategory:
+      primaryCategory !== undefined
+        ? categoryToBinary(primaryCategory)
+        : undefined,
+    expiresAt,
   });
 
   const keys: AccountMeta[] = [
     { pubkey: payer, isSigner: true, isWritable: true },
     { pubkey: communityAddress, isSigner: false, isWritable: false },
     { pubkey: networkAddress, isSigner: false, isWritable: false },
-    { pubkey: reporterAddress, isSigner: false, isWritable: false },
+    { pubkey: reporterAddress, isSigner: false, isWritable: true },
     { pubkey: caseAddress, isSigner: false, isWritable: false },
     { pubkey: addressAddress, isSigner: false, isWritable: true },
+    { pubkey: addressRevisionAddress, isSigner: false, isWritable: true },
+    ...SYSTEM_RENT_KEYS,
   ];
 
   const instruction = new TransactionInstruction({
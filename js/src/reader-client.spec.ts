@@ -28,7 +28,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["mJLCqGmUWWDzYcDVHRAjaDJocoPDLzeimfD7KzwnxFh"],
+        ["8KEKUJhfy4xFpJELSXpSDhkPzd9EuhKN5ihePh8jZsjU"],
         { context: { slot: 173 }, value: null }
       );
 
@@ -41,7 +41,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja"],
+        ["GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU"],
         {
           context: { slot: 280 },
           value: {
@@ -67,14 +67,14 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["3TGzDJHkCHX5R62wtVh1Jr5TNe775jvSCt8tThbizBKQ"],
+        ["6RQ6gvoxAzZfrJTBoQBasgXTgp1PeJ4DjE9nJKatyptX"],
         { context: { slot: 4682 }, value: null }
       );
 
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja"],
+        ["GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU"],
         {
           context: { slot: 280 },
           value: {
@@ -93,7 +93,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["7GiNHuwBKg6URcBJXXXRPRtBVgn5q42AiowBEc8uzqBH"],
+        ["2dYXgMyLmwSGqSn8Cv4XNBjvTD35882jZZX7yJxxVNM3"],
         { context: { slot: 4682 }, value: null }
       );
 
@@ -110,7 +110,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["2viJmmn2pEfd6cogyqdDGWS9YkrVdnx87L994Qo3GwLx"],
+        ["3KqL24GPFysyKyBdXEdMqLSFdcTF91EE2Zd8i37UcNwK"],
         {
           context: { slot: 4682 },
           value: {
@@ -133,7 +133,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["DUALaTZpDYtct73t8D9AirPYnPc4TzSCmqwcNjxmSf9j"],
+        ["8FR6tiXUtFNmSMN7JsqQweCfyc6P95by7PQaNDyCZdaR"],
         { context: { slot: 5254 }, value: null }
       );
 
@@ -151,7 +151,7 @@ describe("ReaderClient", () => {
         mockRpcOk(
           endpoint,
           "getAccountInfo",
-          ["GfwYi1NaoMFJUHzEXtTkXAQewxxqs7PbseAYnsfiNnS7"],
+          ["7iGSudEkSzzBfvVRzjGy3aJnKC4K9mbNBp9SipN71TMQ"],
           {
             context: { slot: 5254 },
             value: {
@@ -179,7 +179,7 @@ describe("ReaderClient", () => {
         mockRpcOk(
           endpoint,
           "getAccountInfo",
-          ["7GFtavi6PpFjqmvhKMtW8D6W4JgFv69VhUurrnBQRbUL"],
+          ["9xv2fVXuv9kpWs5e1vRcZ4xfgx4PpDi3QVmKrg8FvBMo"],
           {
             context: { slot: 5254 },
             value: {
@@ -207,7 +207,7 @@ describe("ReaderClient", () => {
         mockRpcOk(
           endpoint,
           "getAccountInfo",
-          ["DSa28TFYUaomAUNTnRRcsxxmZmvrf1Pfim7obB21m5Jx"],
+          ["3EeAMZz2LHd2aUWKnydJebo6AX9gnntqa6HgfwkvjFU8"],
           {
             context: { slot: 5254 },
             value: {
@@ -237,11 +237,11 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["EGP3s7nD3dFaT9jGtwT7UoZndi58W3VexaJ41N1y5yMm"],
+        ["437ACLd18z8KaawAjm8o6mU9nuHzVrkMYDZPmQMCSFJ3"],
         {
           context: { slot: 8869 },
           value: {
-            data: ["BQUBAAAAAAAAAA8=", "base64"],
+            data: ["BQUBAAAAAAAAAABAAAAAAQ8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=", "base64"],
             executable: false,
             lamports: 967440,
             owner: "hapiScWyxeZy36fqXD5CcRUYFCUdid26jXaakAtcdZ7",
@@ -253,11 +253,11 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["EGP3s7nD3dFaT9jGtwT7UoZndi58W3VexaJ41N1y5yMm"],
+        ["437ACLd18z8KaawAjm8o6mU9nuHzVrkMYDZPmQMCSFJ3"],
         {
           context: { slot: 8869 },
           value: {
-            data: ["BQUBAAAAAAAAAA8=", "base64"],
+            data: ["BQUBAAAAAAAAAABAAAAAAQ8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=", "base64"],
             executable: false,
             lamports: 967440,
             owner: "hapiScWyxeZy36fqXD5CcRUYFCUdid26jXaakAtcdZ7",
@@ -269,7 +269,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["5yGEyZT2WwuLD6BJuvP7TXRcR2ojZTpFVv2TkQm6eX2P"],
+        ["Gpfe5LdbbzYnmkFHRpnPo9xyW5Z6PEoT482VV3C8mLD9"],
         { context: { slot: 5254 }, value: null }
       );
       await expect(() =>
@@ -281,7 +281,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["63G8TLWGQpd26UZj7L9Qr9e3R1MPbybLcW3A7LXtG1Sk"],
+        ["DUgk9JQ24FrRtbikojF83ZSz1DhXJfzFdqNhaaztQXvV"],
         {
           context: { slot: 10001 },
           value: {
@@ -307,7 +307,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["5Q3FZTD9Vs2xXG7GDVeyT1GcdpGfV9ux7XaBWFAfqWWK"],
+        ["J3gCrXsaHE83JmmVuGMeDwfa6o1HTnHELSnxKZNMuHrj"],
         { context: { slot: 6486 }, value: null }
       );
 
@@ -320,7 +320,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["JCoiJf6CgqhvL3WjzUZ1v6j9tSDTVo3VaKBQvVosXmQZ"],
+        ["BBen17VqGQchvG3Frg3oT6BVqFsLTjwb88evf4fgMY2h"],
         { context: { slot: 6911 }, value: null }
       );
 
@@ -343,7 +343,7 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["C4cz5pxVZ9eyqqMYSoRu3DmcCMSjgZ24noo1KukZ9LNF"],
+        ["YGXfcbkx8VKK28XJkiL2EiuzGWxNuH864oDCyn5bGQV"],
         { context: { slot: 6984 }, value: null }
       );
 
@@ -360,11 +360,11 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["EGP3s7nD3dFaT9jGtwT7UoZndi58W3VexaJ41N1y5yMm"],
+        ["437ACLd18z8KaawAjm8o6mU9nuHzVrkMYDZPmQMCSFJ3"],
         {
           context: { slot: 7526 },
           value: {
-            data: ["BQUBAAAAAAAAAA8=", "base64"],
+            data: ["BQUBAAAAAAAAAABAAAAAAQ8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=", "base64"],
             executable: false,
             lamports: 967440,
             owner: "hapiScWyxeZy36fqXD5CcRUYFCUdid26jXaakAtcdZ7",
@@ -385,11 +385,11 @@ describe("ReaderClient", () => {
       mockRpcOk(
         endpoint,
         "getAccountInfo",
-        ["EGP3s7nD3dFaT9jGtwT7UoZndi58W3VexaJ41N1y5yMm"],
+        ["437ACLd18z8KaawAjm8o6mU9nuHzVrkMYDZPmQMCSFJ3"],
         {
           context: { slot: 7526 },
           value: {
-            data: ["BQUBAAAAAAAAAA8=", "base64"],
+            data: ["BQUBAAAAAAAAAABAAAAAAQ8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=", "base64"],
             executable: false,
             lamports: 967440,
             owner: "hapiScWyxeZy36fqXD5CcRUYFCUdid26jXaakAtcdZ7",
@@ -62,7 +62,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "mJLCqGmUWWDzYcDVHRAjaDJocoPDLzeimfD7KzwnxFh",
+        "8KEKUJhfy4xFpJELSXpSDhkPzd9EuhKN5ihePh8jZsjU",
         null
       );
 
@@ -76,7 +76,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -94,7 +94,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -114,7 +114,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -162,6 +162,7 @@ describe("ReporterClient", () => {
         CreateCaseIx {
           "caseId": "0a",
           "categories": 32,
+          "expiresAt": undefined,
           "name": "invalid reporter",
           "status": 0,
           "tag": 6,
@@ -174,7 +175,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -199,7 +200,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "E3NTxagJJwyQrwFC4e2ndpYR3hWoJC59DrQ3aCabjPxo",
+        "CPDkMiSkfFygsYkWS2CoYm79WdtQKHaKwHhoLiDER5mY",
         new Case({
           name: "Slice the dice",
           reporterKey: client.payerPublicKey,
@@ -212,7 +213,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DMYBunz3NcxbuR9yFt8AUCfszJV8VFzm47WWHmisMnhv",
+        "9GxjbKiJDgFBaqQqZUdA4y4wgvYtvq2No4mDV5AkDZMC",
         new Case({
           name: "Slice the dice",
           reporterKey: client.payerPublicKey,
@@ -237,6 +238,7 @@ describe("ReporterClient", () => {
         CreateCaseIx {
           "caseId": "30",
           "categories": 256,
+          "expiresAt": undefined,
           "name": "Slice the dice",
           "status": 0,
           "tag": 6,
@@ -251,7 +253,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "mJLCqGmUWWDzYcDVHRAjaDJocoPDLzeimfD7KzwnxFh",
+        "8KEKUJhfy4xFpJELSXpSDhkPzd9EuhKN5ihePh8jZsjU",
         null
       );
 
@@ -267,7 +269,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -277,7 +279,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "5yGEyZT2WwuLD6BJuvP7TXRcR2ojZTpFVv2TkQm6eX2P",
+        "Gpfe5LdbbzYnmkFHRpnPo9xyW5Z6PEoT482VV3C8mLD9",
         null
       );
 
@@ -293,7 +295,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -303,7 +305,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "6vGsVQ1YMu5zkNUMJ5j5H1TVimfennBcYuYP9hXw1kB2",
+        "3y3x1WgtfytrLDhS2u1qvayJHzxGtxgcat3nb1z4Rr9R",
         new Case({
           name: "super case",
           reporterKey: client.payerPublicKey,
@@ -322,7 +324,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -332,7 +334,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DAgLvQ9zU2K5mseeBjCXubv8bCx41F6zwWbkE2ZeuKY2",
+        "EP1cR5aP7zUthuBHXywz2HN3B8mRrBuL45zrfUw4RvtV",
         new Case({
           name: "super case",
           reporterKey: client.payerPublicKey,
@@ -347,7 +349,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -357,7 +359,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DAgLvQ9zU2K5mseeBjCXubv8bCx41F6zwWbkE2ZeuKY2",
+        "EP1cR5aP7zUthuBHXywz2HN3B8mRrBuL45zrfUw4RvtV",
         new Case({
           name: "super case",
           reporterKey: client.payerPublicKey,
@@ -376,7 +378,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -386,7 +388,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "HqvJ9EVws3tEUuptmXUZpPh3JcJJvoSPBk5LTgaoAtV9",
+        "BBfNn6M6d27xF86yz2ed73zt2X6CLHUXjLmbeL3bRJpX",
         new Case({
           name: "gamble",
           status: CaseStatus.Open,
@@ -414,7 +416,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "HqvJ9EVws3tEUuptmXUZpPh3JcJJvoSPBk5LTgaoAtV9",
+        "BBfNn6M6d27xF86yz2ed73zt2X6CLHUXjLmbeL3bRJpX",
         new Case({
           name: "gamble",
           status: CaseStatus.Closed,
@@ -433,7 +435,10 @@ describe("ReporterClient", () => {
         UpdateCaseIx.decode(getIxFromRawTx(txInput.input().params[0] as string))
       ).toMatchInlineSnapshot(`
         UpdateCaseIx {
+          "caseId": "02",
           "categories": 256,
+          "expiresAt": undefined,
+          "name": "gamble",
           "status": 1,
           "tag": 7,
         }
@@ -447,7 +452,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "mJLCqGmUWWDzYcDVHRAjaDJocoPDLzeimfD7KzwnxFh",
+        "8KEKUJhfy4xFpJELSXpSDhkPzd9EuhKN5ihePh8jZsjU",
         null
       );
 
@@ -456,7 +461,7 @@ describe("ReporterClient", () => {
           "solana",
           new PublicKey("2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"),
           new u64(0),
-          Category.Safe,
+          [Category.Safe],
           0
         )
       ).rejects.toThrowErrorMatchingSnapshot();
@@ -465,7 +470,7 @@ describe("ReporterClient", () => {
     it("should throw - network not found", async () => {
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -475,7 +480,7 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "CkAua1GMeBR3HhKqSyiFtnGZjJm7HsWNq5ynH3wTykC5",
+        "TxLYqwnTFzatPET6pq22rrNQEdh7Z5KZvAAYnW5geg1",
         null
       );
 
@@ -484,7 +489,7 @@ describe("ReporterClient", () => {
           "solana",
           new PublicKey("2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"),
           new u64(0),
-          Category.Safe,
+          [Category.Safe],
           0
         )
       ).rejects.toThrowErrorMatchingSnapshot();
@@ -493,7 +498,7 @@ describe("ReporterClient", () => {
     it("should throw - case not found", async () => {
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -503,13 +508,13 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "CkAua1GMeBR3HhKqSyiFtnGZjJm7HsWNq5ynH3wTykC5",
+        "TxLYqwnTFzatPET6pq22rrNQEdh7Z5KZvAAYnW5geg1",
         new Network({ name: "solana" })
       );
 
       mockRpcAccount(
         endpoint,
-        "5yGEyZT2WwuLD6BJuvP7TXRcR2ojZTpFVv2TkQm6eX2P",
+        "Gpfe5LdbbzYnmkFHRpnPo9xyW5Z6PEoT482VV3C8mLD9",
         null
       );
 
@@ -518,7 +523,7 @@ describe("ReporterClient", () => {
           "solana",
           new PublicKey("2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"),
           new u64(404),
-          Category.Safe,
+          [Category.Safe],
           0
         )
       ).rejects.toThrowErrorMatchingSnapshot();
@@ -527,7 +532,7 @@ describe("ReporterClient", () => {
     it("should throw - invalid category", async () => {
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -537,13 +542,13 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "CkAua1GMeBR3HhKqSyiFtnGZjJm7HsWNq5ynH3wTykC5",
+        "TxLYqwnTFzatPET6pq22rrNQEdh7Z5KZvAAYnW5geg1",
         new Network({ name: "solana" })
       );
 
       mockRpcAccount(
         endpoint,
-        "63G8TLWGQpd26UZj7L9Qr9e3R1MPbybLcW3A7LXtG1Sk",
+        "DUgk9JQ24FrRtbikojF83ZSz1DhXJfzFdqNhaaztQXvV",
         new Case({
           name: "invalid category",
           reporterKey: client.payerPublicKey,
@@ -557,7 +562,7 @@ describe("ReporterClient", () => {
           "solana",
           new PublicKey("2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"),
           new u64(0),
-          99999999,
+          [99999999],
           0
         )
       ).rejects.toThrowErrorMatchingSnapshot();
@@ -566,7 +571,7 @@ describe("ReporterClient", () => {
     it("should throw - invalid risk score", async () => {
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -576,13 +581,13 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "CkAua1GMeBR3HhKqSyiFtnGZjJm7HsWNq5ynH3wTykC5",
+        "TxLYqwnTFzatPET6pq22rrNQEdh7Z5KZvAAYnW5geg1",
         new Network({ name: "solana" })
       );
 
       mockRpcAccount(
         endpoint,
-        "63G8TLWGQpd26UZj7L9Qr9e3R1MPbybLcW3A7LXtG1Sk",
+        "DUgk9JQ24FrRtbikojF83ZSz1DhXJfzFdqNhaaztQXvV",
         new Case({
           name: "invalid category",
           reporterKey: client.payerPublicKey,
@@ -596,7 +601,7 @@ describe("ReporterClient", () => {
           "solana",
           new PublicKey("2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"),
           new u64(0),
-          Category.Safe,
+          [Category.Safe],
           69
         )
       ).rejects.toThrowErrorMatchingSnapshot();
@@ -605,7 +610,7 @@ describe("ReporterClient", () => {
     it("should create an address - success", async () => {
       mockRpcAccount(
         endpoint,
-        "DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja",
+        "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU",
         new Community({
           authority: AUTHORITY.publicKey,
           name: "hapi.one",
@@ -615,13 +620,13 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "CkAua1GMeBR3HhKqSyiFtnGZjJm7HsWNq5ynH3wTykC5",
+        "TxLYqwnTFzatPET6pq22rrNQEdh7Z5KZvAAYnW5geg1",
         new Network({ name: "solana" })
       );
 
       mockRpcAccount(
         endpoint,
-        "63G8TLWGQpd26UZj7L9Qr9e3R1MPbybLcW3A7LXtG1Sk",
+        "DUgk9JQ24FrRtbikojF83ZSz1DhXJfzFdqNhaaztQXvV",
         new Case({
           name: "cool stuff",
           reporterKey: client.payerPublicKey,
@@ -649,11 +654,17 @@ describe("ReporterClient", () => {
 
       mockRpcAccount(
         endpoint,
-        "4SDf7t46o18vMznTRQJWTWLECHUfcAF3u8BnLaHotbET",
+        "D2Vcw9yW33jzhQ3ZZtdMb1VgG8pUpewUKYfthkujPj9H",
         new Address({
           caseId: new u64(0),
           risk: 0,
-          category: Category.Safe,
+          categories: [Category.Safe],
+          entity: null,
+          primaryCategory: null,
+          updatedAt: new u64(0),
+          expiresAt: null,
+          stale: false,
+          revision: new u64(0),
         })
       );
 
@@ -661,7 +672,7 @@ describe("ReporterClient", () => {
         "solana",
         new PublicKey("2Yy2iSPJv4iEMyNkUX7ydFoufSmyPLMc8P9owJopFRew"),
         new u64(0),
-        Category.Safe,
+        [Category.Safe],
         0
       );
 
@@ -708,7 +719,9 @@ describe("ReporterClient", () => {
             24,
           ],
           "caseId": "00",
-          "category": 0,
+          "categories": 0,
+          "expiresAt": undefined,
+          "primaryCategory": undefined,
           "risk": 0,
           "tag": 8,
         }
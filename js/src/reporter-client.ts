@@ -133,16 +133,28 @@ export class ReporterClient extends ReaderClient {
    * @param communityName The name of the community to create
    * @param caseId The ID of the case to update
    * @param categories An array of categories to assign to the case
+   * @param name New case name, the current name is kept if omitted
    * @returns Transaction to sign
    **/
   async updateCaseTransaction(
     caseId: u64,
     status: CaseStatus,
     categories: Category[],
-    communityName?: string
+    communityName?: string,
+    name?: string
   ): Promise<{ transaction: Transaction }> {
     communityName = this.ensureCommunityName(communityName);
 
+    if (name === undefined) {
+      const { data } = await Case.retrieve(
+        this.programId,
+        this.connection,
+        communityName,
+        caseId
+      );
+      name = data.name;
+    }
+
     const transaction = new Transaction();
 
     transaction.add(
@@ -153,6 +165,7 @@ export class ReporterClient extends ReaderClient {
         caseId,
         status,
         categories,
+        name,
       })
     );
 
@@ -165,13 +178,15 @@ export class ReporterClient extends ReaderClient {
    * @param communityName The name of the community to create
    * @param caseID The ID of the case to update
    * @param categories An array of categories to assign to the case
+   * @param name New case name, the current name is kept if omitted
    * @returns Transaction hash, account address and entity data
    **/
   async updateCase(
     caseId: u64,
     status: CaseStatus,
     caseCategories: Category[],
-    communityName?: string
+    communityName?: string,
+    name?: string
   ): Promise<HapiActionResponse<Case>> {
     communityName = this.ensureCommunityName(communityName);
 
@@ -179,13 +194,19 @@ export class ReporterClient extends ReaderClient {
     await Community.retrieve(this.programId, this.connection, communityName);
 
     // Make sure the case exists
-    await Case.retrieve(this.programId, this.connection, communityName, caseId);
+    const { data: caseData } = await Case.retrieve(
+      this.programId,
+      this.connection,
+      communityName,
+      caseId
+    );
 
     const { transaction } = await this.updateCaseTransaction(
       caseId,
       status,
       caseCategories,
-      communityName
+      communityName,
+      name ?? caseData.name
     );
 
     const txHash = await sendAndConfirmTransaction(
@@ -211,7 +232,7 @@ export class ReporterClient extends ReaderClient {
    * @param networkName The name of the network of the address
    * @param address Public key of the address
    * @param caseId The ID of the case to assign to the address
-   * @param category Category to assign to the address
+   * @param categories Categories to assign to the address
    * @param risk Risk score to assign to the address (0 to 10)
    * @returns Transaction to sign
    **/
@@ -219,7 +240,7 @@ export class ReporterClient extends ReaderClient {
     networkName: string,
     address: PublicKey,
     caseId: u64,
-    category: Category,
+    categories: Category[],
     risk: number,
     communityName?: string
   ): Promise<{ transaction: Transaction }> {
@@ -254,7 +275,7 @@ export class ReporterClient extends ReaderClient {
         networkName,
         address,
         caseId,
-        category,
+        categories,
         risk,
       })
     );
@@ -269,7 +290,7 @@ export class ReporterClient extends ReaderClient {
    * @param networkName The name of the network of the address
    * @param address Public key of the address
    * @param caseId The ID of the case to assign to the address
-   * @param category Category to assign to the address
+   * @param categories Categories to assign to the address
    * @param risk Risk score to assign to the address (0 to 10)
    * @returns Transaction hash, account address and entity data
    **/
@@ -277,7 +298,7 @@ export class ReporterClient extends ReaderClient {
     networkName: string,
     address: PublicKey,
     caseId: u64,
-    category: Category,
+    categories: Category[],
     risk: number,
     communityName?: string
   ): Promise<HapiActionResponse<Address>> {
@@ -287,7 +308,7 @@ export class ReporterClient extends ReaderClient {
       networkName,
       address,
       caseId,
-      category,
+      categories,
       risk,
       communityName
     );
@@ -316,7 +337,7 @@ export class ReporterClient extends ReaderClient {
    * @param networkName The name of the network of the address
    * @param address Public key of the address
    * @param caseId The ID of the case to assign to the address
-   * @param category Category to assign to the address
+   * @param categories Categories to assign to the address
    * @param risk Risk score to assign to the address (0 to 10)
    * @returns Transaction to sign
    **/
@@ -324,12 +345,21 @@ export class ReporterClient extends ReaderClient {
     networkName: string,
     address: PublicKey,
     caseId: u64,
-    category: Category,
+    categories: Category[],
     risk: number,
     communityName?: string
   ): Promise<{ transaction: Transaction }> {
     communityName = this.ensureCommunityName(communityName);
 
+    // Replaced values are recorded in the next revision of the address
+    const { data } = await Address.retrieve(
+      this.programId,
+      this.connection,
+      communityName,
+      networkName,
+      address
+    );
+
     const transaction = new Transaction();
 
     transaction.add(
@@ -340,8 +370,9 @@ export class ReporterClient extends ReaderClient {
         networkName,
         address,
         caseId,
-        category,
+        categories,
         risk,
+        revision: data.revision,
       })
     );
 
@@ -355,7 +386,7 @@ export class ReporterClient extends ReaderClient {
    * @param networkName The name of the network of the address
    * @param address Public key of the address
    * @param caseId The ID of the case to assign to the address
-   * @param category Category to assign to the address
+   * @param categories Categories to assign to the address
    * @param risk Risk score to assign to the address (0 to 10)
    * @returns Transaction hash, account address and entity data
    **/
@@ -363,7 +394,7 @@ export class ReporterClient extends ReaderClient {
     networkName: string,
     address: PublicKey,
     caseId: u64,
-    category: Category,
+    categories: Category[],
     risk: number,
     communityName?: string
   ): Promise<HapiActionResponse<Address>> {
@@ -373,7 +404,7 @@ export class ReporterClient extends ReaderClient {
       networkName,
       address,
       caseId,
-      category,
+      categories,
       risk,
       communityName
     );
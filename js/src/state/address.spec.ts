@@ -15,12 +15,19 @@ describe("Address", () => {
   const endpoint = "http://localhost:8899";
   const programId = HAPI_PROGRAM_ID;
 
-  const BINARY_SAMPLE = Buffer.from("BQUBAAAAAAAAAA8=", "base64");
+  const BINARY_SAMPLE = Buffer.from("BQUBAAAAAAAAAABAAAAAAQ8AAAAAAAAAAAAAAAAAAAAAAAD/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==", "base64");
   const ADDRESS_SAMPLE = new Address({
     accountType: HapiAccountType.Address,
     caseId: new u64(1),
     risk: 5,
-    category: Category.Theft,
+    categories: [Category.Theft],
+    entity: null,
+    primaryCategory: Category.Theft,
+    updatedAt: new u64(0),
+    expiresAt: null,
+    stale: false,
+    revision: new u64(0),
+    bumpSeed: 255,
   });
 
   it("should serialize", () => {
@@ -37,11 +44,11 @@ describe("Address", () => {
     mockRpcOk(
       endpoint,
       "getAccountInfo",
-      ["EGP3s7nD3dFaT9jGtwT7UoZndi58W3VexaJ41N1y5yMm"],
+      ["437ACLd18z8KaawAjm8o6mU9nuHzVrkMYDZPmQMCSFJ3"],
       {
         context: { slot: 2223 },
         value: {
-          data: ["BQUBAAAAAAAAAA8=", "base64"],
+          data: ["BQUBAAAAAAAAAABAAAAAAQ8AAAAAAAAAAAAAAAAAAAAAAAD/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==", "base64"],
           executable: false,
           lamports: 967440,
           owner: "hapiScWyxeZy36fqXD5CcRUYFCUdid26jXaakAtcdZ7",
@@ -10,7 +10,14 @@ export class AddressState {
   account_type: HapiAccountType;
   risk: number;
   case_id: u64;
-  category: number;
+  categories: number;
+  entity?: Uint8Array;
+  primary_category?: number;
+  updated_at: u64;
+  expires_at?: u64;
+  stale: number;
+  revision: u64;
+  bump_seed: number;
   constructor(object: Partial<AddressState>) {
     Object.assign(this, object);
   }
@@ -23,12 +30,19 @@ export class AddressState {
           ["account_type", "u8"],
           ["risk", "u8"],
           ["case_id", "u64"],
-          ["category", "u8"],
+          ["categories", "u32"],
+          ["entity", { kind: "option", type: [32] }],
+          ["primary_category", { kind: "option", type: "u8" }],
+          ["updated_at", "u64"],
+          ["expires_at", { kind: "option", type: "u64" }],
+          ["stale", "u8"],
+          ["revision", "u64"],
+          ["bump_seed", "u8"],
         ],
       },
     ],
   ]);
-  static size = 11;
+  static size = 76;
 }
 
 export class Address {
@@ -41,8 +55,29 @@ export class Address {
   /// Case ID
   caseId: u64;
 
-  /// Category
-  category: Category;
+  /// Categories
+  categories: Category[];
+
+  /// Entity account of the address owner
+  entity: PublicKey | null;
+
+  /// Primary category
+  primaryCategory: Category | null;
+
+  /// Time of the last risk update
+  updatedAt: u64;
+
+  /// Time when the report is due for review
+  expiresAt: u64 | null;
+
+  /// Report is expired and no longer bears risk
+  stale: boolean;
+
+  /// Number of revisions recorded by updates
+  revision: u64;
+
+  /// Bump seed of the account PDA
+  bumpSeed = 0;
 
   constructor(data?: Partial<Address>) {
     if (data) {
@@ -61,12 +96,39 @@ export class Address {
     );
   }
 
+  static async getRevisionAddress(
+    programId: PublicKey,
+    addressAddress: PublicKey,
+    revision: u64
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+      [
+        Buffer.from("address_revision"),
+        addressAddress.toBuffer(),
+        new u64(revision).toBuffer(),
+      ],
+      programId
+    );
+  }
+
   static fromState(state: AddressState): Address {
     return new Address({
       accountType: state.account_type,
       risk: state.risk,
       caseId: state.case_id,
-      category: Categories[state.category],
+      categories: Categories.filter(
+        (category) => state.categories & category
+      ).sort(),
+      entity: state.entity ? new PublicKey(state.entity) : null,
+      primaryCategory:
+        state.primary_category !== undefined
+          ? Categories[state.primary_category]
+          : null,
+      updatedAt: state.updated_at,
+      expiresAt: state.expires_at !== undefined ? state.expires_at : null,
+      stale: state.stale !== 0,
+      revision: state.revision,
+      bumpSeed: state.bump_seed,
     });
   }
 
@@ -121,7 +183,19 @@ export class Address {
       account_type: this.accountType,
       risk: this.risk,
       case_id: this.caseId,
-      category: Categories.indexOf(this.category),
+      categories: this.categories.reduce((acc, category) => {
+        return acc | category;
+      }, 0),
+      entity: this.entity ? this.entity.toBytes() : undefined,
+      primary_category:
+        this.primaryCategory !== null
+          ? Categories.indexOf(this.primaryCategory)
+          : undefined,
+      updated_at: this.updatedAt,
+      expires_at: this.expiresAt !== null ? this.expiresAt : undefined,
+      stale: this.stale ? 1 : 0,
+      revision: this.revision,
+      bump_seed: this.bumpSeed,
     });
   }
 }
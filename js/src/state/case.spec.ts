@@ -55,7 +55,7 @@ describe("Case", () => {
     mockRpcOk(
       endpoint,
       "getAccountInfo",
-      ["63G8TLWGQpd26UZj7L9Qr9e3R1MPbybLcW3A7LXtG1Sk"],
+      ["DUgk9JQ24FrRtbikojF83ZSz1DhXJfzFdqNhaaztQXvV"],
       {
         context: { slot: 4131 },
         value: {
@@ -89,7 +89,7 @@ describe("Case", () => {
     mockRpcOk(
       endpoint,
       "getAccountInfo",
-      ["6vGsVQ1YMu5zkNUMJ5j5H1TVimfennBcYuYP9hXw1kB2"],
+      ["3y3x1WgtfytrLDhS2u1qvayJHzxGtxgcat3nb1z4Rr9R"],
       {
         context: { slot: 4131 },
         value: {
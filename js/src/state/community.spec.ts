@@ -40,7 +40,7 @@ describe("Community", () => {
     mockRpcOk(
       endpoint,
       "getAccountInfo",
-      ["DgBtqgnzYRsUZP3PhX5rCLfNycTQQ8cp7eMseosUQ4Ja"],
+      ["GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU"],
       {
         context: { slot: 4425 },
         value: {
@@ -59,4 +59,11 @@ describe("Community", () => {
     const community = await Community.retrieve(programId, conn, "hapi.one");
     expect(stringify(community.data)).toEqual(stringify(COMMUNITY_SAMPLE));
   });
+
+  it("should derive the same address regardless of name case", async () => {
+    const [address] = await Community.getAddress(programId, "HAPI.one");
+    expect(address.toBase58()).toEqual(
+      "GzBr5qCtz1LJaAsfcpQDgj8mC22cBmcrMnYyXk2RHgPU"
+    );
+  });
 });
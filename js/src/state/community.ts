@@ -2,7 +2,7 @@ import { Connection, PublicKey } from "@solana/web3.js";
 import { serialize, deserializeUnchecked } from "borsh";
 import BN from "bn.js";
 
-import { u64 } from "../utils";
+import { getNameHash, u64 } from "../utils";
 import { HapiAccountType } from "./enums";
 
 class CommunityState {
@@ -64,7 +64,7 @@ export class Community {
   }
 
   static getAddressSeeds(communityName: string): Uint8Array[] {
-    return [Buffer.from("community"), Buffer.from(communityName)];
+    return [Buffer.from("community"), getNameHash(communityName)];
   }
 
   static fromState(state: CommunityState): Community {
@@ -37,7 +37,7 @@ describe("Network", () => {
     mockRpcOk(
       endpoint,
       "getAccountInfo",
-      ["2viJmmn2pEfd6cogyqdDGWS9YkrVdnx87L994Qo3GwLx"],
+      ["3KqL24GPFysyKyBdXEdMqLSFdcTF91EE2Zd8i37UcNwK"],
       {
         context: { slot: 4628 },
         value: {
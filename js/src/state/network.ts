@@ -1,6 +1,7 @@
 import { Connection, PublicKey } from "@solana/web3.js";
 import { deserializeUnchecked, Schema, serialize } from "borsh";
 
+import { getNameHash } from "../utils";
 import { Community } from "./community";
 import { HapiAccountType } from "./enums";
 
@@ -49,12 +50,22 @@ export class Network {
       [
         Buffer.from("network"),
         communityAddress.toBuffer(),
-        Buffer.from(networkName),
+        getNameHash(networkName),
       ],
       programId
     );
   }
 
+  static async getAddressFilterAddress(
+    programId: PublicKey,
+    networkAddress: PublicKey
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+      [Buffer.from("address_filter"), networkAddress.toBuffer()],
+      programId
+    );
+  }
+
   static fromState(state: NetworkState): Network {
     const network = new Network();
     network.accountType = state.account_type;
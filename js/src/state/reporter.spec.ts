@@ -40,7 +40,7 @@ describe("Reporter", () => {
     mockRpcOk(
       endpoint,
       "getAccountInfo",
-      ["GfwYi1NaoMFJUHzEXtTkXAQewxxqs7PbseAYnsfiNnS7"],
+      ["7iGSudEkSzzBfvVRzjGy3aJnKC4K9mbNBp9SipN71TMQ"],
       {
         context: { slot: 1113 },
         value: {
@@ -1,5 +1,6 @@
 import BN from "bn.js";
 import { decode } from "bs58";
+import { createHash } from "crypto";
 import {
   Connection,
   Keypair,
@@ -165,6 +166,36 @@ export function setToBuffer<T extends number>(set: Set<T>, size = 4): Buffer {
   return value.toBuffer("le", size);
 }
 
+/// Maximum length of community and network names in bytes
+export const MAX_NAME_LENGTH = 64;
+
+/**
+ * Check that the name is not empty, fits MAX_NAME_LENGTH and consists of printable ASCII characters
+ * other than '/', which separates community and network names in network paths
+ */
+export function isValidName(name: string): boolean {
+  return (
+    name.length > 0 &&
+    Buffer.from(name).length <= MAX_NAME_LENGTH &&
+    /^[\x20-\x7e]+$/.test(name) &&
+    !name.includes("/")
+  );
+}
+
+/**
+ * Fold the name to lower case, names that differ only in case are the same name
+ */
+export function normalizeName(name: string): string {
+  return name.replace(/[A-Z]/g, (c) => c.toLowerCase());
+}
+
+/**
+ * Hash of the normalized name, which is used for PDA seeds instead of the name itself
+ */
+export function getNameHash(name: string): Buffer {
+  return createHash("sha256").update(normalizeName(name)).digest();
+}
+
 export function base58ToPublicKey(address: string): PublicKey {
   const buffer = decode(address);
   return new PublicKey(buffer);
@@ -398,7 +398,7 @@ describe("Scenario 1", () => {
         networkName1,
         addressNobody,
         caseId,
-        Category.WalletService,
+        [Category.WalletService],
         1
       );
 
@@ -406,14 +406,25 @@ describe("Scenario 1", () => {
         `"HLxjkKgfMN3Pc6r3fSH8Gj5uwAdFMTteSWyNKRur2CoT"`
       );
 
-      expect(data).toMatchInlineSnapshot(`
+      expect(data).toMatchInlineSnapshot(
+        { updatedAt: expect.anything() },
+        `
         Address {
           "accountType": 5,
           "caseId": "01",
-          "category": 1,
+          "categories": Array [
+            1,
+          ],
+          "entity": null,
+          "expiresAt": null,
+          "primaryCategory": null,
+          "revision": "00",
           "risk": 1,
+          "stale": false,
+          "updatedAt": Anything,
         }
-      `);
+      `
+      );
     });
 
     it(`It shouldn't be possible to create the same address again`, async () => {
@@ -426,7 +437,7 @@ describe("Scenario 1", () => {
           networkName1,
           addressNobody,
           caseId,
-          Category.WalletService,
+          [Category.WalletService],
           1
         )
       ).rejects.toThrowErrorMatchingInlineSnapshot(
@@ -446,7 +457,7 @@ describe("Scenario 1", () => {
           networkName1,
           address,
           new u64("100000000"),
-          Category.WalletService,
+          [Category.WalletService],
           1
         )
       ).rejects.toThrowErrorMatchingInlineSnapshot(
@@ -463,7 +474,7 @@ describe("Scenario 1", () => {
         networkName2,
         addressBlackhole,
         caseId,
-        Category.Safe,
+        [Category.Safe],
         1
       );
 
@@ -471,14 +482,23 @@ describe("Scenario 1", () => {
         `"79XZACTb39YrLjNAkeYLx4YHwm2s6oi9E7U34Q3UVDnr"`
       );
 
-      expect(data).toMatchInlineSnapshot(`
+      expect(data).toMatchInlineSnapshot(
+        { updatedAt: expect.anything() },
+        `
         Address {
           "accountType": 5,
           "caseId": "01",
-          "category": 0,
+          "categories": Array [],
+          "entity": null,
+          "expiresAt": null,
+          "primaryCategory": null,
+          "revision": "00",
           "risk": 1,
+          "stale": false,
+          "updatedAt": Anything,
         }
-      `);
+      `
+      );
     });
 
     it.todo("Update address category");
@@ -0,0 +1,153 @@
+//! Helpers for other programs to read HAPI accounts without CPI
+
+use solana_program::{
+    account_info::AccountInfo, clock::UnixTimestamp, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    id,
+    state::{
+        address::{
+            assert_is_valid_address, get_address_address, get_address_address_seeds, AddressRef,
+        },
+        address_filter::{address_filter_contains, assert_address_filter_belongs_to_network},
+        address_set::{
+            assert_is_valid_address_set, get_address_set_data, get_address_set_leaf,
+            MAX_ADDRESS_SET_DEPTH,
+        },
+        enums::{Category, CategorySet, CategorySetBitmask},
+    },
+    tools::{
+        account::is_program_address,
+        merkle::{verify_merkle_proof, MerkleHash},
+    },
+};
+
+/// Read-only view of a reported address
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddressView {
+    /// Risk score
+    pub risk: u8,
+
+    /// Case ID
+    pub case_id: u64,
+
+    /// Categories
+    pub categories: CategorySet,
+
+    /// Primary category
+    pub primary_category: Option<Category>,
+
+    /// Time when the report is due for review
+    pub expires_at: Option<UnixTimestamp>,
+
+    /// Report is expired and no longer bears risk
+    pub stale: bool,
+
+    /// Risk score at the time of the view, zero if the report is stale or expired
+    pub effective_risk: u8,
+}
+
+impl AddressView {
+    /// Checks if address effective risk reaches the threshold
+    pub fn is_risky(&self, threshold: u8) -> bool {
+        self.effective_risk >= threshold
+    }
+
+    /// Checks if the report is due for review
+    pub fn is_expired(&self, now: UnixTimestamp) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= now)
+    }
+
+    /// Checks if address is reported with the category
+    pub fn has_category(&self, category: Category) -> bool {
+        self.categories.contains(category)
+    }
+
+    /// Checks if address is reported with any of the categories
+    pub fn has_any_category(&self, categories: &CategorySet) -> bool {
+        self.categories & categories != 0
+    }
+}
+
+/// Validates the account as an Address PDA of the address value in the network
+/// and returns its view or None if the address is not reported. The account is read in place.
+/// Effective risk is computed at `now` with the `risk_decay_period` of the community, zero disables the decay.
+/// Note: Network account is not validated, consumers must pin the network they trust
+pub fn get_address_view(
+    address_info: &AccountInfo,
+    network: &Pubkey,
+    address: &Pubkey,
+    now: UnixTimestamp,
+    risk_decay_period: u64,
+) -> Result<Option<AddressView>, ProgramError> {
+    if address_info.data_is_empty() {
+        if *address_info.key != get_address_address(network, address) {
+            msg!("Address doesn't match Address account");
+            return Err(ProgramError::InvalidSeeds);
+        }
+        return Ok(None);
+    }
+
+    assert_is_valid_address(address_info)?;
+    let data = address_info.data.borrow();
+    let address_ref = AddressRef::new(&data)?;
+    if !is_program_address(
+        address_info.key,
+        &get_address_address_seeds(network, address),
+        address_ref.bump_seed()?,
+        &id(),
+    ) {
+        msg!("Address doesn't match Address account");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(Some(AddressView {
+        risk: address_ref.risk()?,
+        case_id: address_ref.case_id()?,
+        categories: address_ref.categories()?,
+        primary_category: address_ref.primary_category()?,
+        expires_at: address_ref.expires_at()?,
+        stale: address_ref.stale()?,
+        effective_risk: address_ref.effective_risk(now, risk_decay_period)?,
+    }))
+}
+
+/// Checks the Merkle proof of an address reported in the network against the address set root.
+/// Note: Address set account is not tied to a case here, consumers must pin the address set they trust
+pub fn is_address_in_set(
+    address_set_info: &AccountInfo,
+    network: &Pubkey,
+    address: &Pubkey,
+    risk: u8,
+    category: Category,
+    proof: &[MerkleHash],
+) -> Result<bool, ProgramError> {
+    assert_is_valid_address_set(address_set_info)?;
+    let address_set_data = get_address_set_data(address_set_info)?;
+
+    if proof.len() > MAX_ADDRESS_SET_DEPTH {
+        msg!(
+            "Address proof exceeds maximum depth {}",
+            MAX_ADDRESS_SET_DEPTH
+        );
+        return Ok(false);
+    }
+
+    let leaf = get_address_set_leaf(network, address, risk, category);
+
+    Ok(verify_merkle_proof(&leaf, proof, &address_set_data.root))
+}
+
+/// Checks the address filter of the network, returns false if the address is definitely not reported
+/// and true if it may be reported. Runs in constant compute units regardless of the number of addresses.
+pub fn is_address_maybe_reported(
+    address_filter_info: &AccountInfo,
+    network: &Pubkey,
+    address: &Pubkey,
+) -> Result<bool, ProgramError> {
+    assert_address_filter_belongs_to_network(address_filter_info, network)?;
+
+    Ok(address_filter_contains(address_filter_info, address))
+}
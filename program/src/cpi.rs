@@ -0,0 +1,46 @@
+//! Helpers for other programs to invoke HAPI instructions
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::AccountMeta, msg,
+    program::invoke, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{error::HapiError, id, instruction, state::enums::CategorySet};
+
+/// Invokes CheckAddress instruction of the HAPI program.
+/// The risk decays with the risk decay period of the community if its account is passed.
+/// Note: A failed check aborts the whole transaction, the error can't be handled by the caller
+pub fn check_address<'a>(
+    hapi_program_info: &AccountInfo<'a>,
+    network_info: &AccountInfo<'a>,
+    address_info: &AccountInfo<'a>,
+    community_info: Option<&AccountInfo<'a>>,
+    address: &Pubkey,
+    max_risk: u8,
+    forbidden_categories: &CategorySet,
+) -> ProgramResult {
+    if *hapi_program_info.key != id() {
+        msg!("Invalid HAPI program account");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut check_address_instruction = instruction::check_address_with_account(
+        network_info.key,
+        address_info.key,
+        address,
+        max_risk,
+        forbidden_categories,
+    )
+    .map_err(|_| HapiError::InvalidInstruction)?;
+
+    let mut account_infos = vec![network_info.clone(), address_info.clone()];
+    if let Some(community_info) = community_info {
+        check_address_instruction
+            .accounts
+            .push(AccountMeta::new_readonly(*community_info.key, false));
+        account_infos.push(community_info.clone());
+    }
+    account_infos.push(hapi_program_info.clone());
+
+    invoke(&check_address_instruction, &account_infos)
+}
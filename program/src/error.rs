@@ -73,6 +73,106 @@ pub enum HapiError {
     /// Invalid Account type
     #[error("Invalid Account type")]
     InvalidAccountType,
+
+    /// ---- Address Check Errors ----
+
+    /// Address is reported with a risk or category that is not acceptable
+    #[error("HighRiskAddress")]
+    HighRiskAddress,
+
+    /// ---- Network Errors ----
+
+    /// Invalid network account
+    #[error("InvalidNetwork")]
+    InvalidNetwork,
+
+    /// ---- Entity Errors ----
+
+    /// Invalid entity account
+    #[error("InvalidEntity")]
+    InvalidEntity,
+
+    /// Website of this entity is too long
+    #[error("WebsiteTooLong")]
+    WebsiteTooLong,
+
+    /// ---- Address Category Errors ----
+
+    /// Address category is not reported in the case
+    #[error("CategoryNotInCase")]
+    CategoryNotInCase,
+
+    /// Primary category is not one of address categories
+    #[error("InvalidPrimaryCategory")]
+    InvalidPrimaryCategory,
+
+    /// ---- Address Link Errors ----
+
+    /// Address link confidence is out of range
+    #[error("InvalidConfidence")]
+    InvalidConfidence,
+
+    /// Address can't be linked to itself
+    #[error("InvalidAddressLink")]
+    InvalidAddressLink,
+
+    /// ---- Report Expiry Errors ----
+
+    /// Neither address report nor its case is due for review
+    #[error("ReportNotExpired")]
+    ReportNotExpired,
+
+    /// ---- Reporter Errors ----
+
+    /// Reporter has exhausted its report quota for the current period
+    #[error("ReportQuotaExceeded")]
+    ReportQuotaExceeded,
+
+    /// Reporter delegate key has expired
+    #[error("DelegateExpired")]
+    DelegateExpired,
+
+    /// Reporter term has expired
+    #[error("ReporterTermExpired")]
+    ReporterTermExpired,
+
+    /// ---- Address Set Errors ----
+
+    /// Address is not in the address set
+    #[error("AddressNotInSet")]
+    AddressNotInSet,
+
+    /// ---- Name Errors ----
+
+    /// Name is empty, too long or has characters that are not allowed
+    #[error("InvalidName")]
+    InvalidName,
+
+    /// ---- Account Context Errors ----
+
+    /// Instruction account must be writable
+    #[error("AccountNotWritable")]
+    AccountNotWritable,
+
+    /// Instruction account doesn't have the expected address
+    #[error("InvalidAccountAddress")]
+    InvalidAccountAddress,
+
+    /// ---- Address Revision Errors ----
+
+    /// Address revision account is required to update an existing address
+    #[error("AddressRevisionMissing")]
+    AddressRevisionMissing,
+
+    /// ---- Entity Parent Errors ----
+
+    /// Entity can't be an ancestor of itself
+    #[error("EntityParentCycle")]
+    EntityParentCycle,
+
+    /// Parent entity has more ancestors than UpdateEntity can walk
+    #[error("EntityParentChainTooLong")]
+    EntityParentChainTooLong,
 }
 
 impl From<HapiError> for ProgramError {
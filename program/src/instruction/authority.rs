@@ -3,6 +3,7 @@
 use {
     borsh::BorshSerialize,
     solana_program::{
+        clock::UnixTimestamp,
         instruction::{AccountMeta, Instruction},
         pubkey::Pubkey,
         system_program, sysvar,
@@ -10,9 +11,19 @@ use {
 };
 
 use crate::{
-    error::GenericError, id, instruction::HapiInstruction, state::community::get_community_address,
-    state::enums::ReporterType, state::network::get_network_address,
-    state::reporter::get_reporter_address, tools::parse_network_path,
+    error::GenericError,
+    id,
+    instruction::{HapiInstruction, MAX_ADDRESSES_PER_INSTRUCTION},
+    state::address::get_address_address,
+    state::address_filter::get_address_filter_address,
+    state::community::get_community_address,
+    state::enums::ReporterType,
+    state::network::get_network_address,
+    state::reporter::get_reporter_address,
+    tools::{
+        name::{is_valid_name, NameError},
+        parse_network_path,
+    },
 };
 
 /// Creates CreateCommunity instruction
@@ -22,6 +33,10 @@ pub fn create_community(
     // Args
     name: &str,
 ) -> Result<Instruction, GenericError> {
+    if !is_valid_name(name) {
+        return Err(NameError::InvalidName.into());
+    }
+
     let community_address = get_community_address(&name);
 
     let accounts = vec![
@@ -49,6 +64,9 @@ pub fn update_community(
     new_authority: Option<&Pubkey>,
     // Args
     network_name: &str,
+    risk_decay_period: u64,
+    report_quota: u64,
+    report_quota_period: u64,
 ) -> Result<Instruction, GenericError> {
     let community_address = get_community_address(&network_name);
 
@@ -61,7 +79,11 @@ pub fn update_community(
         accounts.push(AccountMeta::new(*new_authority, false));
     }
 
-    let instruction = HapiInstruction::UpdateCommunity {};
+    let instruction = HapiInstruction::UpdateCommunity {
+        risk_decay_period,
+        report_quota,
+        report_quota_period,
+    };
 
     Ok(Instruction {
         program_id: id(),
@@ -78,6 +100,10 @@ pub fn create_network(
     network_path: &str,
 ) -> Result<Instruction, GenericError> {
     let (community_name, network_name) = parse_network_path(network_path)?;
+    if !is_valid_name(&network_name) {
+        return Err(NameError::InvalidName.into());
+    }
+
     let community_address = get_community_address(&community_name);
     let network_address = get_network_address(&community_address, &network_name);
 
@@ -133,6 +159,7 @@ pub fn create_reporter(
     reporter_name: &str,
     reporter_pubkey: &Pubkey,
     reporter_type: ReporterType,
+    active_until: Option<UnixTimestamp>,
 ) -> Result<Instruction, GenericError> {
     let community_address = get_community_address(&community_name);
     let reporter_account = get_reporter_address(&community_address, reporter_pubkey);
@@ -149,6 +176,7 @@ pub fn create_reporter(
     let instruction = HapiInstruction::CreateReporter {
         reporter_type,
         name: reporter_name.to_string(),
+        active_until,
     };
 
     Ok(Instruction {
@@ -167,20 +195,24 @@ pub fn update_reporter(
     reporter_name: &str,
     reporter_pubkey: &Pubkey,
     reporter_type: ReporterType,
+    active_until: Option<UnixTimestamp>,
 ) -> Result<Instruction, GenericError> {
     let community_address = get_community_address(&community_name);
     let reporter_address = get_reporter_address(&community_address, reporter_pubkey);
 
     let accounts = vec![
-        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*authority, true),
         AccountMeta::new_readonly(community_address, false),
         AccountMeta::new(reporter_address, false),
         AccountMeta::new_readonly(*reporter_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
     ];
 
     let instruction = HapiInstruction::UpdateReporter {
         reporter_type,
         name: reporter_name.to_string(),
+        active_until,
     };
 
     Ok(Instruction {
@@ -189,3 +221,129 @@ pub fn update_reporter(
         data: instruction.try_to_vec().unwrap(),
     })
 }
+
+/// Creates RecordLostDispute instruction
+pub fn record_lost_dispute(
+    // Accounts
+    authority: &Pubkey,
+    // Args
+    community_name: &str,
+    reporter_pubkey: &Pubkey,
+) -> Result<Instruction, GenericError> {
+    let community_address = get_community_address(community_name);
+    let reporter_address = get_reporter_address(&community_address, reporter_pubkey);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new(reporter_address, false),
+        AccountMeta::new_readonly(*reporter_pubkey, false),
+    ];
+
+    let instruction = HapiInstruction::RecordLostDispute {};
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates RotateReporterKey instruction
+pub fn rotate_reporter_key(
+    // Accounts
+    authority: &Pubkey,
+    // Args
+    community_name: &str,
+    reporter_pubkey: &Pubkey,
+    new_reporter_pubkey: &Pubkey,
+) -> Result<Instruction, GenericError> {
+    let community_address = get_community_address(community_name);
+    let reporter_address = get_reporter_address(&community_address, reporter_pubkey);
+    let new_reporter_address = get_reporter_address(&community_address, new_reporter_pubkey);
+
+    let accounts = vec![
+        AccountMeta::new(*authority, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new(reporter_address, false),
+        AccountMeta::new_readonly(*reporter_pubkey, false),
+        AccountMeta::new_readonly(*new_reporter_pubkey, false),
+        AccountMeta::new(new_reporter_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let instruction = HapiInstruction::RotateReporterKey {};
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates RebuildAddressFilter instruction
+pub fn rebuild_address_filter(
+    // Accounts
+    authority: &Pubkey,
+    // Args
+    network_path: &str,
+    reset: bool,
+    addresses: &[Pubkey],
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+
+    let mut accounts = vec![
+        AccountMeta::new(*authority, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new(get_address_filter_address(&network_address), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    for address in addresses {
+        accounts.push(AccountMeta::new_readonly(
+            get_address_address(&network_address, address),
+            false,
+        ));
+    }
+
+    let instruction = HapiInstruction::RebuildAddressFilter {
+        reset,
+        addresses: addresses.to_vec(),
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates RebuildAddressFilter instructions that reset the filter and add any number of addresses,
+/// each of the instructions fits a separate transaction
+pub fn rebuild_address_filter_chunked(
+    // Accounts
+    authority: &Pubkey,
+    // Args
+    network_path: &str,
+    addresses: &[Pubkey],
+) -> Result<Vec<Instruction>, GenericError> {
+    if addresses.is_empty() {
+        return Ok(vec![rebuild_address_filter(
+            authority,
+            network_path,
+            true,
+            &[],
+        )?]);
+    }
+
+    addresses
+        .chunks(MAX_ADDRESSES_PER_INSTRUCTION)
+        .enumerate()
+        .map(|(index, chunk)| rebuild_address_filter(authority, network_path, index == 0, chunk))
+        .collect()
+}
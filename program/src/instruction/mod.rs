@@ -7,14 +7,88 @@ pub use authority::*;
 mod reporter;
 pub use reporter::*;
 
+mod reader;
+pub use reader::*;
+
 // TODO: describe actors (Authority and Reporter) and their respective program accounts
 
 use {
     borsh::{BorshDeserialize, BorshSerialize},
-    solana_program::pubkey::Pubkey,
+    solana_program::{
+        clock::UnixTimestamp,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
 };
 
-use crate::state::enums::{CaseStatus, Category, CategorySet, ReporterType};
+use crate::state::{
+    asset::TokenId,
+    enums::{AddressLinkKind, CaseStatus, Category, CategorySet, ReporterType},
+    transaction::TransactionHash,
+};
+
+use crate::tools::merkle::MerkleHash;
+
+/// Maximum number of address reports in a CreateAddresses instruction that still fits
+/// a single transaction along with its accounts
+pub const MAX_ADDRESSES_PER_INSTRUCTION: usize = 8;
+
+/// Address report of CreateAddress, UpdateAddress, ReportAddress and CreateAddresses instructions
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct AddressReport {
+    /// Address value
+    pub address: Pubkey,
+
+    /// Address risk score: 0 is safe, 10 is maximum risk
+    pub risk: u8,
+
+    /// Categories, must be a subset of case categories
+    pub categories: CategorySet,
+
+    /// Primary category, must be one of the categories
+    pub primary_category: Option<Category>,
+
+    /// Time when the report is due for review
+    pub expires_at: Option<UnixTimestamp>,
+}
+
+/// Transaction report of CreateTransaction and UpdateTransaction instructions
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct TransactionReport {
+    /// Transaction hash
+    pub tx_hash: TransactionHash,
+
+    /// Transaction risk score: 0 is safe, 10 is maximum risk
+    pub risk: u8,
+
+    /// Category, must be reported in the case
+    pub category: Category,
+
+    /// Source address of the transaction
+    pub source: Option<Pubkey>,
+
+    /// Destination address of the transaction
+    pub destination: Option<Pubkey>,
+}
+
+/// Asset report of CreateAsset and UpdateAsset instructions
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct AssetReport {
+    /// Asset address (i.e. token mint, collection or contract)
+    pub asset: Pubkey,
+
+    /// Token ID within the asset
+    pub token_id: Option<TokenId>,
+
+    /// Asset risk score: 0 is safe, 10 is maximum risk
+    pub risk: u8,
+
+    /// Category, must be reported in the case
+    pub category: Category,
+}
 
 /// Instructions supported by the HAPI program
 #[repr(C)]
@@ -23,9 +97,10 @@ pub enum HapiInstruction {
     /// Creates a new HAPI Community
     ///
     /// 0. `[signer]` Authority account
-    /// 1. `[writable]` Community account. PDA seeds: ['community', name]
+    /// 1. `[writable]` Community account. PDA seeds: ['community', sha256(normalized_name)]
     /// 2. `[]` System
     /// 3. `[]` Sysvar Rent
+    /// 4. `[writable, signer]` Payer account (optional, the authority pays for created accounts if omitted)
     ///
     CreateCommunity {
         /// UTF-8 encoded HAPI Community name
@@ -35,17 +110,27 @@ pub enum HapiInstruction {
     /// Updates an existing HAPI Community
     ///
     /// 0. `[signer]` Authority account
-    /// 1. `[writable]` Community account. PDA seeds: ['community', name]
+    /// 1. `[writable]` Community account. PDA seeds: ['community', sha256(normalized_name)]
     /// 2. `[]` New authority account
-    UpdateCommunity {},
+    UpdateCommunity {
+        /// Period in seconds for the address risk to decay by half, zero disables the decay
+        risk_decay_period: u64,
+
+        /// Maximum number of reports per reporter within a quota period, zero disables the quota
+        report_quota: u64,
+
+        /// Quota period in seconds, zero disables the quota
+        report_quota_period: u64,
+    },
 
     /// Creates a new HAPI Network
     ///
     /// 0. `[signer]` Authority account
-    /// 1. `[]` Community account. PDA seeds: ['community', name]
-    /// 2. `[writable]` Network account. PDA seeds: ['network', community_address, network_name]
+    /// 1. `[writable]` Network account. PDA seeds: ['network', community_address, sha256(normalized_network_name)]
+    /// 2. `[]` Community account. PDA seeds: ['community', sha256(normalized_name)]
     /// 3. `[]` System
     /// 4. `[]` Sysvar Rent
+    /// 5. `[writable, signer]` Payer account (optional, the authority pays for created accounts if omitted)
     ///
     CreateNetwork {
         /// UTF-8 encoded HAPI Network name
@@ -55,19 +140,20 @@ pub enum HapiInstruction {
     /// Updates an existing HAPI Network
     ///
     /// 0. `[signer]` Authority account
-    /// 1. `[]` Community account. PDA seeds: ['community', name]
-    /// 2. `[writable]` Network account. PDA seeds: ['network', community_address, network_name]
+    /// 1. `[]` Community account. PDA seeds: ['community', sha256(normalized_name)]
+    /// 2. `[writable]` Network account. PDA seeds: ['network', community_address, sha256(normalized_network_name)]
     ///
     UpdateNetwork {},
 
     /// Add reporter to network
     ///
     /// 0. `[signer]` Authority account
-    /// 1. `[]` Community account. PDA seeds: ['community', name]
+    /// 1. `[]` Community account. PDA seeds: ['community', sha256(normalized_name)]
     /// 2. `[]` Reporter key (will be used as signer in address and case reports)
     /// 3. `[writable]` Reporter account. PDA seeds: [`reporter`, community_address, reporter_pubkey]
     /// 4. `[]` System
     /// 5. `[]` Sysvar Rent
+    /// 6. `[writable, signer]` Payer account (optional, the authority pays for created accounts if omitted)
     ///
     CreateReporter {
         /// Reporter type
@@ -75,14 +161,20 @@ pub enum HapiInstruction {
 
         /// UTF-8 encoded Reporter name
         name: String,
+
+        /// Time when the reporter term ends
+        active_until: Option<UnixTimestamp>,
     },
 
-    /// Update reporter name and type
+    /// Update reporter name, type and term
     ///
     /// 0. `[signer]` Authority account
-    /// 1. `[]` Community account. PDA seeds: ['community', name]
+    /// 1. `[]` Community account. PDA seeds: ['community', sha256(normalized_name)]
     /// 2. `[writable]` Reporter account. PDA seeds: [`reporter`, community_address, reporter_pubkey]
     /// 3. `[]` Reporter key
+    /// 4. `[]` System
+    /// 5. `[]` Sysvar Rent
+    /// 6. `[writable, signer]` Payer account (optional, the authority pays for the reporter account growth if omitted)
     ///
     UpdateReporter {
         /// Reporter type
@@ -90,16 +182,20 @@ pub enum HapiInstruction {
 
         /// UTF-8 encoded Reporter name
         name: String,
+
+        /// Time when the reporter term ends
+        active_until: Option<UnixTimestamp>,
     },
 
     /// Report a new case
     ///
     /// 0. `[signer]` Reporter key
     /// 1. `[writable]` Community account
-    /// 2. `[]` Reporter account
+    /// 2. `[writable]` Reporter account
     /// 3. `[writable]` Case account. PDA seeds: ['case', community_account, case_id]
     /// 4. `[]` System
     /// 5. `[]` Sysvar Rent
+    /// 6. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
     ///
     CreateCase {
         /// Case ID
@@ -113,6 +209,9 @@ pub enum HapiInstruction {
 
         /// UTF-8 encoded case name
         name: String,
+
+        /// Time when the case is due for review
+        expires_at: Option<UnixTimestamp>,
     },
 
     /// Update an existing case
@@ -121,25 +220,39 @@ pub enum HapiInstruction {
     /// 1. `[]` Community account
     /// 2. `[]` Reporter account
     /// 3. `[writable]` Case account. PDA seeds: ['case', community_account, case_id]
+    /// 4. `[]` System
+    /// 5. `[]` Sysvar Rent
+    /// 6. `[writable, signer]` Payer account (optional, the reporter key pays for the case account growth if omitted)
     ///
     UpdateCase {
+        /// Case ID
+        case_id: u64,
+
         /// Categories
         categories: CategorySet,
 
         /// Status
         status: CaseStatus,
+
+        /// UTF-8 encoded case name
+        name: String,
+
+        /// Time when the case is due for review
+        expires_at: Option<UnixTimestamp>,
     },
 
     /// Report an address for an existing case
     ///
     /// 0. `[signer]` Reporter key
-    /// 1. `[writable]` Community account
+    /// 1. `[]` Community account
     /// 2. `[]` Network account
-    /// 3. `[]` Reporter account
+    /// 3. `[writable]` Reporter account
     /// 4. `[]` Case account. PDA seeds: ['case', network_account, case_id]
     /// 5. `[writable]` Address account. PDA seeds: ['address', network_account, address]
     /// 6. `[]` System
     /// 7. `[]` Sysvar Rent
+    /// 8. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    /// 9. `[writable]` Address filter account, #8 if there is no payer account. Skipped if not created. PDA seeds: ['address_filter', network_account]
     ///
     CreateAddress {
         /// Address value
@@ -151,8 +264,14 @@ pub enum HapiInstruction {
         /// Case ID
         case_id: u64,
 
-        /// Category
-        category: Category,
+        /// Categories, must be a subset of case categories
+        categories: CategorySet,
+
+        /// Primary category, must be one of the categories
+        primary_category: Option<Category>,
+
+        /// Time when the report is due for review
+        expires_at: Option<UnixTimestamp>,
     },
 
     /// Update an existing address
@@ -160,18 +279,469 @@ pub enum HapiInstruction {
     /// 0. `[signer]` Reporter key
     /// 1. `[]` Community account
     /// 2. `[]` Network account
-    /// 3. `[]` Reporter account
+    /// 3. `[writable]` Reporter account
     /// 4. `[]` Case account. PDA seeds: ['case', network_account, case_id]
     /// 5. `[writable]` Address account. PDA seeds: ['address', network_account, address]
+    /// 6. `[writable]` Address revision account. PDA seeds: ['address_revision', address_account, revision]
+    /// 7. `[]` System
+    /// 8. `[]` Sysvar Rent
+    /// 9. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
     ///
     UpdateAddress {
+        /// Address value
+        address: Pubkey,
+
         /// Address risk score: 0 is safe, 10 is maximum risk
         risk: u8,
 
         /// Case ID
         case_id: u64,
 
+        /// Categories, must be a subset of case categories
+        categories: CategorySet,
+
+        /// Primary category, must be one of the categories
+        primary_category: Option<Category>,
+
+        /// Time when the report is due for review
+        expires_at: Option<UnixTimestamp>,
+    },
+
+    /// Check an address against a risk threshold, fails with HighRiskAddress error if it's not acceptable.
+    /// Addresses that are not reported pass the check. The effective risk is checked,
+    /// stale and expired reports bear no risk.
+    ///
+    /// 0. `[]` Network account
+    /// 1. `[]` Address account. PDA seeds: ['address', network_account, address]
+    /// 2. `[]` Community account (optional, the risk doesn't decay if omitted)
+    ///
+    CheckAddress {
+        /// Address value
+        address: Pubkey,
+
+        /// Maximum acceptable risk score
+        max_risk: u8,
+
+        /// Categories that are not acceptable
+        forbidden_categories: CategorySet,
+    },
+
+    /// Create a new entity
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Reporter account
+    /// 3. `[writable]` Entity account. PDA seeds: ['entity', community_account, sha256(normalized_name)]
+    /// 4. `[]` System
+    /// 5. `[]` Sysvar Rent
+    /// 6. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    /// 7. `[]` Parent entity account (optional, #6 if there is no payer account)
+    ///
+    CreateEntity {
+        /// Category
+        category: Category,
+
+        /// Entity risk score: 0 is safe, 10 is maximum risk
+        risk: u8,
+
+        /// UTF-8 encoded entity name
+        name: String,
+
+        /// UTF-8 encoded entity website
+        website: String,
+    },
+
+    /// Update an existing entity
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Reporter account
+    /// 3. `[writable]` Entity account. PDA seeds: ['entity', community_account, sha256(normalized_name)]
+    /// 4. `[]` Parent entity account (optional, entity has no parent if omitted)
+    /// 5. `[]` Ancestor entity accounts #5..#5+N of the parent entity, nearest first, N <= MAX_ENTITY_ANCESTORS
+    ///
+    UpdateEntity {
         /// Category
         category: Category,
+
+        /// Entity risk score: 0 is safe, 10 is maximum risk
+        risk: u8,
+
+        /// UTF-8 encoded entity website
+        website: String,
+    },
+
+    /// Attach an existing address to an entity
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[]` Reporter account
+    /// 4. `[writable]` Address account. PDA seeds: ['address', network_account, address]
+    /// 5. `[]` Entity account (optional, address is detached from its entity if omitted)
+    ///
+    UpdateAddressEntity {
+        /// Address value
+        address: Pubkey,
+    },
+
+    /// Report a transaction for an existing case
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[]` Reporter account
+    /// 4. `[]` Case account. PDA seeds: ['case', community_account, case_id]
+    /// 5. `[writable]` Transaction account. PDA seeds: ['transaction', network_account, tx_hash[..32], tx_hash[32..]]
+    /// 6. `[]` System
+    /// 7. `[]` Sysvar Rent
+    /// 8. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    ///
+    CreateTransaction {
+        /// Transaction hash
+        tx_hash: TransactionHash,
+
+        /// Transaction risk score: 0 is safe, 10 is maximum risk
+        risk: u8,
+
+        /// Case ID
+        case_id: u64,
+
+        /// Category, must be reported in the case
+        category: Category,
+
+        /// Source address of the transaction
+        source: Option<Pubkey>,
+
+        /// Destination address of the transaction
+        destination: Option<Pubkey>,
+    },
+
+    /// Update an existing transaction
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[]` Reporter account
+    /// 4. `[]` Case account. PDA seeds: ['case', community_account, case_id]
+    /// 5. `[writable]` Transaction account. PDA seeds: ['transaction', network_account, tx_hash[..32], tx_hash[32..]]
+    ///
+    UpdateTransaction {
+        /// Transaction hash
+        tx_hash: TransactionHash,
+
+        /// Transaction risk score: 0 is safe, 10 is maximum risk
+        risk: u8,
+
+        /// Case ID
+        case_id: u64,
+
+        /// Category, must be reported in the case
+        category: Category,
+
+        /// Source address of the transaction
+        source: Option<Pubkey>,
+
+        /// Destination address of the transaction
+        destination: Option<Pubkey>,
+    },
+
+    /// Report an asset for an existing case
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[]` Reporter account
+    /// 4. `[]` Case account. PDA seeds: ['case', community_account, case_id]
+    /// 5. `[writable]` Asset account. PDA seeds: ['asset', network_account, asset, token_id]
+    /// 6. `[]` System
+    /// 7. `[]` Sysvar Rent
+    /// 8. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    ///
+    CreateAsset {
+        /// Asset address (i.e. token mint, collection or contract)
+        asset: Pubkey,
+
+        /// Token ID within the asset
+        token_id: Option<TokenId>,
+
+        /// Asset risk score: 0 is safe, 10 is maximum risk
+        risk: u8,
+
+        /// Case ID
+        case_id: u64,
+
+        /// Category, must be reported in the case
+        category: Category,
+    },
+
+    /// Update an existing asset
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[]` Reporter account
+    /// 4. `[]` Case account. PDA seeds: ['case', community_account, case_id]
+    /// 5. `[writable]` Asset account. PDA seeds: ['asset', network_account, asset, token_id]
+    ///
+    UpdateAsset {
+        /// Asset address (i.e. token mint, collection or contract)
+        asset: Pubkey,
+
+        /// Token ID within the asset
+        token_id: Option<TokenId>,
+
+        /// Asset risk score: 0 is safe, 10 is maximum risk
+        risk: u8,
+
+        /// Case ID
+        case_id: u64,
+
+        /// Category, must be reported in the case
+        category: Category,
+    },
+
+    /// Link two addresses of a network
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[]` Reporter account
+    /// 4. `[writable]` Address link account. PDA seeds: ['address_link', network_account, source, destination, kind]
+    /// 5. `[]` System
+    /// 6. `[]` Sysvar Rent
+    /// 7. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    ///
+    CreateAddressLink {
+        /// Source address
+        source: Pubkey,
+
+        /// Destination address
+        destination: Pubkey,
+
+        /// Relation kind
+        kind: AddressLinkKind,
+
+        /// Confidence of the relation from 0 to 100
+        confidence: u8,
+    },
+
+    /// Mark an expired address report or an address of an expired case as stale.
+    /// Fails with ReportNotExpired error if neither is due for review.
+    ///
+    /// 0. `[]` Community account
+    /// 1. `[]` Network account
+    /// 2. `[]` Case account. PDA seeds: ['case', community_account, case_id]
+    /// 3. `[writable]` Address account. PDA seeds: ['address', network_account, address]
+    ///
+    RefreshAddress {
+        /// Address value
+        address: Pubkey,
+    },
+
+    /// Record a dispute lost by the reporter, lowering its reputation
+    ///
+    /// 0. `[signer]` Authority account
+    /// 1. `[]` Community account. PDA seeds: ['community', sha256(normalized_name)]
+    /// 2. `[writable]` Reporter account. PDA seeds: [`reporter`, community_address, reporter_pubkey]
+    /// 3. `[]` Reporter key
+    ///
+    RecordLostDispute {},
+
+    /// Move reporter to a new key, keeping its identity and history. Delegate key is revoked.
+    ///
+    /// 0. `[writable, signer]` Authority account
+    /// 1. `[]` Community account. PDA seeds: ['community', sha256(normalized_name)]
+    /// 2. `[writable]` Reporter account. PDA seeds: [`reporter`, community_address, reporter_pubkey]
+    /// 3. `[]` Reporter key
+    /// 4. `[]` New reporter key
+    /// 5. `[writable]` New reporter account. PDA seeds: [`reporter`, community_address, new_reporter_pubkey]
+    /// 6. `[]` System
+    /// 7. `[]` Sysvar Rent
+    /// 8. `[writable, signer]` Payer account (optional, the authority pays for created accounts if omitted)
+    ///
+    RotateReporterKey {},
+
+    /// Allow another key to sign reports on behalf of the reporter until the given time
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[writable]` Reporter account. PDA seeds: [`reporter`, community_address, reporter_pubkey]
+    ///
+    SetReporterDelegate {
+        /// Delegate key, None revokes the delegate
+        delegate: Option<Pubkey>,
+
+        /// Time when the delegate key expires
+        valid_until: UnixTimestamp,
+    },
+
+    /// Report a batch of addresses for an existing case
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[writable]` Reporter account
+    /// 4. `[]` Case account. PDA seeds: ['case', community_account, case_id]
+    /// 5. `[]` System
+    /// 6. `[]` Sysvar Rent
+    /// 7. `[writable]` Address filter account, skipped if not created. PDA seeds: ['address_filter', network_account]
+    /// 8. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    /// 9. `[writable]` Address accounts #9..#9+N in the order of address reports, from #8 if there is no payer account. PDA seeds: ['address', network_account, address]
+    ///
+    CreateAddresses {
+        /// Case ID
+        case_id: u64,
+
+        /// Address reports
+        addresses: Vec<AddressReport>,
+    },
+
+    /// Report a new address or update an existing one, without knowing the current address data.
+    /// Reports matching the current address data are ignored.
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[writable]` Reporter account
+    /// 4. `[]` Case account. PDA seeds: ['case', network_account, case_id]
+    /// 5. `[writable]` Address account. PDA seeds: ['address', network_account, address]
+    /// 6. `[]` System
+    /// 7. `[]` Sysvar Rent
+    /// 8. `[writable]` Address filter account, used on create and skipped if not created. PDA seeds: ['address_filter', network_account]
+    /// 9. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    /// 10. `[writable]` Address revision account, #9 if there is no payer account. Required to update an existing address.
+    ///     PDA seeds: ['address_revision', address_account, revision]
+    ///
+    ReportAddress {
+        /// Address value
+        address: Pubkey,
+
+        /// Address risk score: 0 is safe, 10 is maximum risk
+        risk: u8,
+
+        /// Case ID
+        case_id: u64,
+
+        /// Categories, must be a subset of case categories
+        categories: CategorySet,
+
+        /// Primary category, must be one of the categories
+        primary_category: Option<Category>,
+
+        /// Time when the report is due for review
+        expires_at: Option<UnixTimestamp>,
+    },
+
+    /// Commit addresses of a case in bulk by the Merkle root over their address set leaves.
+    /// Creates the address set account on the first update.
+    ///
+    /// 0. `[signer]` Reporter key
+    /// 1. `[]` Community account
+    /// 2. `[]` Reporter account
+    /// 3. `[]` Case account
+    /// 4. `[writable]` Address set account. PDA seeds: ['address_set', case_account]
+    /// 5. `[]` System
+    /// 6. `[]` Sysvar Rent
+    /// 7. `[writable, signer]` Payer account (optional, the reporter key pays for created accounts if omitted)
+    ///
+    UpdateAddressSet {
+        /// Case ID
+        case_id: u64,
+
+        /// Merkle root over address set leaves
+        root: MerkleHash,
+
+        /// Number of addresses in the set
+        size: u64,
+    },
+
+    /// Verify that an address is in the address set, fails with AddressNotInSet error if it's not
+    ///
+    /// 0. `[]` Network account
+    /// 1. `[]` Address set account. PDA seeds: ['address_set', case_account]
+    ///
+    VerifyAddressInSet {
+        /// Address value
+        address: Pubkey,
+
+        /// Address risk score
+        risk: u8,
+
+        /// Address category
+        category: Category,
+
+        /// Merkle proof of the address set leaf
+        proof: Vec<MerkleHash>,
     },
+
+    /// Create the address filter of the network or reset it, and add addresses to it.
+    /// Addresses that are not reported are skipped. Stale addresses are kept, as updating
+    /// or reporting them again clears the stale flag without touching the filter.
+    ///
+    /// 0. `[signer]` Authority account
+    /// 1. `[]` Community account
+    /// 2. `[]` Network account
+    /// 3. `[writable]` Address filter account. PDA seeds: ['address_filter', network_account]
+    /// 4. `[]` System
+    /// 5. `[]` Sysvar Rent
+    /// 6. `[writable, signer]` Payer account (optional, the authority pays for created accounts if omitted)
+    /// 7. `[]` Address accounts #7..#7+N in the order of addresses, from #6 if there is no payer account. PDA seeds: ['address', network_account, address]
+    ///
+    RebuildAddressFilter {
+        /// Clear the filter before adding the addresses
+        reset: bool,
+
+        /// Address values
+        addresses: Vec<Pubkey>,
+    },
+}
+
+impl HapiInstruction {
+    /// Returns the index of the optional payer account of instructions that create accounts
+    pub fn payer_account_index(&self) -> Option<usize> {
+        match self {
+            HapiInstruction::CreateCommunity { .. } => Some(4),
+            HapiInstruction::CreateNetwork { .. } => Some(5),
+            HapiInstruction::CreateReporter { .. } => Some(6),
+            HapiInstruction::UpdateReporter { .. } => Some(6),
+            HapiInstruction::CreateCase { .. } => Some(6),
+            HapiInstruction::UpdateCase { .. } => Some(6),
+            HapiInstruction::CreateAddress { .. } => Some(8),
+            HapiInstruction::UpdateAddress { .. } => Some(9),
+            HapiInstruction::CreateEntity { .. } => Some(6),
+            HapiInstruction::CreateTransaction { .. } => Some(8),
+            HapiInstruction::CreateAsset { .. } => Some(8),
+            HapiInstruction::CreateAddressLink { .. } => Some(7),
+            HapiInstruction::RotateReporterKey { .. } => Some(8),
+            HapiInstruction::CreateAddresses { .. } => Some(8),
+            HapiInstruction::ReportAddress { .. } => Some(9),
+            HapiInstruction::UpdateAddressSet { .. } => Some(7),
+            HapiInstruction::RebuildAddressFilter { .. } => Some(6),
+            _ => None,
+        }
+    }
+}
+
+/// Makes an instruction that creates accounts pay for them from the payer instead of its signer,
+/// so the reporter or authority key only signs and doesn't have to hold SOL.
+/// Other instructions are returned unchanged
+pub fn with_payer(mut instruction: Instruction, payer: &Pubkey) -> Instruction {
+    let hapi_instruction = match HapiInstruction::try_from_slice(&instruction.data) {
+        Ok(hapi_instruction) => hapi_instruction,
+        Err(_) => return instruction,
+    };
+
+    if let Some(payer_index) = hapi_instruction.payer_account_index() {
+        if instruction.accounts[0].pubkey != *payer {
+            // Authority still receives the lamports of the disposed reporter account
+            instruction.accounts[0].is_writable =
+                matches!(hapi_instruction, HapiInstruction::RotateReporterKey {});
+            instruction
+                .accounts
+                .insert(payer_index, AccountMeta::new(*payer, true));
+        }
+    }
+
+    instruction
 }
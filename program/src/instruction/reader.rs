@@ -0,0 +1,143 @@
+//! Reader operation instructions
+
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    error::GenericError,
+    id,
+    instruction::HapiInstruction,
+    state::{
+        address::get_address_address,
+        address_set::get_address_set_address,
+        case::get_case_address,
+        community::get_community_address,
+        enums::{Category, CategorySet},
+        network::get_network_address,
+    },
+    tools::{merkle::MerkleHash, parse_network_path},
+};
+
+/// Creates CheckAddress instruction
+pub fn check_address(
+    // Accounts
+    network: &Pubkey,
+    // Args
+    address: &Pubkey,
+    max_risk: u8,
+    forbidden_categories: &CategorySet,
+) -> Result<Instruction, GenericError> {
+    check_address_with_account(
+        network,
+        &get_address_address(network, address),
+        address,
+        max_risk,
+        forbidden_categories,
+    )
+}
+
+/// Creates CheckAddress instruction with a known address account, skips the address PDA derivation
+pub fn check_address_with_account(
+    // Accounts
+    network: &Pubkey,
+    address_account: &Pubkey,
+    // Args
+    address: &Pubkey,
+    max_risk: u8,
+    forbidden_categories: &CategorySet,
+) -> Result<Instruction, GenericError> {
+    let accounts = vec![
+        AccountMeta::new_readonly(*network, false),
+        AccountMeta::new_readonly(*address_account, false),
+    ];
+
+    let instruction = HapiInstruction::CheckAddress {
+        address: *address,
+        max_risk,
+        forbidden_categories: *forbidden_categories,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Makes a CheckAddress instruction decay the address risk with the risk decay period of the community
+pub fn with_risk_decay(mut instruction: Instruction, community_name: &str) -> Instruction {
+    instruction.accounts.push(AccountMeta::new_readonly(
+        get_community_address(community_name),
+        false,
+    ));
+    instruction
+}
+
+/// Creates RefreshAddress instruction
+pub fn refresh_address(
+    // Args
+    network_path: &str,
+    address: &Pubkey,
+    case_id: u64,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let address_address = get_address_address(&network_address, address);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+
+    let accounts = vec![
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new(address_address, false),
+    ];
+
+    let instruction = HapiInstruction::RefreshAddress { address: *address };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates VerifyAddressInSet instruction
+pub fn verify_address_in_set(
+    // Args
+    network_path: &str,
+    case_id: u64,
+    address: &Pubkey,
+    risk: u8,
+    category: Category,
+    proof: &[MerkleHash],
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+    let address_set_address = get_address_set_address(&case_address);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new_readonly(address_set_address, false),
+    ];
+
+    let instruction = HapiInstruction::VerifyAddressInSet {
+        address: *address,
+        risk,
+        category,
+        proof: proof.to_vec(),
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
@@ -3,6 +3,7 @@
 use {
     borsh::BorshSerialize,
     solana_program::{
+        clock::UnixTimestamp,
         instruction::{AccountMeta, Instruction},
         pubkey::Pubkey,
         system_program, sysvar,
@@ -12,16 +13,26 @@ use {
 use crate::{
     error::GenericError,
     id,
-    instruction::HapiInstruction,
+    instruction::{
+        AddressReport, AssetReport, HapiInstruction, TransactionReport,
+        MAX_ADDRESSES_PER_INSTRUCTION,
+    },
     state::{
         address::get_address_address,
+        address_filter::get_address_filter_address,
+        address_link::get_address_link_address,
+        address_revision::get_address_revision_address,
+        address_set::get_address_set_address,
+        asset::get_asset_address,
         case::get_case_address,
         community::get_community_address,
-        enums::{CaseStatus, Category, CategorySet},
+        entity::get_entity_address,
+        enums::{AddressLinkKind, CaseStatus, Category, CategorySet},
         network::get_network_address,
         reporter::get_reporter_address,
+        transaction::get_transaction_address,
     },
-    tools::parse_network_path,
+    tools::{merkle::MerkleHash, parse_network_path},
 };
 
 /// Creates CreateCase instruction
@@ -34,6 +45,7 @@ pub fn create_case(
     case_name: &str,
     status: CaseStatus,
     categories: &CategorySet,
+    expires_at: Option<UnixTimestamp>,
 ) -> Result<Instruction, GenericError> {
     let community_address = get_community_address(&community_name);
     let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
@@ -42,7 +54,7 @@ pub fn create_case(
     let accounts = vec![
         AccountMeta::new(*reporter, true),
         AccountMeta::new(community_address, false),
-        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new(reporter_address, false),
         AccountMeta::new(case_address, false),
         AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
@@ -53,6 +65,7 @@ pub fn create_case(
         status,
         case_id,
         categories: *categories,
+        expires_at,
     };
 
     Ok(Instruction {
@@ -69,8 +82,10 @@ pub fn update_case(
     // Args
     community_name: &str,
     case_id: u64,
+    case_name: &str,
     status: CaseStatus,
     categories: &CategorySet,
+    expires_at: Option<UnixTimestamp>,
 ) -> Result<Instruction, GenericError> {
     let community_address = get_community_address(&community_name);
     let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
@@ -81,11 +96,16 @@ pub fn update_case(
         AccountMeta::new_readonly(community_address, false),
         AccountMeta::new_readonly(reporter_address, false),
         AccountMeta::new(case_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
     ];
 
     let instruction = HapiInstruction::UpdateCase {
+        case_id,
         categories: *categories,
         status,
+        name: case_name.to_string(),
+        expires_at,
     };
 
     Ok(Instruction {
@@ -101,15 +121,13 @@ pub fn create_address(
     reporter: &Pubkey,
     // Args
     network_path: &str,
-    address: &Pubkey,
     case_id: u64,
-    risk: u8,
-    category: Category,
+    report: &AddressReport,
 ) -> Result<Instruction, GenericError> {
     let (community_name, network_name) = parse_network_path(network_path)?;
     let community_address = get_community_address(&community_name);
     let network_address = get_network_address(&community_address, &network_name);
-    let address_address = get_address_address(&network_address, address);
+    let address_address = get_address_address(&network_address, &report.address);
     let reporter_address = get_reporter_address(&community_address, &reporter);
     let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
 
@@ -117,18 +135,21 @@ pub fn create_address(
         AccountMeta::new(*reporter, true),
         AccountMeta::new_readonly(community_address, false),
         AccountMeta::new_readonly(network_address, false),
-        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new(reporter_address, false),
         AccountMeta::new_readonly(case_address, false),
         AccountMeta::new(address_address, false),
         AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new(get_address_filter_address(&network_address), false),
     ];
 
     let instruction = HapiInstruction::CreateAddress {
-        address: *address,
-        risk,
+        address: report.address,
+        risk: report.risk,
         case_id,
-        category,
+        categories: report.categories,
+        primary_category: report.primary_category,
+        expires_at: report.expires_at,
     };
 
     Ok(Instruction {
@@ -144,30 +165,552 @@ pub fn update_address(
     reporter: &Pubkey,
     // Args
     network_path: &str,
-    address: &Pubkey,
     case_id: u64,
+    report: &AddressReport,
+    revision: u64,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let address_address = get_address_address(&network_address, &report.address);
+    let reporter_address = get_reporter_address(&community_address, &reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+    let address_revision_address = get_address_revision_address(&address_address, revision);
+
+    let accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new(reporter_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new(address_address, false),
+        AccountMeta::new(address_revision_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let instruction = HapiInstruction::UpdateAddress {
+        address: report.address,
+        risk: report.risk,
+        case_id,
+        categories: report.categories,
+        primary_category: report.primary_category,
+        expires_at: report.expires_at,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates CreateEntity instruction
+pub fn create_entity(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    community_name: &str,
+    entity_name: &str,
+    website: &str,
+    category: Category,
     risk: u8,
+    parent_name: Option<&str>,
+) -> Result<Instruction, GenericError> {
+    let community_address = get_community_address(community_name);
+    let entity_address = get_entity_address(&community_address, entity_name);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+
+    let mut accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new(entity_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    if let Some(parent_name) = parent_name {
+        let parent_address = get_entity_address(&community_address, parent_name);
+        accounts.push(AccountMeta::new_readonly(parent_address, false));
+    }
+
+    let instruction = HapiInstruction::CreateEntity {
+        category,
+        risk,
+        name: entity_name.to_string(),
+        website: website.to_string(),
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates UpdateEntity instruction.
+/// Parent names are the parent entity followed by its ancestors, nearest first, the entity has no parent if empty
+pub fn update_entity(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    community_name: &str,
+    entity_name: &str,
+    website: &str,
     category: Category,
+    risk: u8,
+    parent_names: &[&str],
+) -> Result<Instruction, GenericError> {
+    let community_address = get_community_address(community_name);
+    let entity_address = get_entity_address(&community_address, entity_name);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+
+    let mut accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new(entity_address, false),
+    ];
+
+    for parent_name in parent_names {
+        let parent_address = get_entity_address(&community_address, parent_name);
+        accounts.push(AccountMeta::new_readonly(parent_address, false));
+    }
+
+    let instruction = HapiInstruction::UpdateEntity {
+        category,
+        risk,
+        website: website.to_string(),
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates UpdateAddressEntity instruction
+pub fn update_address_entity(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    address: &Pubkey,
+    entity_name: Option<&str>,
 ) -> Result<Instruction, GenericError> {
     let (community_name, network_name) = parse_network_path(network_path)?;
     let community_address = get_community_address(&community_name);
     let network_address = get_network_address(&community_address, &network_name);
     let address_address = get_address_address(&network_address, address);
-    let reporter_address = get_reporter_address(&community_address, &reporter);
-    let case_address = get_case_address(&network_address, &case_id.to_le_bytes());
+    let reporter_address = get_reporter_address(&community_address, reporter);
+
+    let mut accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new(address_address, false),
+    ];
+
+    if let Some(entity_name) = entity_name {
+        let entity_address = get_entity_address(&community_address, entity_name);
+        accounts.push(AccountMeta::new_readonly(entity_address, false));
+    }
+
+    let instruction = HapiInstruction::UpdateAddressEntity { address: *address };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates CreateTransaction instruction
+pub fn create_transaction(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    case_id: u64,
+    report: &TransactionReport,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let transaction_address = get_transaction_address(&network_address, &report.tx_hash);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
 
     let accounts = vec![
         AccountMeta::new(*reporter, true),
         AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
         AccountMeta::new_readonly(reporter_address, false),
         AccountMeta::new_readonly(case_address, false),
-        AccountMeta::new_readonly(address_address, false),
+        AccountMeta::new(transaction_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
     ];
 
-    let instruction = HapiInstruction::UpdateAddress {
-        risk,
+    let instruction = HapiInstruction::CreateTransaction {
+        tx_hash: report.tx_hash,
+        risk: report.risk,
         case_id,
-        category,
+        category: report.category,
+        source: report.source,
+        destination: report.destination,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates UpdateTransaction instruction
+pub fn update_transaction(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    case_id: u64,
+    report: &TransactionReport,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let transaction_address = get_transaction_address(&network_address, &report.tx_hash);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+
+    let accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new(transaction_address, false),
+    ];
+
+    let instruction = HapiInstruction::UpdateTransaction {
+        tx_hash: report.tx_hash,
+        risk: report.risk,
+        case_id,
+        category: report.category,
+        source: report.source,
+        destination: report.destination,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates CreateAsset instruction
+pub fn create_asset(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    case_id: u64,
+    report: &AssetReport,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let asset_address = get_asset_address(&network_address, &report.asset, &report.token_id);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+
+    let accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new(asset_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let instruction = HapiInstruction::CreateAsset {
+        asset: report.asset,
+        token_id: report.token_id,
+        risk: report.risk,
+        case_id,
+        category: report.category,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates UpdateAsset instruction
+pub fn update_asset(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    case_id: u64,
+    report: &AssetReport,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let asset_address = get_asset_address(&network_address, &report.asset, &report.token_id);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+
+    let accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new(asset_address, false),
+    ];
+
+    let instruction = HapiInstruction::UpdateAsset {
+        asset: report.asset,
+        token_id: report.token_id,
+        risk: report.risk,
+        case_id,
+        category: report.category,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates CreateAddressLink instruction
+pub fn create_address_link(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    source: &Pubkey,
+    destination: &Pubkey,
+    kind: AddressLinkKind,
+    confidence: u8,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let address_link_address =
+        get_address_link_address(&network_address, source, destination, kind);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+
+    let accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new(address_link_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let instruction = HapiInstruction::CreateAddressLink {
+        source: *source,
+        destination: *destination,
+        kind,
+        confidence,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates SetReporterDelegate instruction
+pub fn set_reporter_delegate(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    community_name: &str,
+    delegate: Option<&Pubkey>,
+    valid_until: UnixTimestamp,
+) -> Result<Instruction, GenericError> {
+    let community_address = get_community_address(community_name);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new(reporter_address, false),
+    ];
+
+    let instruction = HapiInstruction::SetReporterDelegate {
+        delegate: delegate.copied(),
+        valid_until,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Makes a reporter instruction signed by the delegate of the reporter instead of its key,
+/// the delegate also pays for the created accounts unless the instruction has a separate payer
+pub fn sign_with_delegate(mut instruction: Instruction, delegate: &Pubkey) -> Instruction {
+    instruction.accounts[0].pubkey = *delegate;
+    instruction
+}
+
+/// Creates CreateAddresses instruction
+pub fn create_addresses(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    case_id: u64,
+    addresses: &[AddressReport],
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new(reporter_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new(get_address_filter_address(&network_address), false),
+    ];
+
+    for report in addresses {
+        accounts.push(AccountMeta::new(
+            get_address_address(&network_address, &report.address),
+            false,
+        ));
+    }
+
+    let instruction = HapiInstruction::CreateAddresses {
+        case_id,
+        addresses: addresses.to_vec(),
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates CreateAddresses instructions for any number of addresses,
+/// each of the instructions fits a separate transaction
+pub fn create_addresses_chunked(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    case_id: u64,
+    addresses: &[AddressReport],
+) -> Result<Vec<Instruction>, GenericError> {
+    addresses
+        .chunks(MAX_ADDRESSES_PER_INSTRUCTION)
+        .map(|chunk| create_addresses(reporter, network_path, case_id, chunk))
+        .collect()
+}
+
+/// Creates ReportAddress instruction.
+/// Revision is the current revision of an existing address, zero for a new one
+pub fn report_address(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    network_path: &str,
+    case_id: u64,
+    report: &AddressReport,
+    revision: u64,
+) -> Result<Instruction, GenericError> {
+    let (community_name, network_name) = parse_network_path(network_path)?;
+    let community_address = get_community_address(&community_name);
+    let network_address = get_network_address(&community_address, &network_name);
+    let address_address = get_address_address(&network_address, &report.address);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+    let address_revision_address = get_address_revision_address(&address_address, revision);
+
+    let accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(network_address, false),
+        AccountMeta::new(reporter_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new(address_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new(get_address_filter_address(&network_address), false),
+        AccountMeta::new(address_revision_address, false),
+    ];
+
+    let instruction = HapiInstruction::ReportAddress {
+        address: report.address,
+        risk: report.risk,
+        case_id,
+        categories: report.categories,
+        primary_category: report.primary_category,
+        expires_at: report.expires_at,
+    };
+
+    Ok(Instruction {
+        program_id: id(),
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    })
+}
+
+/// Creates UpdateAddressSet instruction
+pub fn update_address_set(
+    // Accounts
+    reporter: &Pubkey,
+    // Args
+    community_name: &str,
+    case_id: u64,
+    root: &MerkleHash,
+    size: u64,
+) -> Result<Instruction, GenericError> {
+    let community_address = get_community_address(community_name);
+    let reporter_address = get_reporter_address(&community_address, reporter);
+    let case_address = get_case_address(&community_address, &case_id.to_le_bytes());
+    let address_set_address = get_address_set_address(&case_address);
+
+    let accounts = vec![
+        AccountMeta::new(*reporter, true),
+        AccountMeta::new_readonly(community_address, false),
+        AccountMeta::new_readonly(reporter_address, false),
+        AccountMeta::new_readonly(case_address, false),
+        AccountMeta::new(address_set_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let instruction = HapiInstruction::UpdateAddressSet {
+        case_id,
+        root: *root,
+        size,
     };
 
     Ok(Instruction {
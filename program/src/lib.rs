@@ -2,6 +2,8 @@
 
 //! HAPI core smart contract for the Solana blockchain
 
+pub mod consumer;
+pub mod cpi;
 pub mod entrypoint;
 pub mod error;
 pub mod instruction;
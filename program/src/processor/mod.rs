@@ -8,29 +8,65 @@ use {
     },
 };
 
-use crate::instruction::HapiInstruction;
+use crate::instruction::{AddressReport, AssetReport, HapiInstruction, TransactionReport};
 
+mod process_check_address;
 mod process_create_address;
+mod process_create_address_link;
+mod process_create_addresses;
+mod process_create_asset;
 mod process_create_case;
 mod process_create_community;
+mod process_create_entity;
 mod process_create_network;
 mod process_create_reporter;
+mod process_create_transaction;
+mod process_rebuild_address_filter;
+mod process_record_lost_dispute;
+mod process_refresh_address;
+mod process_report_address;
+mod process_rotate_reporter_key;
+mod process_set_reporter_delegate;
 mod process_update_address;
+mod process_update_address_entity;
+mod process_update_address_set;
+mod process_update_asset;
 mod process_update_case;
 mod process_update_community;
+mod process_update_entity;
 mod process_update_network;
 mod process_update_reporter;
+mod process_update_transaction;
+mod process_verify_address_in_set;
 
+use process_check_address::*;
 use process_create_address::*;
+use process_create_address_link::*;
+use process_create_addresses::*;
+use process_create_asset::*;
 use process_create_case::*;
 use process_create_community::*;
+use process_create_entity::*;
 use process_create_network::*;
 use process_create_reporter::*;
+use process_create_transaction::*;
+use process_rebuild_address_filter::*;
+use process_record_lost_dispute::*;
+use process_refresh_address::*;
+use process_report_address::*;
+use process_rotate_reporter_key::*;
+use process_set_reporter_delegate::*;
 use process_update_address::*;
+use process_update_address_entity::*;
+use process_update_address_set::*;
+use process_update_asset::*;
 use process_update_case::*;
 use process_update_community::*;
+use process_update_entity::*;
 use process_update_network::*;
 use process_update_reporter::*;
+use process_update_transaction::*;
+use process_verify_address_in_set::*;
 
 /// Processes an instruction
 pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
@@ -44,7 +80,17 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
             process_create_community(program_id, accounts, &name)
         }
 
-        HapiInstruction::UpdateCommunity {} => process_update_community(program_id, accounts),
+        HapiInstruction::UpdateCommunity {
+            risk_decay_period,
+            report_quota,
+            report_quota_period,
+        } => process_update_community(
+            program_id,
+            accounts,
+            risk_decay_period,
+            report_quota,
+            report_quota_period,
+        ),
 
         HapiInstruction::CreateNetwork { name } => {
             process_create_network(program_id, accounts, &name)
@@ -55,35 +101,258 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
         HapiInstruction::CreateReporter {
             reporter_type,
             name,
-        } => process_create_reporter(program_id, accounts, &name, reporter_type),
+            active_until,
+        } => process_create_reporter(program_id, accounts, &name, reporter_type, active_until),
 
         HapiInstruction::UpdateReporter {
             reporter_type,
             name,
-        } => process_update_reporter(program_id, accounts, &name, reporter_type),
+            active_until,
+        } => process_update_reporter(program_id, accounts, &name, reporter_type, active_until),
 
         HapiInstruction::CreateCase {
             case_id,
             categories,
             status,
             name,
-        } => process_create_case(program_id, accounts, case_id, &name, &categories, status),
+            expires_at,
+        } => process_create_case(
+            program_id,
+            accounts,
+            case_id,
+            &name,
+            &categories,
+            status,
+            expires_at,
+        ),
 
-        HapiInstruction::UpdateCase { categories, status } => {
-            process_update_case(program_id, accounts, &categories, status)
-        }
+        HapiInstruction::UpdateCase {
+            case_id,
+            categories,
+            status,
+            name,
+            expires_at,
+        } => process_update_case(
+            program_id,
+            accounts,
+            case_id,
+            &name,
+            &categories,
+            status,
+            expires_at,
+        ),
 
         HapiInstruction::CreateAddress {
             address,
             risk,
             case_id,
-            category,
-        } => process_create_address(program_id, accounts, &address, case_id, risk, category),
+            categories,
+            primary_category,
+            expires_at,
+        } => process_create_address(
+            program_id,
+            accounts,
+            case_id,
+            &AddressReport {
+                address,
+                risk,
+                categories,
+                primary_category,
+                expires_at,
+            },
+        ),
 
         HapiInstruction::UpdateAddress {
+            address,
+            risk,
+            case_id,
+            categories,
+            primary_category,
+            expires_at,
+        } => process_update_address(
+            program_id,
+            accounts,
+            case_id,
+            &AddressReport {
+                address,
+                risk,
+                categories,
+                primary_category,
+                expires_at,
+            },
+        ),
+
+        HapiInstruction::CheckAddress {
+            address,
+            max_risk,
+            forbidden_categories,
+        } => process_check_address(
+            program_id,
+            accounts,
+            &address,
+            max_risk,
+            &forbidden_categories,
+        ),
+
+        HapiInstruction::CreateEntity {
+            category,
+            risk,
+            name,
+            website,
+        } => process_create_entity(program_id, accounts, category, risk, &name, &website),
+
+        HapiInstruction::UpdateEntity {
+            category,
+            risk,
+            website,
+        } => process_update_entity(program_id, accounts, category, risk, &website),
+
+        HapiInstruction::UpdateAddressEntity { address } => {
+            process_update_address_entity(program_id, accounts, &address)
+        }
+
+        HapiInstruction::CreateTransaction {
+            tx_hash,
+            risk,
+            case_id,
+            category,
+            source,
+            destination,
+        } => process_create_transaction(
+            program_id,
+            accounts,
+            case_id,
+            &TransactionReport {
+                tx_hash,
+                risk,
+                category,
+                source,
+                destination,
+            },
+        ),
+
+        HapiInstruction::UpdateTransaction {
+            tx_hash,
             risk,
             case_id,
             category,
-        } => process_update_address(program_id, accounts, case_id, risk, category),
+            source,
+            destination,
+        } => process_update_transaction(
+            program_id,
+            accounts,
+            case_id,
+            &TransactionReport {
+                tx_hash,
+                risk,
+                category,
+                source,
+                destination,
+            },
+        ),
+
+        HapiInstruction::CreateAsset {
+            asset,
+            token_id,
+            risk,
+            case_id,
+            category,
+        } => process_create_asset(
+            program_id,
+            accounts,
+            case_id,
+            &AssetReport {
+                asset,
+                token_id,
+                risk,
+                category,
+            },
+        ),
+
+        HapiInstruction::UpdateAsset {
+            asset,
+            token_id,
+            risk,
+            case_id,
+            category,
+        } => process_update_asset(
+            program_id,
+            accounts,
+            case_id,
+            &AssetReport {
+                asset,
+                token_id,
+                risk,
+                category,
+            },
+        ),
+
+        HapiInstruction::CreateAddressLink {
+            source,
+            destination,
+            kind,
+            confidence,
+        } => process_create_address_link(
+            program_id,
+            accounts,
+            &source,
+            &destination,
+            kind,
+            confidence,
+        ),
+
+        HapiInstruction::RefreshAddress { address } => {
+            process_refresh_address(program_id, accounts, &address)
+        }
+
+        HapiInstruction::RecordLostDispute {} => process_record_lost_dispute(program_id, accounts),
+
+        HapiInstruction::RotateReporterKey {} => process_rotate_reporter_key(program_id, accounts),
+
+        HapiInstruction::SetReporterDelegate {
+            delegate,
+            valid_until,
+        } => process_set_reporter_delegate(program_id, accounts, delegate, valid_until),
+
+        HapiInstruction::CreateAddresses { case_id, addresses } => {
+            process_create_addresses(program_id, accounts, case_id, &addresses)
+        }
+
+        HapiInstruction::ReportAddress {
+            address,
+            risk,
+            case_id,
+            categories,
+            primary_category,
+            expires_at,
+        } => process_report_address(
+            program_id,
+            accounts,
+            case_id,
+            &AddressReport {
+                address,
+                risk,
+                categories,
+                primary_category,
+                expires_at,
+            },
+        ),
+
+        HapiInstruction::UpdateAddressSet {
+            case_id,
+            root,
+            size,
+        } => process_update_address_set(program_id, accounts, case_id, &root, size),
+
+        HapiInstruction::VerifyAddressInSet {
+            address,
+            risk,
+            category,
+            proof,
+        } => process_verify_address_in_set(program_id, accounts, &address, risk, category, &proof),
+
+        HapiInstruction::RebuildAddressFilter { reset, addresses } => {
+            process_rebuild_address_filter(program_id, accounts, reset, &addresses)
+        }
     }
 }
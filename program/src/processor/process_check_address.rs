@@ -0,0 +1,97 @@
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    consumer::get_address_view,
+    error::HapiError,
+    state::community::get_community_data,
+    state::enums::CategorySet,
+    state::network::{assert_is_valid_network, assert_network_belongs_to_community},
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of CheckAddress instruction
+struct CheckAddressAccounts<'a, 'b> {
+    network_info: &'a AccountInfo<'b>,
+    address_info: &'a AccountInfo<'b>,
+    community_info: Option<&'a AccountInfo<'b>>,
+}
+
+impl<'a, 'b> CheckAddressAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CheckAddress", program_id, accounts);
+
+        Ok(Self {
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            address_info: context.next("Address", AccountConstraints::new())?,
+            community_info: context
+                .next_optional("Community", AccountConstraints::new().initialized())?,
+        })
+    }
+}
+
+pub fn process_check_address(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &Pubkey,
+    max_risk: u8,
+    forbidden_categories: &CategorySet,
+) -> ProgramResult {
+    let CheckAddressAccounts {
+        network_info,
+        address_info,
+        community_info,
+    } = CheckAddressAccounts::parse(program_id, accounts)?;
+
+    assert_is_valid_network(network_info)?;
+
+    let risk_decay_period = match community_info {
+        Some(community_info) => {
+            assert_network_belongs_to_community(network_info, community_info)?;
+            get_community_data(community_info)?.risk_decay_period
+        }
+        None => 0,
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let address_view = match get_address_view(
+        address_info,
+        network_info.key,
+        value,
+        now,
+        risk_decay_period,
+    )? {
+        Some(address_view) => address_view,
+        // Address is not reported
+        None => return Ok(()),
+    };
+
+    // Stale and expired reports bear no risk, neither by score nor by category
+    if address_view.stale || address_view.is_expired(now) {
+        return Ok(());
+    }
+
+    if address_view.effective_risk > max_risk {
+        msg!(
+            "Address risk {} exceeds acceptable risk {}",
+            address_view.effective_risk,
+            max_risk
+        );
+        return Err(HapiError::HighRiskAddress.into());
+    }
+
+    if address_view.has_any_category(forbidden_categories) {
+        msg!(
+            "Address categories {:#x} are forbidden",
+            address_view.categories & forbidden_categories
+        );
+        return Err(HapiError::HighRiskAddress.into());
+    }
+
+    Ok(())
+}
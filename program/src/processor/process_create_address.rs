@@ -1,73 +1,139 @@
-use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    msg,
-    pubkey::Pubkey,
-    rent::Rent,
-    sysvar::Sysvar,
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+        program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    },
 };
 
 use crate::{
-    error::HapiError,
-    state::address::{get_address_address_seeds, Address},
-    state::case::{assert_is_valid_case, get_case_address},
-    state::enums::{Category, HapiAccountType},
-    state::reporter::{assert_reporter_belongs_to_community, assert_reporter_can_create_address},
+    instruction::AddressReport,
+    state::address::{assert_address_categories_are_valid, get_address_address_seeds, Address},
+    state::address_filter::add_to_address_filter,
+    state::case::{assert_case_belongs_to_community, get_case_data},
+    state::community::{assert_is_valid_community, get_community_data},
+    state::enums::HapiAccountType,
+    state::network::assert_network_belongs_to_community,
+    state::reporter::{
+        assert_reporter_belongs_to_community, assert_reporter_can_create_address, get_reporter_data,
+    },
     tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
 };
 
+/// Accounts of CreateAddress instruction
+struct CreateAddressAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    address_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+    address_filter_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> CreateAddressAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateAddress", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            address_info: context.next("Address", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+            address_filter_info: context
+                .next("Address filter", AccountConstraints::new().writable())?,
+        })
+    }
+}
+
 pub fn process_create_address(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    value: &Pubkey,
     case_id: u64,
-    risk: u8,
-    category: Category,
+    report: &AddressReport,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let reporter_key_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let network_info = next_account_info(account_info_iter)?; // 2
-    let reporter_info = next_account_info(account_info_iter)?; // 3
-    let case_info = next_account_info(account_info_iter)?; // 4
-    let address_info = next_account_info(account_info_iter)?; // 5
-    let system_info = next_account_info(account_info_iter)?; // 6
-    let rent_sysvar_info = next_account_info(account_info_iter)?; // 7
-    let rent = &Rent::from_account_info(rent_sysvar_info)?;
-
-    // Reporter must sign
-    if !reporter_key_info.is_signer {
-        msg!("Reporter did not sign CreateCase");
-        return Err(HapiError::SignatureMissing.into());
-    }
+    let CreateAddressAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        case_info,
+        address_info,
+        system_info,
+        rent,
+        payer_info,
+        address_filter_info,
+    } = CreateAddressAccounts::parse(program_id, accounts)?;
 
     assert_reporter_belongs_to_community(reporter_info, community_info, &reporter_key_info.key)?;
     assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
     assert_is_empty_account(address_info)?;
 
     // Make sure that case ID and account is fine
-    assert_is_valid_case(&case_info)?;
-    if *case_info.key != get_case_address(community_info.key, &case_id.to_le_bytes()) {
-        msg!("Invalid case ID");
-        return Err(HapiError::CaseIDMismatch.into());
-    }
+    assert_case_belongs_to_community(case_info, community_info, case_id)?;
+
+    let case_data = get_case_data(case_info)?;
+    assert_address_categories_are_valid(
+        report.categories,
+        report.primary_category,
+        case_data.categories,
+    )?;
 
-    let address_data = Address {
+    // Count the report against reporter's quota
+    assert_is_valid_community(community_info)?;
+    let community_data = get_community_data(community_info)?;
+    let now = Clock::get()?.unix_timestamp;
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    reporter_data.consume_quota(&community_data, now)?;
+
+    let mut address_data = Address {
         account_type: HapiAccountType::Address,
-        risk,
+        risk: report.risk,
         case_id,
-        category,
+        categories: report.categories,
+        entity: None,
+        primary_category: report.primary_category,
+        updated_at: now,
+        expires_at: report.expires_at,
+        stale: false,
+        revision: 0,
+        bump_seed: 0,
     };
 
     create_and_serialize_account_signed::<Address>(
-        reporter_key_info,
+        payer_info,
         &address_info,
-        &address_data,
-        &get_address_address_seeds(&network_info.key, value),
+        &mut address_data,
+        &get_address_address_seeds(&network_info.key, &report.address),
         program_id,
         system_info,
-        rent,
+        &rent,
     )?;
 
+    add_to_address_filter(address_filter_info, network_info.key, &report.address)?;
+
+    // Record reporter activity
+    reporter_data.addresses_created += 1;
+    reporter_data.last_activity_at = now;
+    reporter_data.serialize(&mut *reporter_info.data.borrow_mut())?;
+
     Ok(())
 }
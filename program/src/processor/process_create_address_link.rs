@@ -0,0 +1,111 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent,
+};
+
+use crate::{
+    error::HapiError,
+    state::address_link::{get_address_link_address_seeds, AddressLink, MAX_LINK_CONFIDENCE},
+    state::enums::{AddressLinkKind, HapiAccountType},
+    state::network::assert_network_belongs_to_community,
+    state::reporter::{
+        assert_reporter_belongs_to_community, assert_reporter_can_create_address, get_reporter_data,
+    },
+    tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of CreateAddressLink instruction
+struct CreateAddressLinkAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    address_link_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> CreateAddressLinkAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateAddressLink", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            address_link_info: context
+                .next("Address link", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
+pub fn process_create_address_link(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    source: &Pubkey,
+    destination: &Pubkey,
+    kind: AddressLinkKind,
+    confidence: u8,
+) -> ProgramResult {
+    let CreateAddressLinkAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        address_link_info,
+        system_info,
+        rent,
+        payer_info,
+    } = CreateAddressLinkAccounts::parse(program_id, accounts)?;
+
+    if confidence > MAX_LINK_CONFIDENCE {
+        msg!("Link confidence must not exceed {}", MAX_LINK_CONFIDENCE);
+        return Err(HapiError::InvalidConfidence.into());
+    }
+
+    if source == destination {
+        msg!("Address can't be linked to itself");
+        return Err(HapiError::InvalidAddressLink.into());
+    }
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
+    assert_is_empty_account(address_link_info)?;
+
+    let reporter_data = get_reporter_data(reporter_info)?;
+
+    let mut address_link_data = AddressLink {
+        account_type: HapiAccountType::AddressLink,
+        network: *network_info.key,
+        source: *source,
+        destination: *destination,
+        kind,
+        confidence,
+        reporter_key: reporter_data.identity,
+        bump_seed: 0,
+    };
+
+    create_and_serialize_account_signed::<AddressLink>(
+        payer_info,
+        address_link_info,
+        &mut address_link_data,
+        &get_address_link_address_seeds(network_info.key, source, destination, &[kind as u8]),
+        program_id,
+        system_info,
+        &rent,
+    )?;
+
+    Ok(())
+}
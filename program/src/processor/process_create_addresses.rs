@@ -0,0 +1,153 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+        program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    instruction::AddressReport,
+    state::address::{assert_address_categories_are_valid, get_address_address_seeds, Address},
+    state::address_filter::add_to_address_filter,
+    state::case::{assert_case_belongs_to_community, get_case_data},
+    state::community::{assert_is_valid_community, get_community_data},
+    state::enums::HapiAccountType,
+    state::network::assert_network_belongs_to_community,
+    state::reporter::{
+        assert_reporter_belongs_to_community, assert_reporter_can_create_address, get_reporter_data,
+    },
+    tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of CreateAddresses instruction
+struct CreateAddressesAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    address_filter_info: &'a AccountInfo<'b>,
+    payer_info: &'a AccountInfo<'b>,
+    address_infos: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> CreateAddressesAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        address_count: usize,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateAddresses", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            address_filter_info: context
+                .next("Address filter", AccountConstraints::new().writable())?,
+            payer_info: context.next_payer(0)?,
+            address_infos: context.next_many(
+                "Address",
+                address_count,
+                AccountConstraints::new().writable(),
+            )?,
+        })
+    }
+}
+
+pub fn process_create_addresses(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    case_id: u64,
+    addresses: &[AddressReport],
+) -> ProgramResult {
+    let CreateAddressesAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        case_info,
+        system_info,
+        rent,
+        address_filter_info,
+        payer_info,
+        address_infos,
+    } = CreateAddressesAccounts::parse(program_id, accounts, addresses.len())?;
+
+    if addresses.is_empty() {
+        msg!("No addresses to report");
+        return Err(HapiError::InvalidInstruction.into());
+    }
+
+    assert_is_valid_community(community_info)?;
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
+
+    // Make sure that case ID and account is fine
+    assert_case_belongs_to_community(case_info, community_info, case_id)?;
+
+    let case_data = get_case_data(case_info)?;
+    let community_data = get_community_data(community_info)?;
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    for (report, address_info) in addresses.iter().zip(address_infos) {
+        assert_address_categories_are_valid(
+            report.categories,
+            report.primary_category,
+            case_data.categories,
+        )?;
+        assert_is_empty_account(address_info)?;
+
+        // Count the report against reporter's quota
+        reporter_data.consume_quota(&community_data, now)?;
+
+        let mut address_data = Address {
+            account_type: HapiAccountType::Address,
+            risk: report.risk,
+            case_id,
+            categories: report.categories,
+            entity: None,
+            primary_category: report.primary_category,
+            updated_at: now,
+            expires_at: report.expires_at,
+            stale: false,
+            revision: 0,
+            bump_seed: 0,
+        };
+
+        create_and_serialize_account_signed::<Address>(
+            payer_info,
+            address_info,
+            &mut address_data,
+            &get_address_address_seeds(network_info.key, &report.address),
+            program_id,
+            system_info,
+            &rent,
+        )?;
+
+        add_to_address_filter(address_filter_info, network_info.key, &report.address)?;
+    }
+
+    // Record reporter activity
+    reporter_data.addresses_created += addresses.len() as u64;
+    reporter_data.last_activity_at = now;
+    reporter_data.serialize(&mut *reporter_info.data.borrow_mut())?;
+
+    Ok(())
+}
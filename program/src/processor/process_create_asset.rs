@@ -0,0 +1,99 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent,
+};
+
+use crate::{
+    instruction::AssetReport,
+    state::asset::{get_asset_address_seeds, Asset},
+    state::case::{assert_case_belongs_to_community, assert_case_has_category},
+    state::enums::HapiAccountType,
+    state::network::assert_network_belongs_to_community,
+    state::reporter::{assert_reporter_belongs_to_community, assert_reporter_can_create_address},
+    tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of CreateAsset instruction
+struct CreateAssetAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    asset_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> CreateAssetAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateAsset", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            asset_info: context.next("Asset", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
+pub fn process_create_asset(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    case_id: u64,
+    report: &AssetReport,
+) -> ProgramResult {
+    let CreateAssetAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        case_info,
+        asset_info,
+        system_info,
+        rent,
+        payer_info,
+    } = CreateAssetAccounts::parse(program_id, accounts)?;
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
+    assert_is_empty_account(asset_info)?;
+
+    // Make sure that case ID and account is fine
+    assert_case_belongs_to_community(case_info, community_info, case_id)?;
+    assert_case_has_category(case_info, report.category)?;
+
+    let mut asset_data = Asset {
+        account_type: HapiAccountType::Asset,
+        risk: report.risk,
+        case_id,
+        category: report.category,
+        bump_seed: 0,
+    };
+
+    create_and_serialize_account_signed::<Asset>(
+        payer_info,
+        asset_info,
+        &mut asset_data,
+        &get_asset_address_seeds(network_info.key, &report.asset, &report.token_id),
+        program_id,
+        system_info,
+        &rent,
+    )?;
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use {
     borsh::BorshSerialize,
     solana_program::{
-        account_info::{next_account_info, AccountInfo},
+        account_info::AccountInfo,
+        clock::{Clock, UnixTimestamp},
         entrypoint::ProgramResult,
         msg,
+        program_error::ProgramError,
         pubkey::Pubkey,
         rent::Rent,
         sysvar::Sysvar,
@@ -16,11 +18,53 @@ use crate::{
         case::{get_case_address_seeds, Case},
         community::{assert_is_valid_community, get_community_data},
         enums::{CaseStatus, CategorySet, HapiAccountType},
-        reporter::{assert_reporter_can_create_case, get_reporter_address},
+        reporter::{
+            assert_reporter_belongs_to_community, assert_reporter_can_create_case,
+            get_reporter_data,
+        },
     },
     tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
 };
 
+/// Accounts of CreateCase instruction
+struct CreateCaseAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> CreateCaseAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateCase", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next(
+                "Community",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            case_info: context.next("Case", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
 pub fn process_create_case(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -28,35 +72,25 @@ pub fn process_create_case(
     name: &str,
     categories: &CategorySet,
     status: CaseStatus,
+    expires_at: Option<UnixTimestamp>,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let payer = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let reporter_info = next_account_info(account_info_iter)?; // 2
-    let case_info = next_account_info(account_info_iter)?; // 3
-    let system_info = next_account_info(account_info_iter)?; // 4
-    let rent_sysvar_info = next_account_info(account_info_iter)?; // 5
-    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    let CreateCaseAccounts {
+        reporter_key_info,
+        community_info,
+        reporter_info,
+        case_info,
+        system_info,
+        rent,
+        payer_info,
+    } = CreateCaseAccounts::parse(program_id, accounts)?;
 
     if name.len() > 32 {
         msg!("Case name must not exceed 32 bytes");
         return Err(HapiError::NameTooLong.into());
     }
 
-    // Reporter must sign
-    if !payer.is_signer {
-        msg!("Reporter did not sign CreateCase");
-        return Err(HapiError::SignatureMissing.into());
-    }
-
-    // Make sure that reporter's public key matches Reporter account
-    let reporter_address = get_reporter_address(&community_info.key, &payer.key);
-    if reporter_address != *reporter_info.key {
-        msg!("Reporter doesn't match Reporter account");
-        return Err(HapiError::InvalidReporter.into());
-    }
-
     assert_is_valid_community(community_info)?;
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
     assert_is_empty_account(case_info)?;
     assert_reporter_can_create_case(reporter_info)?;
 
@@ -69,23 +103,35 @@ pub fn process_create_case(
     community_data.next_case_id += 1;
     community_data.serialize(&mut *community_info.data.borrow_mut())?;
 
-    let case_data = Case {
+    // Count the report against reporter's quota
+    let now = Clock::get()?.unix_timestamp;
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    reporter_data.consume_quota(&community_data, now)?;
+
+    let mut case_data = Case {
         account_type: HapiAccountType::Case,
-        reporter_key: *payer.key,
+        reporter_key: reporter_data.identity,
         categories: *categories,
         status,
         name: name.to_string(),
+        expires_at,
+        bump_seed: 0,
     };
 
     create_and_serialize_account_signed::<Case>(
-        payer,
+        payer_info,
         &case_info,
-        &case_data,
+        &mut case_data,
         &get_case_address_seeds(&community_info.key, &case_id.to_le_bytes()),
         program_id,
         system_info,
-        rent,
+        &rent,
     )?;
 
+    // Record reporter activity
+    reporter_data.cases_created += 1;
+    reporter_data.last_activity_at = now;
+    reporter_data.serialize(&mut *reporter_info.data.borrow_mut())?;
+
     Ok(())
 }
@@ -1,10 +1,6 @@
 use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    msg,
-    pubkey::Pubkey,
-    rent::Rent,
-    sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent,
 };
 
 use crate::{
@@ -13,44 +9,83 @@ use crate::{
         community::{get_community_address_seeds, Community},
         enums::HapiAccountType,
     },
-    tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::{
+        account::{assert_is_empty_account, create_and_serialize_account_signed},
+        account_context::{AccountConstraints, AccountContext},
+        name::{get_name_hash, is_valid_name, MAX_NAME_LENGTH},
+    },
 };
 
+/// Accounts of CreateCommunity instruction
+struct CreateCommunityAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> CreateCommunityAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateCommunity", program_id, accounts);
+
+        let authority_info = context.next("Authority", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            authority_info,
+            community_info: context.next("Community", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
 pub fn process_create_community(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     name: &str,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let payer_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let system_info = next_account_info(account_info_iter)?; // 2
-    let rent_sysvar_info = next_account_info(account_info_iter)?; // 3
-    let rent = &Rent::from_account_info(rent_sysvar_info)?;
-
-    // Authority must sign
-    if !payer_info.is_signer {
-        msg!("Authority did not sign initialization");
-        return Err(HapiError::SignatureMissing.into());
+    let CreateCommunityAccounts {
+        authority_info,
+        community_info,
+        system_info,
+        rent,
+        payer_info,
+    } = CreateCommunityAccounts::parse(program_id, accounts)?;
+
+    if !is_valid_name(name) {
+        msg!(
+            "Community name must be 1 to {} printable characters other than '/'",
+            MAX_NAME_LENGTH
+        );
+        return Err(HapiError::InvalidName.into());
     }
 
     assert_is_empty_account(community_info)?;
 
-    let community_data = Community {
+    let mut community_data = Community {
         account_type: HapiAccountType::Community,
-        authority: *payer_info.key,
+        authority: *authority_info.key,
         next_case_id: 0,
         name: name.to_string(),
+        risk_decay_period: 0,
+        report_quota: 0,
+        report_quota_period: 0,
+        bump_seed: 0,
     };
 
     create_and_serialize_account_signed::<Community>(
         payer_info,
         &community_info,
-        &community_data,
-        &get_community_address_seeds(name),
+        &mut community_data,
+        &get_community_address_seeds(&get_name_hash(name)),
         program_id,
         system_info,
-        rent,
+        &rent,
     )?;
 
     Ok(())
@@ -0,0 +1,124 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent,
+};
+
+use crate::{
+    error::HapiError,
+    state::{
+        community::assert_is_valid_community,
+        entity::{assert_entity_belongs_to_community, get_entity_address_seeds, Entity},
+        enums::{Category, HapiAccountType},
+        reporter::{
+            assert_reporter_belongs_to_community, assert_reporter_can_create_entity,
+            get_reporter_data,
+        },
+    },
+    tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
+    tools::name::get_name_hash,
+};
+
+/// Accounts of CreateEntity instruction
+struct CreateEntityAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    entity_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+    parent_info: Option<&'a AccountInfo<'b>>,
+}
+
+impl<'a, 'b> CreateEntityAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateEntity", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            entity_info: context.next("Entity", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+            parent_info: context
+                .next_optional("Parent entity", AccountConstraints::new().initialized())?,
+        })
+    }
+}
+
+pub fn process_create_entity(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    category: Category,
+    risk: u8,
+    name: &str,
+    website: &str,
+) -> ProgramResult {
+    let CreateEntityAccounts {
+        reporter_key_info,
+        community_info,
+        reporter_info,
+        entity_info,
+        system_info,
+        rent,
+        payer_info,
+        parent_info,
+    } = CreateEntityAccounts::parse(program_id, accounts)?;
+
+    if name.len() > 32 {
+        msg!("Entity name must not exceed 32 bytes");
+        return Err(HapiError::NameTooLong.into());
+    }
+
+    if website.len() > 64 {
+        msg!("Entity website must not exceed 64 bytes");
+        return Err(HapiError::WebsiteTooLong.into());
+    }
+
+    assert_is_valid_community(community_info)?;
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_entity(reporter_info)?;
+    assert_is_empty_account(entity_info)?;
+
+    // If parent entity account is supplemented, it must be an entity of the same community.
+    // A new entity has no children, so its parent chain can't cycle back to it
+    let parent = if let Some(info) = parent_info {
+        assert_entity_belongs_to_community(info, community_info)?;
+        Some(*info.key)
+    } else {
+        None
+    };
+
+    let reporter_data = get_reporter_data(reporter_info)?;
+
+    let mut entity_data = Entity {
+        account_type: HapiAccountType::Entity,
+        reporter_key: reporter_data.identity,
+        category,
+        risk,
+        parent,
+        name: name.to_string(),
+        website: website.to_string(),
+        bump_seed: 0,
+    };
+
+    create_and_serialize_account_signed::<Entity>(
+        payer_info,
+        entity_info,
+        &mut entity_data,
+        &get_entity_address_seeds(community_info.key, &get_name_hash(name)),
+        program_id,
+        system_info,
+        &rent,
+    )?;
+
+    Ok(())
+}
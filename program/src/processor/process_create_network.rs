@@ -1,10 +1,6 @@
 use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    msg,
-    pubkey::Pubkey,
-    rent::Rent,
-    sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent,
 };
 
 use crate::{
@@ -13,31 +9,63 @@ use crate::{
     state::enums::HapiAccountType,
     state::network::get_network_address_seeds,
     state::network::Network,
-    tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::{
+        account::{assert_is_empty_account, create_and_serialize_account_signed},
+        account_context::{AccountConstraints, AccountContext},
+        name::{get_name_hash, is_valid_name, MAX_NAME_LENGTH},
+    },
 };
 
+/// Accounts of CreateNetwork instruction
+struct CreateNetworkAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> CreateNetworkAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateNetwork", program_id, accounts);
+
+        let authority_info = context.next("Authority", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            authority_info,
+            network_info: context.next("Network", AccountConstraints::new().writable())?,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
 pub fn process_create_network(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     name: &str,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let authority_info = next_account_info(account_info_iter)?; // 0
-    let network_info = next_account_info(account_info_iter)?; // 1
-    let community_info = next_account_info(account_info_iter)?; // 2
-    let system_info = next_account_info(account_info_iter)?; // 3
-    let rent_sysvar_info = next_account_info(account_info_iter)?; // 4
-    let rent = &Rent::from_account_info(rent_sysvar_info)?;
-
-    if name.len() > 32 {
-        msg!("Network name must not exceed 32 bytes");
-        return Err(HapiError::NameTooLong.into());
-    }
+    let CreateNetworkAccounts {
+        authority_info,
+        network_info,
+        community_info,
+        system_info,
+        rent,
+        payer_info,
+    } = CreateNetworkAccounts::parse(program_id, accounts)?;
 
-    // Authority must sign
-    if !authority_info.is_signer {
-        msg!("Authority did not sign initialization");
-        return Err(HapiError::SignatureMissing.into());
+    if !is_valid_name(name) {
+        msg!(
+            "Network name must be 1 to {} printable characters other than '/'",
+            MAX_NAME_LENGTH
+        );
+        return Err(HapiError::InvalidName.into());
     }
 
     assert_is_valid_community(community_info)?;
@@ -49,19 +77,20 @@ pub fn process_create_network(
         return Err(HapiError::InvalidNetworkAuthority.into());
     }
 
-    let network_data = Network {
+    let mut network_data = Network {
         account_type: HapiAccountType::Network,
         name: name.to_string(),
+        bump_seed: 0,
     };
 
     create_and_serialize_account_signed::<Network>(
-        authority_info,
+        payer_info,
         &network_info,
-        &network_data,
-        &get_network_address_seeds(&community_info.key, &name),
+        &mut network_data,
+        &get_network_address_seeds(community_info.key, &get_name_hash(name)),
         program_id,
         system_info,
-        rent,
+        &rent,
     )?;
 
     Ok(())
@@ -1,10 +1,6 @@
 use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    msg,
-    pubkey::Pubkey,
-    rent::Rent,
-    sysvar::Sysvar,
+    account_info::AccountInfo, clock::UnixTimestamp, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent,
 };
 
 use crate::{
@@ -14,34 +10,63 @@ use crate::{
     state::reporter::get_reporter_address_seeds,
     state::reporter::Reporter,
     tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
 };
 
+/// Accounts of CreateReporter instruction
+struct CreateReporterAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_key_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> CreateReporterAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("CreateReporter", program_id, accounts);
+
+        let authority_info = context.next("Authority", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            authority_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_key_info: context.next("Reporter key", AccountConstraints::new())?,
+            reporter_info: context.next("Reporter", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
 pub fn process_create_reporter(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     name: &str,
     reporter_type: ReporterType,
+    active_until: Option<UnixTimestamp>,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let authority_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let reporter_key_info = next_account_info(account_info_iter)?; // 2
-    let reporter_info = next_account_info(account_info_iter)?; // 3
-    let system_info = next_account_info(account_info_iter)?; // 4
-    let rent_sysvar_info = next_account_info(account_info_iter)?; // 5
-    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    let CreateReporterAccounts {
+        authority_info,
+        community_info,
+        reporter_key_info,
+        reporter_info,
+        system_info,
+        rent,
+        payer_info,
+    } = CreateReporterAccounts::parse(program_id, accounts)?;
 
     if name.len() > 32 {
         msg!("Reporter name must not exceed 32 bytes");
         return Err(HapiError::NameTooLong.into());
     }
 
-    // Authority must sign
-    if !authority_info.is_signer {
-        msg!("Payer did not sign");
-        return Err(HapiError::SignatureMissing.into());
-    }
-
     // Authority must match community record
     assert_is_valid_community(community_info)?;
     let community_data = get_community_data(community_info)?;
@@ -52,20 +77,33 @@ pub fn process_create_reporter(
 
     assert_is_empty_account(reporter_info)?;
 
-    let reporter_data = Reporter {
+    let mut reporter_data = Reporter {
         account_type: HapiAccountType::Reporter,
         name: name.to_string(),
         reporter_type,
+        cases_created: 0,
+        addresses_created: 0,
+        addresses_updated: 0,
+        disputes_lost: 0,
+        last_activity_at: 0,
+        quota_period_start: 0,
+        quota_period_reports: 0,
+        identity: *reporter_key_info.key,
+        key: *reporter_key_info.key,
+        delegate: None,
+        delegate_valid_until: 0,
+        active_until,
+        bump_seed: 0,
     };
 
     create_and_serialize_account_signed::<Reporter>(
-        authority_info,
+        payer_info,
         &reporter_info,
-        &reporter_data,
+        &mut reporter_data,
         &get_reporter_address_seeds(community_info.key, reporter_key_info.key),
         program_id,
         system_info,
-        rent,
+        &rent,
     )?;
 
     Ok(())
@@ -0,0 +1,127 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent,
+};
+
+use crate::{
+    error::HapiError,
+    state::address::{
+        assert_is_valid_address, get_address_address, get_address_address_seeds, AddressRef,
+    },
+    state::address_filter::{
+        add_to_address_filter, clear_address_filter, get_address_filter_address_seeds,
+        AddressFilter,
+    },
+    state::community::{assert_is_valid_community, get_community_data},
+    state::network::assert_network_belongs_to_community,
+    tools::account::create_and_serialize_account_signed,
+    tools::account_context::{assert_account_address, AccountConstraints, AccountContext},
+};
+
+/// Accounts of RebuildAddressFilter instruction
+struct RebuildAddressFilterAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    address_filter_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+    address_infos: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> RebuildAddressFilterAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        address_count: usize,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("RebuildAddressFilter", program_id, accounts);
+
+        let authority_info = context.next("Authority", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            authority_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            address_filter_info: context
+                .next("Address filter", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+            address_infos: context.next_many(
+                "Address",
+                address_count,
+                AccountConstraints::new(),
+            )?,
+        })
+    }
+}
+
+pub fn process_rebuild_address_filter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reset: bool,
+    addresses: &[Pubkey],
+) -> ProgramResult {
+    let RebuildAddressFilterAccounts {
+        authority_info,
+        community_info,
+        network_info,
+        address_filter_info,
+        system_info,
+        rent,
+        payer_info,
+        address_infos,
+    } = RebuildAddressFilterAccounts::parse(program_id, accounts, addresses.len())?;
+
+    // Authority must match community
+    assert_is_valid_community(community_info)?;
+    let community_data = get_community_data(community_info)?;
+    if *authority_info.key != community_data.authority {
+        msg!("Signer does not match community authority");
+        return Err(HapiError::InvalidNetworkAuthority.into());
+    }
+
+    assert_network_belongs_to_community(network_info, community_info)?;
+
+    if address_filter_info.data_is_empty() {
+        create_and_serialize_account_signed::<AddressFilter>(
+            payer_info,
+            address_filter_info,
+            &mut AddressFilter::new(network_info.key),
+            &get_address_filter_address_seeds(network_info.key),
+            program_id,
+            system_info,
+            &rent,
+        )?;
+    } else if reset {
+        clear_address_filter(address_filter_info, network_info.key)?;
+    }
+
+    for (address, address_info) in addresses.iter().zip(address_infos) {
+        // Only addresses that bear risk are added
+        if address_info.data_is_empty() {
+            if *address_info.key != get_address_address(network_info.key, address) {
+                msg!("Address account doesn't match the address");
+                return Err(ProgramError::InvalidSeeds);
+            }
+            continue;
+        }
+
+        assert_is_valid_address(address_info)?;
+        let data = address_info.data.borrow();
+        let address_ref = AddressRef::new(&data)?;
+        assert_account_address(
+            "Address",
+            address_info,
+            &get_address_address_seeds(network_info.key, address),
+            address_ref.bump_seed()?,
+            program_id,
+        )?;
+
+        add_to_address_filter(address_filter_info, network_info.key, address)?;
+    }
+
+    Ok(())
+}
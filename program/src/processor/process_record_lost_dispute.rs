@@ -0,0 +1,77 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    state::community::{assert_is_valid_community, get_community_data},
+    state::reporter::{assert_is_valid_reporter, get_reporter_address_seeds, get_reporter_data},
+    tools::account::is_program_address,
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of RecordLostDispute instruction
+struct RecordLostDisputeAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    reporter_key_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> RecordLostDisputeAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("RecordLostDispute", program_id, accounts);
+
+        Ok(Self {
+            authority_info: context.next("Authority", AccountConstraints::new().signer())?,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            reporter_key_info: context.next("Reporter key", AccountConstraints::new())?,
+        })
+    }
+}
+
+pub fn process_record_lost_dispute(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let RecordLostDisputeAccounts {
+        authority_info,
+        community_info,
+        reporter_info,
+        reporter_key_info,
+    } = RecordLostDisputeAccounts::parse(program_id, accounts)?;
+
+    // Authority must match community
+    assert_is_valid_community(community_info)?;
+    let community_data = get_community_data(community_info)?;
+    if *authority_info.key != community_data.authority {
+        msg!("Signer does not match community authority");
+        return Err(HapiError::InvalidNetworkAuthority.into());
+    }
+
+    // Make sure that this is in fact a correct reporter
+    assert_is_valid_reporter(reporter_info)?;
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    if !is_program_address(
+        reporter_info.key,
+        &get_reporter_address_seeds(community_info.key, reporter_key_info.key),
+        reporter_data.bump_seed,
+        program_id,
+    ) {
+        msg!("Reporter doesn't match Reporter account");
+        return Err(HapiError::InvalidReporter.into());
+    }
+
+    reporter_data.disputes_lost += 1;
+    reporter_data.serialize(&mut *reporter_info.data.borrow_mut())?;
+
+    Ok(())
+}
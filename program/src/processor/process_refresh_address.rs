@@ -0,0 +1,82 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+        program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    state::address::{get_address_address_seeds, get_address_bump_seed, get_address_data},
+    state::case::{assert_case_belongs_to_community, get_case_data},
+    state::network::assert_network_belongs_to_community,
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of RefreshAddress instruction
+struct RefreshAddressAccounts<'a, 'b> {
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    address_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> RefreshAddressAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        value: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("RefreshAddress", program_id, accounts);
+
+        let community_info = context.next("Community", AccountConstraints::new().initialized())?;
+        let network_info = context.next("Network", AccountConstraints::new().initialized())?;
+
+        Ok(Self {
+            community_info,
+            network_info,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            address_info: context.next(
+                "Address",
+                AccountConstraints::new().writable().pda(
+                    &get_address_address_seeds(network_info.key, value),
+                    get_address_bump_seed,
+                ),
+            )?,
+        })
+    }
+}
+
+pub fn process_refresh_address(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &Pubkey,
+) -> ProgramResult {
+    let RefreshAddressAccounts {
+        community_info,
+        network_info,
+        case_info,
+        address_info,
+    } = RefreshAddressAccounts::parse(program_id, accounts, value)?;
+
+    assert_network_belongs_to_community(network_info, community_info)?;
+
+    let mut address_data = get_address_data(address_info)?;
+
+    // Make sure that case account belongs to the address
+    assert_case_belongs_to_community(case_info, community_info, address_data.case_id)?;
+
+    let case_data = get_case_data(case_info)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if !address_data.is_expired(now) && !case_data.is_expired(now) {
+        msg!("Address report is not due for review");
+        return Err(HapiError::ReportNotExpired.into());
+    }
+
+    address_data.stale = true;
+    address_data.serialize(&mut *address_info.data.borrow_mut())?;
+
+    Ok(())
+}
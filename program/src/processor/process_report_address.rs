@@ -0,0 +1,154 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar, sysvar::Sysvar,
+};
+
+use crate::{
+    error::HapiError,
+    instruction::AddressReport,
+    processor::{process_create_address, update_address, UpdateAddressAccounts},
+    state::address::{get_address_address_seeds, get_address_data},
+    state::reporter::{assert_reporter_belongs_to_community, assert_reporter_can_create_address},
+    tools::account_context::{assert_account_address, AccountConstraints, AccountContext},
+};
+
+/// Accounts of ReportAddress instruction
+struct ReportAddressAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    address_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent_sysvar_info: &'a AccountInfo<'b>,
+    address_filter_info: &'a AccountInfo<'b>,
+    payer_info: &'a AccountInfo<'b>,
+    address_revision_info: Option<&'a AccountInfo<'b>>,
+}
+
+impl<'a, 'b> ReportAddressAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("ReportAddress", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            address_info: context.next("Address", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent_sysvar_info: context.next(
+                "Sysvar Rent",
+                AccountConstraints::new().address(&sysvar::rent::id()),
+            )?,
+            address_filter_info: context
+                .next("Address filter", AccountConstraints::new().writable())?,
+            payer_info: context.next_payer(0)?,
+            address_revision_info: context
+                .next_optional("Address revision", AccountConstraints::new().writable())?,
+        })
+    }
+}
+
+pub fn process_report_address(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    case_id: u64,
+    report: &AddressReport,
+) -> ProgramResult {
+    let ReportAddressAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        case_info,
+        address_info,
+        system_info,
+        rent_sysvar_info,
+        address_filter_info,
+        payer_info,
+        address_revision_info,
+    } = ReportAddressAccounts::parse(program_id, accounts)?;
+
+    // Report a new address
+    if address_info.data_is_empty() {
+        return process_create_address(
+            program_id,
+            &[
+                reporter_key_info.clone(),
+                community_info.clone(),
+                network_info.clone(),
+                reporter_info.clone(),
+                case_info.clone(),
+                address_info.clone(),
+                system_info.clone(),
+                rent_sysvar_info.clone(),
+                payer_info.clone(),
+                address_filter_info.clone(),
+            ],
+            case_id,
+            report,
+        );
+    }
+
+    // Replaced values of an existing address must be recorded in a revision
+    let address_revision_info = match address_revision_info {
+        Some(address_revision_info) => address_revision_info,
+        None => {
+            msg!("ReportAddress: Address revision account is required to update an address");
+            return Err(HapiError::AddressRevisionMissing.into());
+        }
+    };
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_address(reporter_info)?;
+
+    let address_data = get_address_data(address_info)?;
+    assert_account_address(
+        "Address",
+        address_info,
+        &get_address_address_seeds(network_info.key, &report.address),
+        address_data.bump_seed,
+        program_id,
+    )?;
+
+    // Reports matching the current address data are ignored
+    if !address_data.stale
+        && address_data.case_id == case_id
+        && address_data.risk == report.risk
+        && address_data.categories == report.categories
+        && address_data.primary_category == report.primary_category
+        && address_data.expires_at == report.expires_at
+    {
+        msg!("Address report is up to date");
+        return Ok(());
+    }
+
+    update_address(
+        program_id,
+        UpdateAddressAccounts {
+            reporter_key_info,
+            community_info,
+            network_info,
+            reporter_info,
+            case_info,
+            address_info,
+            address_revision_info,
+            system_info,
+            rent: Rent::from_account_info(rent_sysvar_info)?,
+            payer_info,
+        },
+        case_id,
+        report,
+    )
+}
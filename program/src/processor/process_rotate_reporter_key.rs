@@ -0,0 +1,114 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent,
+};
+
+use crate::{
+    error::HapiError,
+    state::community::{assert_is_valid_community, get_community_data},
+    state::reporter::{
+        assert_is_valid_reporter, get_reporter_address_seeds, get_reporter_data, Reporter,
+    },
+    tools::account::{
+        assert_is_empty_account, create_and_serialize_account_signed, dispose_account,
+        is_program_address,
+    },
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of RotateReporterKey instruction
+struct RotateReporterKeyAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    reporter_key_info: &'a AccountInfo<'b>,
+    new_reporter_key_info: &'a AccountInfo<'b>,
+    new_reporter_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> RotateReporterKeyAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("RotateReporterKey", program_id, accounts);
+
+        let authority_info =
+            context.next("Authority", AccountConstraints::new().signer().writable())?;
+
+        Ok(Self {
+            authority_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            reporter_key_info: context.next("Reporter key", AccountConstraints::new())?,
+            new_reporter_key_info: context.next("New reporter key", AccountConstraints::new())?,
+            new_reporter_info: context
+                .next("New reporter", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
+pub fn process_rotate_reporter_key(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let RotateReporterKeyAccounts {
+        authority_info,
+        community_info,
+        reporter_info,
+        reporter_key_info,
+        new_reporter_key_info,
+        new_reporter_info,
+        system_info,
+        rent,
+        payer_info,
+    } = RotateReporterKeyAccounts::parse(program_id, accounts)?;
+
+    // Authority must match community
+    assert_is_valid_community(community_info)?;
+    let community_data = get_community_data(community_info)?;
+    if *authority_info.key != community_data.authority {
+        msg!("Signer does not match community authority");
+        return Err(HapiError::InvalidNetworkAuthority.into());
+    }
+
+    // Make sure that this is in fact a correct reporter
+    assert_is_valid_reporter(reporter_info)?;
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    if !is_program_address(
+        reporter_info.key,
+        &get_reporter_address_seeds(community_info.key, reporter_key_info.key),
+        reporter_data.bump_seed,
+        program_id,
+    ) {
+        msg!("Reporter doesn't match Reporter account");
+        return Err(HapiError::InvalidReporter.into());
+    }
+
+    assert_is_empty_account(new_reporter_info)?;
+
+    // Move reporter data to the account of the new key, delegate is revoked
+    reporter_data.key = *new_reporter_key_info.key;
+    reporter_data.delegate = None;
+    reporter_data.delegate_valid_until = 0;
+
+    create_and_serialize_account_signed::<Reporter>(
+        payer_info,
+        new_reporter_info,
+        &mut reporter_data,
+        &get_reporter_address_seeds(community_info.key, new_reporter_key_info.key),
+        program_id,
+        system_info,
+        &rent,
+    )?;
+
+    dispose_account(reporter_info, authority_info);
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, clock::UnixTimestamp, entrypoint::ProgramResult, msg,
+        program_error::ProgramError, pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    state::reporter::{assert_is_valid_reporter, get_reporter_address_seeds, get_reporter_data},
+    tools::account::is_program_address,
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of SetReporterDelegate instruction
+struct SetReporterDelegateAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> SetReporterDelegateAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("SetReporterDelegate", program_id, accounts);
+
+        Ok(Self {
+            reporter_key_info: context.next("Reporter key", AccountConstraints::new().signer())?,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+        })
+    }
+}
+
+pub fn process_set_reporter_delegate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delegate: Option<Pubkey>,
+    valid_until: UnixTimestamp,
+) -> ProgramResult {
+    let SetReporterDelegateAccounts {
+        reporter_key_info,
+        community_info,
+        reporter_info,
+    } = SetReporterDelegateAccounts::parse(program_id, accounts)?;
+
+    // Only the reporter key itself can assign a delegate
+    assert_is_valid_reporter(reporter_info)?;
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    if !is_program_address(
+        reporter_info.key,
+        &get_reporter_address_seeds(community_info.key, reporter_key_info.key),
+        reporter_data.bump_seed,
+        program_id,
+    ) {
+        msg!("Reporter doesn't match Reporter account");
+        return Err(HapiError::InvalidReporter.into());
+    }
+
+    reporter_data.delegate = delegate;
+    reporter_data.delegate_valid_until = valid_until;
+    reporter_data.serialize(&mut *reporter_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -1,58 +1,173 @@
 use {
     borsh::BorshSerialize,
     solana_program::{
-        account_info::{next_account_info, AccountInfo},
-        entrypoint::ProgramResult,
-        msg,
-        pubkey::Pubkey,
+        account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+        program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
     },
 };
 
 use crate::{
-    error::HapiError,
-    state::address::{assert_is_valid_address, get_address_data},
-    state::case::{assert_is_valid_case, get_case_address},
-    state::enums::Category,
-    state::reporter::{assert_reporter_belongs_to_community, assert_reporter_can_create_address},
+    instruction::AddressReport,
+    state::address::{
+        assert_address_categories_are_valid, assert_is_valid_address, get_address_address_seeds,
+        get_address_bump_seed, get_address_data,
+    },
+    state::address_revision::{get_address_revision_address_seeds, AddressRevision},
+    state::case::{assert_case_belongs_to_community, get_case_data},
+    state::enums::HapiAccountType,
+    state::network::assert_network_belongs_to_community,
+    state::reporter::{
+        assert_reporter_belongs_to_community, assert_reporter_can_create_address, get_reporter_data,
+    },
+    tools::account::{assert_is_empty_account, create_and_serialize_account_signed},
+    tools::account_context::{AccountConstraints, AccountContext},
 };
 
+/// Accounts of UpdateAddress instruction, also used by ReportAddress
+pub struct UpdateAddressAccounts<'a, 'b> {
+    pub reporter_key_info: &'a AccountInfo<'b>,
+    pub community_info: &'a AccountInfo<'b>,
+    pub network_info: &'a AccountInfo<'b>,
+    pub reporter_info: &'a AccountInfo<'b>,
+    pub case_info: &'a AccountInfo<'b>,
+    pub address_info: &'a AccountInfo<'b>,
+    pub address_revision_info: &'a AccountInfo<'b>,
+    pub system_info: &'a AccountInfo<'b>,
+    pub rent: Rent,
+    pub payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> UpdateAddressAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        value: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateAddress", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+        let community_info = context.next("Community", AccountConstraints::new().initialized())?;
+        let network_info = context.next("Network", AccountConstraints::new().initialized())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info,
+            network_info,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            address_info: context.next(
+                "Address",
+                AccountConstraints::new().writable().pda(
+                    &get_address_address_seeds(network_info.key, value),
+                    get_address_bump_seed,
+                ),
+            )?,
+            address_revision_info: context
+                .next("Address revision", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
 pub fn process_update_address(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     case_id: u64,
-    risk: u8,
-    category: Category,
+    report: &AddressReport,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let reporter_key_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let reporter_info = next_account_info(account_info_iter)?; // 3
-    let case_info = next_account_info(account_info_iter)?; // 4
-    let address_info = next_account_info(account_info_iter)?; // 5
-
-    // Reporter must sign
-    if !reporter_key_info.is_signer {
-        msg!("Reporter did not sign CreateCase");
-        return Err(HapiError::SignatureMissing.into());
-    }
+    update_address(
+        program_id,
+        UpdateAddressAccounts::parse(program_id, accounts, &report.address)?,
+        case_id,
+        report,
+    )
+}
+
+/// Updates address data, recording the replaced values in the address revision account
+pub fn update_address(
+    program_id: &Pubkey,
+    accounts: UpdateAddressAccounts,
+    case_id: u64,
+    report: &AddressReport,
+) -> ProgramResult {
+    let UpdateAddressAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        case_info,
+        address_info,
+        address_revision_info,
+        system_info,
+        rent,
+        payer_info,
+    } = accounts;
 
     assert_is_valid_address(address_info)?;
     assert_reporter_belongs_to_community(reporter_info, community_info, &reporter_key_info.key)?;
     assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
 
     // Make sure that case ID and account is fine
-    assert_is_valid_case(&case_info)?;
-    if *case_info.key != get_case_address(community_info.key, &case_id.to_le_bytes()) {
-        msg!("Invalid case ID");
-        return Err(HapiError::CaseIDMismatch.into());
-    }
+    assert_case_belongs_to_community(case_info, community_info, case_id)?;
+
+    let case_data = get_case_data(case_info)?;
+    assert_address_categories_are_valid(
+        report.categories,
+        report.primary_category,
+        case_data.categories,
+    )?;
 
-    // Update address data
     let mut address_data = get_address_data(address_info)?;
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Record replaced values in a new revision
+    assert_is_empty_account(address_revision_info)?;
+
+    let mut address_revision_data = AddressRevision {
+        account_type: HapiAccountType::AddressRevision,
+        address: *address_info.key,
+        revision: address_data.revision,
+        risk: address_data.risk,
+        case_id: address_data.case_id,
+        categories: address_data.categories,
+        primary_category: address_data.primary_category,
+        updater_key: reporter_data.identity,
+        updated_at: now,
+        bump_seed: 0,
+    };
+
+    create_and_serialize_account_signed::<AddressRevision>(
+        payer_info,
+        address_revision_info,
+        &mut address_revision_data,
+        &get_address_revision_address_seeds(address_info.key, &address_data.revision.to_le_bytes()),
+        program_id,
+        system_info,
+        &rent,
+    )?;
+
+    // Update address data
     address_data.case_id = case_id;
-    address_data.category = category;
-    address_data.risk = risk;
-    address_data.serialize(&mut *reporter_info.data.borrow_mut())?;
+    address_data.categories = report.categories;
+    address_data.primary_category = report.primary_category;
+    address_data.risk = report.risk;
+    address_data.updated_at = now;
+    address_data.revision += 1;
+    address_data.expires_at = report.expires_at;
+    address_data.stale = false;
+    address_data.serialize(&mut *address_info.data.borrow_mut())?;
+
+    // Record reporter activity
+    reporter_data.addresses_updated += 1;
+    reporter_data.last_activity_at = now;
+    reporter_data.serialize(&mut *reporter_info.data.borrow_mut())?;
 
     Ok(())
 }
@@ -0,0 +1,91 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    state::{
+        address::{get_address_address_seeds, get_address_bump_seed, get_address_data},
+        entity::assert_entity_belongs_to_community,
+        network::assert_network_belongs_to_community,
+        reporter::{assert_reporter_belongs_to_community, assert_reporter_can_create_address},
+    },
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of UpdateAddressEntity instruction
+struct UpdateAddressEntityAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    address_info: &'a AccountInfo<'b>,
+    entity_info: Option<&'a AccountInfo<'b>>,
+}
+
+impl<'a, 'b> UpdateAddressEntityAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        value: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateAddressEntity", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+        let community_info = context.next("Community", AccountConstraints::new().initialized())?;
+        let network_info = context.next("Network", AccountConstraints::new().initialized())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info,
+            network_info,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            address_info: context.next(
+                "Address",
+                AccountConstraints::new().writable().pda(
+                    &get_address_address_seeds(network_info.key, value),
+                    get_address_bump_seed,
+                ),
+            )?,
+            entity_info: context
+                .next_optional("Entity", AccountConstraints::new().initialized())?,
+        })
+    }
+}
+
+pub fn process_update_address_entity(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &Pubkey,
+) -> ProgramResult {
+    let UpdateAddressEntityAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        address_info,
+        entity_info,
+    } = UpdateAddressEntityAccounts::parse(program_id, accounts, value)?;
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
+
+    let mut address_data = get_address_data(address_info)?;
+
+    // If entity account is supplemented, attach address to it, otherwise detach
+    let entity = if let Some(info) = entity_info {
+        assert_entity_belongs_to_community(info, community_info)?;
+        Some(*info.key)
+    } else {
+        None
+    };
+
+    address_data.entity = entity;
+    address_data.serialize(&mut *address_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+        program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    },
+};
+
+use crate::{
+    state::address_set::{
+        assert_is_valid_address_set, get_address_set_address_seeds, get_address_set_data,
+        AddressSet,
+    },
+    state::case::{assert_case_belongs_to_community, get_case_data},
+    state::enums::HapiAccountType,
+    state::reporter::{
+        assert_reporter_belongs_to_community, assert_reporter_can_update_case, get_reporter_data,
+    },
+    tools::{
+        account::create_and_serialize_account_signed,
+        account_context::{assert_account_address, AccountConstraints, AccountContext},
+        merkle::MerkleHash,
+    },
+};
+
+/// Accounts of UpdateAddressSet instruction
+struct UpdateAddressSetAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    address_set_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> UpdateAddressSetAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateAddressSet", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            address_set_info: context.next("Address set", AccountConstraints::new().writable())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
+pub fn process_update_address_set(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    case_id: u64,
+    root: &MerkleHash,
+    size: u64,
+) -> ProgramResult {
+    let UpdateAddressSetAccounts {
+        reporter_key_info,
+        community_info,
+        reporter_info,
+        case_info,
+        address_set_info,
+        system_info,
+        rent,
+        payer_info,
+    } = UpdateAddressSetAccounts::parse(program_id, accounts)?;
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+
+    // Make sure that case ID and account is fine
+    assert_case_belongs_to_community(case_info, community_info, case_id)?;
+
+    let case_data = get_case_data(case_info)?;
+    assert_reporter_can_update_case(reporter_info, &case_data.reporter_key)?;
+
+    let reporter_data = get_reporter_data(reporter_info)?;
+
+    let mut address_set_data = AddressSet {
+        account_type: HapiAccountType::AddressSet,
+        case_id,
+        root: *root,
+        size,
+        reporter_key: reporter_data.identity,
+        updated_at: Clock::get()?.unix_timestamp,
+        bump_seed: 0,
+    };
+
+    if address_set_info.data_is_empty() {
+        create_and_serialize_account_signed::<AddressSet>(
+            payer_info,
+            address_set_info,
+            &mut address_set_data,
+            &get_address_set_address_seeds(case_info.key),
+            program_id,
+            system_info,
+            &rent,
+        )?;
+    } else {
+        assert_is_valid_address_set(address_set_info)?;
+        address_set_data.bump_seed = get_address_set_data(address_set_info)?.bump_seed;
+        assert_account_address(
+            "Address set",
+            address_set_info,
+            &get_address_set_address_seeds(case_info.key),
+            address_set_data.bump_seed,
+            program_id,
+        )?;
+        address_set_data.serialize(&mut *address_set_info.data.borrow_mut())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    instruction::AssetReport,
+    state::asset::{get_asset_address_seeds, get_asset_bump_seed, get_asset_data, TokenId},
+    state::case::{assert_case_belongs_to_community, assert_case_has_category},
+    state::network::assert_network_belongs_to_community,
+    state::reporter::{assert_reporter_belongs_to_community, assert_reporter_can_create_address},
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of UpdateAsset instruction
+struct UpdateAssetAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    asset_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> UpdateAssetAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        asset: &Pubkey,
+        token_id: &Option<TokenId>,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateAsset", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+        let community_info = context.next("Community", AccountConstraints::new().initialized())?;
+        let network_info = context.next("Network", AccountConstraints::new().initialized())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info,
+            network_info,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            asset_info: context.next(
+                "Asset",
+                AccountConstraints::new().writable().pda(
+                    &get_asset_address_seeds(network_info.key, asset, token_id),
+                    get_asset_bump_seed,
+                ),
+            )?,
+        })
+    }
+}
+
+pub fn process_update_asset(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    case_id: u64,
+    report: &AssetReport,
+) -> ProgramResult {
+    let UpdateAssetAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        case_info,
+        asset_info,
+    } = UpdateAssetAccounts::parse(program_id, accounts, &report.asset, &report.token_id)?;
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
+
+    // Make sure that case ID and account is fine
+    assert_case_belongs_to_community(case_info, community_info, case_id)?;
+    assert_case_has_category(case_info, report.category)?;
+
+    // Update asset data
+    let mut asset_data = get_asset_data(asset_info)?;
+    asset_data.case_id = case_id;
+    asset_data.risk = report.risk;
+    asset_data.category = report.category;
+    asset_data.serialize(&mut *asset_info.data.borrow_mut())?;
+
+    Ok(())
+}
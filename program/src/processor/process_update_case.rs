@@ -1,59 +1,95 @@
-use {
-    borsh::BorshSerialize,
-    solana_program::{
-        account_info::{next_account_info, AccountInfo},
-        entrypoint::ProgramResult,
-        msg,
-        pubkey::Pubkey,
-    },
+use solana_program::{
+    account_info::AccountInfo, clock::UnixTimestamp, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent,
 };
 
 use crate::{
     error::HapiError,
     state::{
-        case::{assert_is_valid_case, get_case_data},
+        case::{get_case_address_seeds, get_case_bump_seed, get_case_data},
         enums::{CaseStatus, CategorySet},
-        reporter::{
-            assert_is_valid_reporter, assert_reporter_can_update_case, get_reporter_address,
-        },
+        reporter::{assert_reporter_belongs_to_community, assert_reporter_can_update_case},
     },
+    tools::account::serialize_and_realloc_account,
+    tools::account_context::{AccountConstraints, AccountContext},
+    tools::name::MAX_NAME_LENGTH,
 };
 
+/// Accounts of UpdateCase instruction
+struct UpdateCaseAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> UpdateCaseAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        case_id: u64,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateCase", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+        let community_info = context.next("Community", AccountConstraints::new().initialized())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            case_info: context.next(
+                "Case",
+                AccountConstraints::new().writable().pda(
+                    &get_case_address_seeds(community_info.key, &case_id.to_le_bytes()),
+                    get_case_bump_seed,
+                ),
+            )?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
 pub fn process_update_case(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
+    case_id: u64,
+    name: &str,
     categories: &CategorySet,
     status: CaseStatus,
+    expires_at: Option<UnixTimestamp>,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let reporter_key_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let reporter_info = next_account_info(account_info_iter)?; // 2
-    let case_info = next_account_info(account_info_iter)?; // 3
-
-    // Reporter must sign
-    if !reporter_key_info.is_signer {
-        msg!("Reporter did not sign CreateCase");
-        return Err(HapiError::SignatureMissing.into());
-    }
+    let UpdateCaseAccounts {
+        reporter_key_info,
+        community_info,
+        reporter_info,
+        case_info,
+        system_info,
+        rent,
+        payer_info,
+    } = UpdateCaseAccounts::parse(program_id, accounts, case_id)?;
 
-    // Make sure that reporter's public key matches Reporter account
-    let reporter_address = get_reporter_address(&community_info.key, &reporter_key_info.key);
-    if reporter_address != *reporter_info.key {
-        msg!("Reporter doesn't match Reporter account");
-        return Err(HapiError::InvalidReporter.into());
+    // Case account grows if the name exceeds the space preallocated on creation
+    if name.len() > MAX_NAME_LENGTH {
+        msg!("Case name must not exceed {} bytes", MAX_NAME_LENGTH);
+        return Err(HapiError::NameTooLong.into());
     }
 
-    assert_is_valid_reporter(reporter_info)?;
-    assert_is_valid_case(case_info)?;
-
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
     let mut case_data = get_case_data(&case_info)?;
 
-    assert_reporter_can_update_case(&reporter_key_info, &reporter_info, &case_data.reporter_key)?;
+    assert_reporter_can_update_case(reporter_info, &case_data.reporter_key)?;
 
     case_data.categories = *categories;
     case_data.status = status;
-    case_data.serialize(&mut *case_info.data.borrow_mut())?;
+    case_data.name = name.to_string();
+    case_data.expires_at = expires_at;
+    serialize_and_realloc_account(payer_info, case_info, &case_data, system_info, &rent)?;
 
     Ok(())
 }
@@ -1,9 +1,7 @@
 use {
     borsh::BorshSerialize,
     solana_program::{
-        account_info::{next_account_info, AccountInfo},
-        entrypoint::ProgramResult,
-        msg,
+        account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
         pubkey::Pubkey,
     },
 };
@@ -11,22 +9,47 @@ use {
 use crate::{
     error::HapiError,
     state::community::{assert_is_valid_community, get_community_data},
+    tools::account_context::{AccountConstraints, AccountContext},
 };
 
+/// Accounts of UpdateCommunity instruction
+struct UpdateCommunityAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    new_authority_info: Option<&'a AccountInfo<'b>>,
+}
+
+impl<'a, 'b> UpdateCommunityAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateCommunity", program_id, accounts);
+
+        Ok(Self {
+            authority_info: context.next("Authority", AccountConstraints::new().signer())?,
+            community_info: context.next(
+                "Community",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            new_authority_info: context
+                .next_optional("New authority", AccountConstraints::new())?,
+        })
+    }
+}
+
 pub fn process_update_community(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
+    risk_decay_period: u64,
+    report_quota: u64,
+    report_quota_period: u64,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let authority_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let new_authority_info = next_account_info(account_info_iter); // 2
-
-    // Authority must sign
-    if !authority_info.is_signer {
-        msg!("Authority did not sign initialization");
-        return Err(HapiError::SignatureMissing.into());
-    }
+    let UpdateCommunityAccounts {
+        authority_info,
+        community_info,
+        new_authority_info,
+    } = UpdateCommunityAccounts::parse(program_id, accounts)?;
 
     // Authority must match community
     assert_is_valid_community(community_info)?;
@@ -37,10 +60,14 @@ pub fn process_update_community(
     }
 
     // If new authority account is supplemented, update it
-    if let Ok(info) = new_authority_info {
+    if let Some(info) = new_authority_info {
         community_data.authority = *info.key;
     }
 
+    community_data.risk_decay_period = risk_decay_period;
+    community_data.report_quota = report_quota;
+    community_data.report_quota_period = report_quota_period;
+
     community_data.serialize(&mut *community_info.data.borrow_mut())?;
 
     Ok(())
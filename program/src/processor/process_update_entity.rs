@@ -0,0 +1,100 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    state::{
+        entity::{
+            assert_entity_belongs_to_community, assert_entity_is_not_ancestor, get_entity_data,
+        },
+        enums::Category,
+        reporter::{assert_reporter_belongs_to_community, assert_reporter_can_update_entity},
+    },
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of UpdateEntity instruction
+struct UpdateEntityAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    entity_info: &'a AccountInfo<'b>,
+    parent_info: Option<&'a AccountInfo<'b>>,
+    ancestor_infos: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> UpdateEntityAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateEntity", program_id, accounts);
+
+        Ok(Self {
+            reporter_key_info: context.next("Reporter key", AccountConstraints::new().signer())?,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            entity_info: context
+                .next("Entity", AccountConstraints::new().writable().initialized())?,
+            parent_info: context
+                .next_optional("Parent entity", AccountConstraints::new().initialized())?,
+            ancestor_infos: context
+                .next_remaining("Ancestor entity", AccountConstraints::new().initialized())?,
+        })
+    }
+}
+
+pub fn process_update_entity(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    category: Category,
+    risk: u8,
+    website: &str,
+) -> ProgramResult {
+    let UpdateEntityAccounts {
+        reporter_key_info,
+        community_info,
+        reporter_info,
+        entity_info,
+        parent_info,
+        ancestor_infos,
+    } = UpdateEntityAccounts::parse(program_id, accounts)?;
+
+    if website.len() > 64 {
+        msg!("Entity website must not exceed 64 bytes");
+        return Err(HapiError::WebsiteTooLong.into());
+    }
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_entity_belongs_to_community(entity_info, community_info)?;
+
+    let mut entity_data = get_entity_data(entity_info)?;
+
+    assert_reporter_can_update_entity(reporter_info, &entity_data.reporter_key)?;
+
+    // If parent entity account is supplemented, it must be another entity of the same community
+    // and the entity must not be among its ancestors, otherwise the parent chain becomes a cycle
+    entity_data.parent = if let Some(info) = parent_info {
+        if info.key == entity_info.key {
+            msg!("Entity can't be a parent of itself");
+            return Err(HapiError::InvalidEntity.into());
+        }
+        assert_entity_belongs_to_community(info, community_info)?;
+        assert_entity_is_not_ancestor(entity_info.key, info, ancestor_infos)?;
+        Some(*info.key)
+    } else {
+        None
+    };
+
+    entity_data.category = category;
+    entity_data.risk = risk;
+    entity_data.website = website.to_string();
+    entity_data.serialize(&mut *entity_info.data.borrow_mut())?;
+
+    Ok(())
+}
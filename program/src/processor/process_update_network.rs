@@ -1,7 +1,5 @@
 use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    msg,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
@@ -9,20 +7,46 @@ use crate::{
     error::HapiError,
     state::{
         community::{assert_is_valid_community, get_community_data},
-        network::{assert_is_valid_network, get_network_address, get_network_data},
+        network::{assert_is_valid_network, get_network_address_seeds, get_network_data},
+    },
+    tools::{
+        account::is_program_address,
+        account_context::{AccountConstraints, AccountContext},
+        name::get_name_hash,
     },
 };
-pub fn process_update_network(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let authority_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let network_info = next_account_info(account_info_iter)?; // 2
-
-    // Authority must sign
-    if !authority_info.is_signer {
-        msg!("Authority did not sign initialization");
-        return Err(HapiError::SignatureMissing.into());
+
+/// Accounts of UpdateNetwork instruction
+struct UpdateNetworkAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> UpdateNetworkAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateNetwork", program_id, accounts);
+
+        Ok(Self {
+            authority_info: context.next("Authority", AccountConstraints::new().signer())?,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            network_info: context.next(
+                "Network",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+        })
     }
+}
+
+pub fn process_update_network(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let UpdateNetworkAccounts {
+        authority_info,
+        community_info,
+        network_info,
+    } = UpdateNetworkAccounts::parse(program_id, accounts)?;
 
     // Authority must match community
     assert_is_valid_community(community_info)?;
@@ -34,8 +58,12 @@ pub fn process_update_network(_program_id: &Pubkey, accounts: &[AccountInfo]) ->
 
     assert_is_valid_network(network_info)?;
     let network_data = get_network_data(network_info)?;
-    let network_address = get_network_address(community_info.key, &network_data.name);
-    if *network_info.key != network_address {
+    if !is_program_address(
+        network_info.key,
+        &get_network_address_seeds(community_info.key, &get_name_hash(&network_data.name)),
+        network_data.bump_seed,
+        program_id,
+    ) {
         msg!("Network doesn't match Network account");
         return Err(HapiError::InvalidReporter.into());
     }
@@ -1,43 +1,74 @@
-use {
-    borsh::BorshSerialize,
-    solana_program::{
-        account_info::{next_account_info, AccountInfo},
-        entrypoint::ProgramResult,
-        msg,
-        pubkey::Pubkey,
-    },
+use solana_program::{
+    account_info::AccountInfo, clock::UnixTimestamp, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent,
 };
 
 use crate::{
     error::HapiError,
     state::community::{assert_is_valid_community, get_community_data},
     state::enums::ReporterType,
-    state::reporter::{assert_is_valid_reporter, get_reporter_address, get_reporter_data},
+    state::reporter::{assert_is_valid_reporter, get_reporter_address_seeds, get_reporter_data},
+    tools::account::{is_program_address, serialize_and_realloc_account},
+    tools::account_context::{AccountConstraints, AccountContext},
+    tools::name::MAX_NAME_LENGTH,
 };
 
+/// Accounts of UpdateReporter instruction
+struct UpdateReporterAccounts<'a, 'b> {
+    authority_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    reporter_key_info: &'a AccountInfo<'b>,
+    system_info: &'a AccountInfo<'b>,
+    rent: Rent,
+    payer_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> UpdateReporterAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateReporter", program_id, accounts);
+
+        Ok(Self {
+            authority_info: context.next("Authority", AccountConstraints::new().signer())?,
+            community_info: context.next("Community", AccountConstraints::new().initialized())?,
+            reporter_info: context.next(
+                "Reporter",
+                AccountConstraints::new().writable().initialized(),
+            )?,
+            reporter_key_info: context.next("Reporter key", AccountConstraints::new())?,
+            system_info: context.next_system_program()?,
+            rent: context.next_rent()?,
+            payer_info: context.next_payer(0)?,
+        })
+    }
+}
+
 pub fn process_update_reporter(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     name: &str,
     reporter_type: ReporterType,
+    active_until: Option<UnixTimestamp>,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let authority_info = next_account_info(account_info_iter)?; // 0
-    let community_info = next_account_info(account_info_iter)?; // 1
-    let reporter_info = next_account_info(account_info_iter)?; // 2
-    let reporter_key_info = next_account_info(account_info_iter)?; // 3
+    let UpdateReporterAccounts {
+        authority_info,
+        community_info,
+        reporter_info,
+        reporter_key_info,
+        system_info,
+        rent,
+        payer_info,
+    } = UpdateReporterAccounts::parse(program_id, accounts)?;
 
-    if name.len() > 32 {
-        msg!("Reporter name must not exceed 32 bytes");
+    // Reporter account grows if the name exceeds the space preallocated on creation
+    if name.len() > MAX_NAME_LENGTH {
+        msg!("Reporter name must not exceed {} bytes", MAX_NAME_LENGTH);
         return Err(HapiError::NameTooLong.into());
     }
 
-    // Authority must sign
-    if !authority_info.is_signer {
-        msg!("Authority did not sign initialization");
-        return Err(HapiError::SignatureMissing.into());
-    }
-
     // Authority must match community
     assert_is_valid_community(community_info)?;
     let community_data = get_community_data(community_info)?;
@@ -48,17 +79,28 @@ pub fn process_update_reporter(
 
     // Make sure that this is in fact a correct reporter
     assert_is_valid_reporter(reporter_info)?;
-    let reporter_address = get_reporter_address(community_info.key, reporter_key_info.key);
-    if *reporter_info.key != reporter_address {
+    let mut reporter_data = get_reporter_data(reporter_info)?;
+    if !is_program_address(
+        reporter_info.key,
+        &get_reporter_address_seeds(community_info.key, reporter_key_info.key),
+        reporter_data.bump_seed,
+        program_id,
+    ) {
         msg!("Reporter doesn't match Reporter account");
         return Err(HapiError::InvalidReporter.into());
     }
 
     // Update reporter data
-    let mut reporter_data = get_reporter_data(reporter_info)?;
     reporter_data.name = name.to_string();
     reporter_data.reporter_type = reporter_type;
-    reporter_data.serialize(&mut *reporter_info.data.borrow_mut())?;
+    reporter_data.active_until = active_until;
+    serialize_and_realloc_account(
+        payer_info,
+        reporter_info,
+        &reporter_data,
+        system_info,
+        &rent,
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,93 @@
+use {
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    instruction::TransactionReport,
+    state::case::{assert_case_belongs_to_community, assert_case_has_category},
+    state::network::assert_network_belongs_to_community,
+    state::reporter::{assert_reporter_belongs_to_community, assert_reporter_can_create_address},
+    state::transaction::{
+        get_transaction_address_seeds, get_transaction_bump_seed, get_transaction_data,
+        TransactionHash,
+    },
+    tools::account_context::{AccountConstraints, AccountContext},
+};
+
+/// Accounts of UpdateTransaction instruction
+struct UpdateTransactionAccounts<'a, 'b> {
+    reporter_key_info: &'a AccountInfo<'b>,
+    community_info: &'a AccountInfo<'b>,
+    network_info: &'a AccountInfo<'b>,
+    reporter_info: &'a AccountInfo<'b>,
+    case_info: &'a AccountInfo<'b>,
+    transaction_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> UpdateTransactionAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+        tx_hash: &TransactionHash,
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("UpdateTransaction", program_id, accounts);
+
+        let reporter_key_info = context.next("Reporter key", AccountConstraints::new().signer())?;
+        let community_info = context.next("Community", AccountConstraints::new().initialized())?;
+        let network_info = context.next("Network", AccountConstraints::new().initialized())?;
+
+        Ok(Self {
+            reporter_key_info,
+            community_info,
+            network_info,
+            reporter_info: context.next("Reporter", AccountConstraints::new().initialized())?,
+            case_info: context.next("Case", AccountConstraints::new().initialized())?,
+            transaction_info: context.next(
+                "Transaction",
+                AccountConstraints::new().writable().pda(
+                    &get_transaction_address_seeds(network_info.key, tx_hash),
+                    get_transaction_bump_seed,
+                ),
+            )?,
+        })
+    }
+}
+
+pub fn process_update_transaction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    case_id: u64,
+    report: &TransactionReport,
+) -> ProgramResult {
+    let UpdateTransactionAccounts {
+        reporter_key_info,
+        community_info,
+        network_info,
+        reporter_info,
+        case_info,
+        transaction_info,
+    } = UpdateTransactionAccounts::parse(program_id, accounts, &report.tx_hash)?;
+
+    assert_reporter_belongs_to_community(reporter_info, community_info, reporter_key_info.key)?;
+    assert_reporter_can_create_address(reporter_info)?;
+    assert_network_belongs_to_community(network_info, community_info)?;
+
+    // Make sure that case ID and account is fine
+    assert_case_belongs_to_community(case_info, community_info, case_id)?;
+    assert_case_has_category(case_info, report.category)?;
+
+    // Update transaction data
+    let mut transaction_data = get_transaction_data(transaction_info)?;
+    transaction_data.case_id = case_id;
+    transaction_data.risk = report.risk;
+    transaction_data.category = report.category;
+    transaction_data.source = report.source;
+    transaction_data.destination = report.destination;
+    transaction_data.serialize(&mut *transaction_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    consumer::is_address_in_set,
+    error::HapiError,
+    state::enums::Category,
+    state::network::assert_is_valid_network,
+    tools::account_context::{AccountConstraints, AccountContext},
+    tools::merkle::MerkleHash,
+};
+
+/// Accounts of VerifyAddressInSet instruction
+struct VerifyAddressInSetAccounts<'a, 'b> {
+    network_info: &'a AccountInfo<'b>,
+    address_set_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> VerifyAddressInSetAccounts<'a, 'b> {
+    fn parse(
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Self, ProgramError> {
+        let mut context = AccountContext::new("VerifyAddressInSet", program_id, accounts);
+
+        Ok(Self {
+            network_info: context.next("Network", AccountConstraints::new().initialized())?,
+            address_set_info: context
+                .next("Address set", AccountConstraints::new().initialized())?,
+        })
+    }
+}
+
+pub fn process_verify_address_in_set(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    value: &Pubkey,
+    risk: u8,
+    category: Category,
+    proof: &[MerkleHash],
+) -> ProgramResult {
+    let VerifyAddressInSetAccounts {
+        network_info,
+        address_set_info,
+    } = VerifyAddressInSetAccounts::parse(program_id, accounts)?;
+
+    assert_is_valid_network(network_info)?;
+
+    if !is_address_in_set(
+        address_set_info,
+        network_info.key,
+        value,
+        risk,
+        category,
+        proof,
+    )? {
+        msg!("Address proof doesn't match address set root");
+        return Err(HapiError::AddressNotInSet.into());
+    }
+
+    Ok(())
+}
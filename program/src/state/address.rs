@@ -3,19 +3,29 @@
 use {
     borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
     solana_program::{
-        account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
-        pubkey::Pubkey,
+        account_info::AccountInfo, clock::UnixTimestamp, msg, program_error::ProgramError,
+        program_pack::IsInitialized, pubkey::Pubkey,
     },
 };
 
 use crate::{
+    error::HapiError,
     id,
-    state::enums::{Category, HapiAccountType},
-    tools::account::{assert_is_valid_account, get_account_data, AccountMaxSize},
+    state::enums::{Category, CategorySet, CategorySetBitmask, HapiAccountType},
+    tools::account::{
+        assert_is_valid_account, get_account_data, read_bytes, read_option_pubkey, read_u32,
+        read_u64, read_u8, skip_option, AccountBumpSeed, AccountMaxSize,
+    },
 };
 
+/// Byte offset of the `entity` field in a serialized Address account
+pub const ADDRESS_ENTITY_OFFSET: usize = 14;
+
 /// HAPI Address Account.
 /// Account PDA seeds: ['address', network_name, address]
+/// Note: The layout is not compatible with the single category layout of the first release.
+/// Accounts can grow with realloc, but there is no instruction migrating the old layout, so upgrading
+/// is redeploy-only, with fresh program state, accounts of the old layout fail to deserialize.
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
 pub struct Address {
@@ -28,14 +38,45 @@ pub struct Address {
     /// Case ID
     pub case_id: u64,
 
-    /// Category
-    pub category: Category,
+    /// Categories
+    pub categories: CategorySet,
+
+    /// Entity account of the address owner
+    pub entity: Option<Pubkey>,
+
+    /// Primary category
+    pub primary_category: Option<Category>,
+
+    /// Time of the last risk update
+    pub updated_at: UnixTimestamp,
+
+    /// Time when the report is due for review
+    pub expires_at: Option<UnixTimestamp>,
+
+    /// Report is expired and no longer bears risk
+    pub stale: bool,
+
+    /// Number of revisions recorded by updates
+    pub revision: u64,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
 }
 
 impl AccountMaxSize for Address {
     fn get_max_size(&self) -> Option<usize> {
         Some(
             std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<CategorySet>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<UnixTimestamp>()
                 + std::mem::size_of::<u8>()
                 + std::mem::size_of::<u64>()
                 + std::mem::size_of::<u8>(),
@@ -43,6 +84,171 @@ impl AccountMaxSize for Address {
     }
 }
 
+impl AccountBumpSeed for Address {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl Address {
+    /// Checks if the report is due for review
+    pub fn is_expired(&self, now: UnixTimestamp) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= now)
+    }
+
+    /// Returns risk score halved every `risk_decay_period` seconds since the last update,
+    /// stale and expired reports bear no risk. Zero decay period disables the decay.
+    pub fn effective_risk(&self, now: UnixTimestamp, risk_decay_period: u64) -> u8 {
+        if self.stale || self.is_expired(now) {
+            return 0;
+        }
+
+        decay_risk(self.risk, self.updated_at, now, risk_decay_period)
+    }
+}
+
+/// Returns risk score halved every `risk_decay_period` seconds since the update,
+/// zero decay period disables the decay
+pub fn decay_risk(
+    risk: u8,
+    updated_at: UnixTimestamp,
+    now: UnixTimestamp,
+    risk_decay_period: u64,
+) -> u8 {
+    if risk_decay_period == 0 {
+        return risk;
+    }
+
+    let elapsed = now.saturating_sub(updated_at).max(0) as u64;
+    risk.checked_shr((elapsed / risk_decay_period).min(u32::MAX as u64) as u32)
+        .unwrap_or(0)
+}
+
+/// Zero-copy view of a serialized Address account reading fields in place without allocation.
+/// Offsets past the optional fields are resolved once, the layout is the Borsh layout of Address.
+pub struct AddressRef<'a> {
+    data: &'a [u8],
+    primary_category_offset: usize,
+    expires_at_offset: usize,
+}
+
+impl<'a> AddressRef<'a> {
+    /// Checks the account type and resolves field offsets of the serialized Address
+    pub fn new(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if read_u8(data, 0)? != HapiAccountType::Address as u8 {
+            return Err(HapiError::InvalidAccountType.into());
+        }
+
+        let primary_category_offset =
+            skip_option(data, ADDRESS_ENTITY_OFFSET, std::mem::size_of::<Pubkey>())?;
+        let expires_at_offset =
+            skip_option(data, primary_category_offset, 1)? + std::mem::size_of::<UnixTimestamp>();
+
+        Ok(Self {
+            data,
+            primary_category_offset,
+            expires_at_offset,
+        })
+    }
+
+    /// Risk score
+    pub fn risk(&self) -> Result<u8, ProgramError> {
+        read_u8(self.data, 1)
+    }
+
+    /// Case ID
+    pub fn case_id(&self) -> Result<u64, ProgramError> {
+        read_u64(self.data, 2)
+    }
+
+    /// Categories
+    pub fn categories(&self) -> Result<CategorySet, ProgramError> {
+        read_u32(self.data, 10)
+    }
+
+    /// Entity account of the address owner
+    pub fn entity(&self) -> Result<Option<Pubkey>, ProgramError> {
+        read_option_pubkey(self.data, ADDRESS_ENTITY_OFFSET)
+    }
+
+    /// Primary category
+    pub fn primary_category(&self) -> Result<Option<Category>, ProgramError> {
+        match read_u8(self.data, self.primary_category_offset)? {
+            0 => Ok(None),
+            1 => Ok(Some(Category::try_from_slice(read_bytes(
+                self.data,
+                self.primary_category_offset + 1,
+                1,
+            )?)?)),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Time of the last risk update
+    pub fn updated_at(&self) -> Result<UnixTimestamp, ProgramError> {
+        Ok(read_u64(
+            self.data,
+            self.expires_at_offset - std::mem::size_of::<UnixTimestamp>(),
+        )? as UnixTimestamp)
+    }
+
+    /// Time when the report is due for review
+    pub fn expires_at(&self) -> Result<Option<UnixTimestamp>, ProgramError> {
+        match read_u8(self.data, self.expires_at_offset)? {
+            0 => Ok(None),
+            1 => Ok(Some(
+                read_u64(self.data, self.expires_at_offset + 1)? as UnixTimestamp
+            )),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Report is expired and no longer bears risk
+    pub fn stale(&self) -> Result<bool, ProgramError> {
+        Ok(read_u8(self.data, self.stale_offset()?)? != 0)
+    }
+
+    /// Number of revisions recorded by updates
+    pub fn revision(&self) -> Result<u64, ProgramError> {
+        read_u64(self.data, self.stale_offset()? + 1)
+    }
+
+    /// Bump seed of the account PDA
+    pub fn bump_seed(&self) -> Result<u8, ProgramError> {
+        read_u8(
+            self.data,
+            self.stale_offset()? + 1 + std::mem::size_of::<u64>(),
+        )
+    }
+
+    /// Risk score at the time, see `Address::effective_risk`
+    pub fn effective_risk(
+        &self,
+        now: UnixTimestamp,
+        risk_decay_period: u64,
+    ) -> Result<u8, ProgramError> {
+        let is_expired = matches!(self.expires_at()?, Some(expires_at) if expires_at <= now);
+        if self.stale()? || is_expired {
+            return Ok(0);
+        }
+
+        Ok(decay_risk(
+            self.risk()?,
+            self.updated_at()?,
+            now,
+            risk_decay_period,
+        ))
+    }
+
+    fn stale_offset(&self) -> Result<usize, ProgramError> {
+        skip_option(
+            self.data,
+            self.expires_at_offset,
+            std::mem::size_of::<UnixTimestamp>(),
+        )
+    }
+}
+
 impl IsInitialized for Address {
     fn is_initialized(&self) -> bool {
         self.account_type == HapiAccountType::Address
@@ -54,11 +260,37 @@ pub fn assert_is_valid_address(address_info: &AccountInfo) -> Result<(), Program
     assert_is_valid_account(address_info, HapiAccountType::Address, &id())
 }
 
+/// Checks that address categories are reported in the case and the primary category is one of them
+pub fn assert_address_categories_are_valid(
+    categories: CategorySet,
+    primary_category: Option<Category>,
+    case_categories: CategorySet,
+) -> Result<(), ProgramError> {
+    if !categories.is_subset(case_categories) {
+        msg!("Address categories must be a subset of case categories");
+        return Err(HapiError::CategoryNotInCase.into());
+    }
+
+    if let Some(primary_category) = primary_category {
+        if !categories.contains(primary_category) {
+            msg!("Primary category must be one of address categories");
+            return Err(HapiError::InvalidPrimaryCategory.into());
+        }
+    }
+
+    Ok(())
+}
+
 /// Deserializes account and checks owner program
 pub fn get_address_data(address_info: &AccountInfo) -> Result<Address, ProgramError> {
     get_account_data::<Address>(address_info, &id())
 }
 
+/// Reads the PDA bump seed of the account in place
+pub fn get_address_bump_seed(address_info: &AccountInfo) -> Result<u8, ProgramError> {
+    AddressRef::new(&address_info.data.borrow())?.bump_seed()
+}
+
 /// Returns Address PDA seeds
 pub fn get_address_address_seeds<'a>(network: &'a Pubkey, address: &'a Pubkey) -> [&'a [u8]; 3] {
     [b"address", &network.as_ref(), &address.as_ref()]
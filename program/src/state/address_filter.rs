@@ -0,0 +1,217 @@
+//! HAPI Address Filter Account
+
+use {
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo, hash::hashv, msg, program_error::ProgramError,
+        program_pack::IsInitialized, pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    id,
+    state::enums::HapiAccountType,
+    tools::account::{assert_is_valid_account, get_account_data, AccountBumpSeed, AccountMaxSize},
+};
+
+/// Size of the address filter bit array in bytes
+pub const ADDRESS_FILTER_SIZE: usize = 8192;
+
+/// Number of bits set in the address filter for each address
+pub const ADDRESS_FILTER_HASHES: usize = 4;
+
+/// Offset of the address count in the account data
+const ADDRESS_FILTER_COUNT_OFFSET: usize = 1 + 32;
+
+/// Offset of the bit array in the account data, past its length prefix
+const ADDRESS_FILTER_BITS_OFFSET: usize = ADDRESS_FILTER_COUNT_OFFSET + 8 + 4;
+
+/// Offset of the bump seed in the account data, past the bit array
+const ADDRESS_FILTER_BUMP_SEED_OFFSET: usize = ADDRESS_FILTER_BITS_OFFSET + ADDRESS_FILTER_SIZE;
+
+/// HAPI Address Filter Account: Bloom filter of addresses reported in the network.
+/// An address missing from the filter is definitely not reported, an address found in the filter may be reported.
+/// Account PDA seeds: ['address_filter', network_account]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct AddressFilter {
+    /// HAPI account type
+    pub account_type: HapiAccountType,
+
+    /// Network account
+    pub network: Pubkey,
+
+    /// Number of addresses added since the last reset
+    pub count: u64,
+
+    /// Bloom filter bit array of ADDRESS_FILTER_SIZE bytes
+    pub bits: Vec<u8>,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
+}
+
+impl AddressFilter {
+    /// Returns an empty address filter of the network
+    pub fn new(network: &Pubkey) -> Self {
+        Self {
+            account_type: HapiAccountType::AddressFilter,
+            network: *network,
+            count: 0,
+            bits: vec![0; ADDRESS_FILTER_SIZE],
+            bump_seed: 0,
+        }
+    }
+
+    /// Adds the address to the filter
+    pub fn insert(&mut self, address: &Pubkey) {
+        insert_into_bits(&mut self.bits, address);
+        self.count += 1;
+    }
+
+    /// Checks if the address may be in the filter
+    pub fn contains(&self, address: &Pubkey) -> bool {
+        bits_contain(&self.bits, address)
+    }
+}
+
+impl AccountMaxSize for AddressFilter {
+    fn get_max_size(&self) -> Option<usize> {
+        Some(ADDRESS_FILTER_BUMP_SEED_OFFSET + std::mem::size_of::<u8>())
+    }
+}
+
+impl AccountBumpSeed for AddressFilter {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl IsInitialized for AddressFilter {
+    fn is_initialized(&self) -> bool {
+        self.account_type == HapiAccountType::AddressFilter
+    }
+}
+
+/// Returns indexes of the filter bits of the address
+pub fn get_address_filter_bit_indexes(address: &Pubkey) -> [usize; ADDRESS_FILTER_HASHES] {
+    let hash = hashv(&[address.as_ref()]).to_bytes();
+    let mut indexes = [0; ADDRESS_FILTER_HASHES];
+    for (index, chunk) in indexes.iter_mut().zip(hash.chunks(4)) {
+        let value = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        *index = value as usize % (ADDRESS_FILTER_SIZE * 8);
+    }
+    indexes
+}
+
+fn insert_into_bits(bits: &mut [u8], address: &Pubkey) {
+    for index in get_address_filter_bit_indexes(address).iter() {
+        bits[index / 8] |= 1 << (index % 8);
+    }
+}
+
+fn bits_contain(bits: &[u8], address: &Pubkey) -> bool {
+    get_address_filter_bit_indexes(address)
+        .iter()
+        .all(|index| bits[index / 8] & (1 << (index % 8)) != 0)
+}
+
+/// Checks whether address filter account exists, is initialized and owned by HAPI program
+pub fn assert_is_valid_address_filter(
+    address_filter_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    assert_is_valid_account(address_filter_info, HapiAccountType::AddressFilter, &id())
+}
+
+/// Checks whether address filter account is valid and belongs to the network, without deserializing the filter
+pub fn assert_address_filter_belongs_to_network(
+    address_filter_info: &AccountInfo,
+    network: &Pubkey,
+) -> Result<(), ProgramError> {
+    assert_is_valid_address_filter(address_filter_info)?;
+
+    if address_filter_info.data_len() != ADDRESS_FILTER_BUMP_SEED_OFFSET + 1
+        || address_filter_info.data.borrow()[1..ADDRESS_FILTER_COUNT_OFFSET] != network.as_ref()[..]
+    {
+        msg!("Address filter doesn't match Network account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Deserializes account and checks owner program
+pub fn get_address_filter_data(
+    address_filter_info: &AccountInfo,
+) -> Result<AddressFilter, ProgramError> {
+    get_account_data::<AddressFilter>(address_filter_info, &id())
+}
+
+/// Adds the address to the address filter account of the network in place.
+/// Networks without an address filter are skipped, the empty account must still be the filter PDA.
+pub fn add_to_address_filter(
+    address_filter_info: &AccountInfo,
+    network: &Pubkey,
+    address: &Pubkey,
+) -> Result<(), ProgramError> {
+    if address_filter_info.data_is_empty() {
+        if *address_filter_info.key != get_address_filter_address(network) {
+            msg!("Address filter doesn't match Network account");
+            return Err(ProgramError::InvalidSeeds);
+        }
+        return Ok(());
+    }
+
+    assert_address_filter_belongs_to_network(address_filter_info, network)?;
+
+    let mut data = address_filter_info.data.borrow_mut();
+
+    insert_into_bits(
+        &mut data[ADDRESS_FILTER_BITS_OFFSET..ADDRESS_FILTER_BUMP_SEED_OFFSET],
+        address,
+    );
+
+    let count_data = &mut data[ADDRESS_FILTER_COUNT_OFFSET..ADDRESS_FILTER_COUNT_OFFSET + 8];
+    let mut count = [0; 8];
+    count.copy_from_slice(count_data);
+    count_data.copy_from_slice(&(u64::from_le_bytes(count) + 1).to_le_bytes());
+
+    Ok(())
+}
+
+/// Clears the address filter account of the network in place, keeping its bump seed
+pub fn clear_address_filter(
+    address_filter_info: &AccountInfo,
+    network: &Pubkey,
+) -> Result<(), ProgramError> {
+    assert_address_filter_belongs_to_network(address_filter_info, network)?;
+
+    let mut data = address_filter_info.data.borrow_mut();
+    for byte in data[ADDRESS_FILTER_COUNT_OFFSET..ADDRESS_FILTER_COUNT_OFFSET + 8].iter_mut() {
+        *byte = 0;
+    }
+    for byte in data[ADDRESS_FILTER_BITS_OFFSET..ADDRESS_FILTER_BUMP_SEED_OFFSET].iter_mut() {
+        *byte = 0;
+    }
+
+    Ok(())
+}
+
+/// Checks if the address may be in the address filter account in place
+pub fn address_filter_contains(address_filter_info: &AccountInfo, address: &Pubkey) -> bool {
+    bits_contain(
+        &address_filter_info.data.borrow()
+            [ADDRESS_FILTER_BITS_OFFSET..ADDRESS_FILTER_BUMP_SEED_OFFSET],
+        address,
+    )
+}
+
+/// Returns AddressFilter PDA seeds
+pub fn get_address_filter_address_seeds(network: &Pubkey) -> [&[u8]; 2] {
+    [b"address_filter", network.as_ref()]
+}
+
+/// Returns AddressFilter PDA address
+pub fn get_address_filter_address(network: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_address_filter_address_seeds(network), &id()).0
+}
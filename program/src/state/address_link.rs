@@ -0,0 +1,124 @@
+//! HAPI Address Link Account
+
+use {
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    id,
+    state::enums::{AddressLinkKind, HapiAccountType},
+    tools::account::{assert_is_valid_account, get_account_data, AccountBumpSeed, AccountMaxSize},
+};
+
+/// Byte offset of the `network` field in a serialized AddressLink account
+pub const ADDRESS_LINK_NETWORK_OFFSET: usize = 1;
+
+/// Byte offset of the `source` field in a serialized AddressLink account
+pub const ADDRESS_LINK_SOURCE_OFFSET: usize = 33;
+
+/// Byte offset of the `destination` field in a serialized AddressLink account
+pub const ADDRESS_LINK_DESTINATION_OFFSET: usize = 65;
+
+/// Maximum confidence of an address link
+pub const MAX_LINK_CONFIDENCE: u8 = 100;
+
+/// HAPI Address Link Account: a directed relation between two addresses of a network
+/// Account PDA seeds: ['address_link', network_account, source, destination, kind]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct AddressLink {
+    /// HAPI account type
+    pub account_type: HapiAccountType,
+
+    /// Network account
+    pub network: Pubkey,
+
+    /// Source address
+    pub source: Pubkey,
+
+    /// Destination address
+    pub destination: Pubkey,
+
+    /// Relation kind
+    pub kind: AddressLinkKind,
+
+    /// Confidence of the relation from 0 to 100
+    pub confidence: u8,
+
+    /// Link reporter key
+    pub reporter_key: Pubkey,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
+}
+
+impl AccountMaxSize for AddressLink {
+    fn get_max_size(&self) -> Option<usize> {
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for AddressLink {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl IsInitialized for AddressLink {
+    fn is_initialized(&self) -> bool {
+        self.account_type == HapiAccountType::AddressLink
+    }
+}
+
+/// Checks whether address link account exists, is initialized and owned by HAPI program
+pub fn assert_is_valid_address_link(address_link_info: &AccountInfo) -> Result<(), ProgramError> {
+    assert_is_valid_account(address_link_info, HapiAccountType::AddressLink, &id())
+}
+
+/// Deserializes account and checks owner program
+pub fn get_address_link_data(address_link_info: &AccountInfo) -> Result<AddressLink, ProgramError> {
+    get_account_data::<AddressLink>(address_link_info, &id())
+}
+
+/// Returns AddressLink PDA seeds
+pub fn get_address_link_address_seeds<'a>(
+    network: &'a Pubkey,
+    source: &'a Pubkey,
+    destination: &'a Pubkey,
+    kind: &'a [u8; 1],
+) -> [&'a [u8]; 5] {
+    [
+        b"address_link",
+        network.as_ref(),
+        source.as_ref(),
+        destination.as_ref(),
+        kind,
+    ]
+}
+
+/// Returns AddressLink PDA address
+pub fn get_address_link_address(
+    network: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    kind: AddressLinkKind,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_address_link_address_seeds(network, source, destination, &[kind as u8]),
+        &id(),
+    )
+    .0
+}
@@ -0,0 +1,116 @@
+//! HAPI Address Revision Account
+
+use {
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo, clock::UnixTimestamp, program_error::ProgramError,
+        program_pack::IsInitialized, pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    id,
+    state::enums::{Category, CategorySet, HapiAccountType},
+    tools::account::{assert_is_valid_account, get_account_data, AccountBumpSeed, AccountMaxSize},
+};
+
+/// HAPI Address Revision Account: immutable record of address values replaced by an update
+/// Account PDA seeds: ['address_revision', address_account, revision]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct AddressRevision {
+    /// HAPI account type
+    pub account_type: HapiAccountType,
+
+    /// Address account
+    pub address: Pubkey,
+
+    /// Revision sequence number, starting from zero
+    pub revision: u64,
+
+    /// Previous risk score
+    pub risk: u8,
+
+    /// Previous case ID
+    pub case_id: u64,
+
+    /// Previous categories
+    pub categories: CategorySet,
+
+    /// Previous primary category
+    pub primary_category: Option<Category>,
+
+    /// Key of the reporter who made the update
+    pub updater_key: Pubkey,
+
+    /// Time of the update
+    pub updated_at: UnixTimestamp,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
+}
+
+impl AccountMaxSize for AddressRevision {
+    fn get_max_size(&self) -> Option<usize> {
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<CategorySet>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for AddressRevision {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl IsInitialized for AddressRevision {
+    fn is_initialized(&self) -> bool {
+        self.account_type == HapiAccountType::AddressRevision
+    }
+}
+
+/// Checks whether address revision account exists, is initialized and owned by HAPI program
+pub fn assert_is_valid_address_revision(
+    address_revision_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    assert_is_valid_account(
+        address_revision_info,
+        HapiAccountType::AddressRevision,
+        &id(),
+    )
+}
+
+/// Deserializes account and checks owner program
+pub fn get_address_revision_data(
+    address_revision_info: &AccountInfo,
+) -> Result<AddressRevision, ProgramError> {
+    get_account_data::<AddressRevision>(address_revision_info, &id())
+}
+
+/// Returns AddressRevision PDA seeds
+pub fn get_address_revision_address_seeds<'a>(
+    address: &'a Pubkey,
+    revision: &'a [u8; 8],
+) -> [&'a [u8]; 3] {
+    [b"address_revision", address.as_ref(), revision]
+}
+
+/// Returns AddressRevision PDA address
+pub fn get_address_revision_address(address: &Pubkey, revision: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_address_revision_address_seeds(address, &revision.to_le_bytes()),
+        &id(),
+    )
+    .0
+}
@@ -0,0 +1,109 @@
+//! HAPI Address Set Account
+
+use {
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo, clock::UnixTimestamp, program_error::ProgramError,
+        program_pack::IsInitialized, pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    id,
+    state::enums::{Category, HapiAccountType},
+    tools::{
+        account::{assert_is_valid_account, get_account_data, AccountBumpSeed, AccountMaxSize},
+        merkle::{hash_merkle_leaf, MerkleHash},
+    },
+};
+
+/// Maximum depth of the address set tree, limits proof size
+pub const MAX_ADDRESS_SET_DEPTH: usize = 24;
+
+/// HAPI Address Set Account: Merkle root over addresses reported by a case in bulk
+/// Account PDA seeds: ['address_set', case_account]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct AddressSet {
+    /// HAPI account type
+    pub account_type: HapiAccountType,
+
+    /// Case ID
+    pub case_id: u64,
+
+    /// Merkle root over address set leaves
+    pub root: MerkleHash,
+
+    /// Number of addresses in the set
+    pub size: u64,
+
+    /// Key of the reporter who made the last update
+    pub reporter_key: Pubkey,
+
+    /// Time of the last update
+    pub updated_at: UnixTimestamp,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
+}
+
+impl AccountMaxSize for AddressSet {
+    fn get_max_size(&self) -> Option<usize> {
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<MerkleHash>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for AddressSet {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl IsInitialized for AddressSet {
+    fn is_initialized(&self) -> bool {
+        self.account_type == HapiAccountType::AddressSet
+    }
+}
+
+/// Checks whether address set account exists, is initialized and owned by HAPI program
+pub fn assert_is_valid_address_set(address_set_info: &AccountInfo) -> Result<(), ProgramError> {
+    assert_is_valid_account(address_set_info, HapiAccountType::AddressSet, &id())
+}
+
+/// Deserializes account and checks owner program
+pub fn get_address_set_data(address_set_info: &AccountInfo) -> Result<AddressSet, ProgramError> {
+    get_account_data::<AddressSet>(address_set_info, &id())
+}
+
+/// Returns AddressSet PDA seeds
+pub fn get_address_set_address_seeds(case: &Pubkey) -> [&[u8]; 2] {
+    [b"address_set", case.as_ref()]
+}
+
+/// Returns AddressSet PDA address
+pub fn get_address_set_address(case: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_address_set_address_seeds(case), &id()).0
+}
+
+/// Returns the address set leaf of an address reported in the network
+pub fn get_address_set_leaf(
+    network: &Pubkey,
+    address: &Pubkey,
+    risk: u8,
+    category: Category,
+) -> MerkleHash {
+    hash_merkle_leaf(&[
+        network.as_ref(),
+        address.as_ref(),
+        &[risk],
+        &(category as u32).to_le_bytes(),
+    ])
+}
@@ -0,0 +1,141 @@
+//! HAPI Asset Account
+
+use {
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    id,
+    state::enums::{Category, HapiAccountType},
+    tools::account::{
+        assert_is_valid_account, get_account_data, read_bytes, read_u64, read_u8, AccountBumpSeed,
+        AccountMaxSize,
+    },
+};
+
+/// Token ID of an asset within a collection or a multi-token contract
+pub type TokenId = [u8; 32];
+
+/// HAPI Asset Account: a token mint, an NFT collection item or a contract
+/// Account PDA seeds: ['asset', network_account, asset, token_id]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Asset {
+    /// HAPI account type
+    pub account_type: HapiAccountType,
+
+    /// Risk score
+    pub risk: u8,
+
+    /// Case ID
+    pub case_id: u64,
+
+    /// Category
+    pub category: Category,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
+}
+
+impl AccountMaxSize for Asset {
+    fn get_max_size(&self) -> Option<usize> {
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for Asset {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+/// Zero-copy view of a serialized Asset account reading fields in place without allocation
+pub struct AssetRef<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> AssetRef<'a> {
+    /// Checks the account type of the serialized Asset
+    pub fn new(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if read_u8(data, 0)? != HapiAccountType::Asset as u8 {
+            return Err(HapiError::InvalidAccountType.into());
+        }
+
+        Ok(Self { data })
+    }
+
+    /// Risk score
+    pub fn risk(&self) -> Result<u8, ProgramError> {
+        read_u8(self.data, 1)
+    }
+
+    /// Case ID
+    pub fn case_id(&self) -> Result<u64, ProgramError> {
+        read_u64(self.data, 2)
+    }
+
+    /// Category
+    pub fn category(&self) -> Result<Category, ProgramError> {
+        Ok(Category::try_from_slice(read_bytes(self.data, 10, 1)?)?)
+    }
+
+    /// Bump seed of the account PDA
+    pub fn bump_seed(&self) -> Result<u8, ProgramError> {
+        read_u8(self.data, 11)
+    }
+}
+
+impl IsInitialized for Asset {
+    fn is_initialized(&self) -> bool {
+        self.account_type == HapiAccountType::Asset
+    }
+}
+
+/// Checks whether asset account exists, is initialized and owned by HAPI program
+pub fn assert_is_valid_asset(asset_info: &AccountInfo) -> Result<(), ProgramError> {
+    assert_is_valid_account(asset_info, HapiAccountType::Asset, &id())
+}
+
+/// Deserializes account and checks owner program
+pub fn get_asset_data(asset_info: &AccountInfo) -> Result<Asset, ProgramError> {
+    get_account_data::<Asset>(asset_info, &id())
+}
+
+/// Reads the PDA bump seed of the account in place
+pub fn get_asset_bump_seed(asset_info: &AccountInfo) -> Result<u8, ProgramError> {
+    AssetRef::new(&asset_info.data.borrow())?.bump_seed()
+}
+
+/// Returns Asset PDA seeds, token ID seed is empty for assets without token ID
+pub fn get_asset_address_seeds<'a>(
+    network: &'a Pubkey,
+    asset: &'a Pubkey,
+    token_id: &'a Option<TokenId>,
+) -> [&'a [u8]; 4] {
+    [
+        b"asset",
+        network.as_ref(),
+        asset.as_ref(),
+        token_id.as_ref().map_or(&[], |token_id| token_id.as_ref()),
+    ]
+}
+
+/// Returns Asset PDA address
+pub fn get_asset_address<'a>(
+    network: &'a Pubkey,
+    asset: &'a Pubkey,
+    token_id: &'a Option<TokenId>,
+) -> Pubkey {
+    Pubkey::find_program_address(&get_asset_address_seeds(network, asset, token_id), &id()).0
+}
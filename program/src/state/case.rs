@@ -3,15 +3,19 @@
 use {
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
-        account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
-        pubkey::Pubkey,
+        account_info::AccountInfo, clock::UnixTimestamp, msg, program_error::ProgramError,
+        program_pack::IsInitialized, pubkey::Pubkey,
     },
 };
 
 use crate::{
+    error::HapiError,
     id,
-    state::enums::{CategorySet, HapiAccountType},
-    tools::account::{assert_is_valid_account, get_account_data, AccountMaxSize},
+    state::enums::{Category, CategorySet, CategorySetBitmask, HapiAccountType},
+    tools::account::{
+        assert_is_valid_account, get_account_data, is_program_address, AccountBumpSeed,
+        AccountMaxSize,
+    },
 };
 
 use super::enums::CaseStatus;
@@ -35,6 +39,12 @@ pub struct Case {
 
     /// Case name
     pub name: String,
+
+    /// Time when the case is due for review
+    pub expires_at: Option<UnixTimestamp>,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
 }
 
 impl AccountMaxSize for Case {
@@ -45,11 +55,27 @@ impl AccountMaxSize for Case {
                 + std::mem::size_of::<Pubkey>()
                 + std::mem::size_of::<u32>()
                 + std::mem::size_of::<u8>()
-                + 32,
+                + 32
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<u8>(),
         )
     }
 }
 
+impl AccountBumpSeed for Case {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl Case {
+    /// Checks if the case is due for review
+    pub fn is_expired(&self, now: UnixTimestamp) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= now)
+    }
+}
+
 impl IsInitialized for Case {
     fn is_initialized(&self) -> bool {
         self.account_type == HapiAccountType::Case
@@ -61,11 +87,52 @@ pub fn assert_is_valid_case(case_info: &AccountInfo) -> Result<(), ProgramError>
     assert_is_valid_account(case_info, HapiAccountType::Case, &id())
 }
 
+/// Checks whether case account is a valid case of the community with the case ID
+pub fn assert_case_belongs_to_community(
+    case_info: &AccountInfo,
+    community_info: &AccountInfo,
+    case_id: u64,
+) -> Result<(), ProgramError> {
+    assert_is_valid_case(case_info)?;
+
+    let case_data = get_case_data(case_info)?;
+    if !is_program_address(
+        case_info.key,
+        &get_case_address_seeds(community_info.key, &case_id.to_le_bytes()),
+        case_data.bump_seed,
+        &id(),
+    ) {
+        msg!("Invalid case ID");
+        return Err(HapiError::CaseIDMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the case is reported with the category
+pub fn assert_case_has_category(
+    case_info: &AccountInfo,
+    category: Category,
+) -> Result<(), ProgramError> {
+    let case_data = get_case_data(case_info)?;
+    if !case_data.categories.contains(category) {
+        msg!("Category must be reported in the case");
+        return Err(HapiError::CategoryNotInCase.into());
+    }
+
+    Ok(())
+}
+
 /// Deserializes account and checks owner program
 pub fn get_case_data(case_info: &AccountInfo) -> Result<Case, ProgramError> {
     get_account_data::<Case>(case_info, &id())
 }
 
+/// Returns the PDA bump seed of the account
+pub fn get_case_bump_seed(case_info: &AccountInfo) -> Result<u8, ProgramError> {
+    Ok(get_case_data(case_info)?.bump_seed)
+}
+
 /// Returns Case PDA seeds
 pub fn get_case_address_seeds<'a>(
     community_address: &'a Pubkey,
@@ -3,19 +3,22 @@
 use {
     borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
     solana_program::{
-        account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
-        pubkey::Pubkey,
+        account_info::AccountInfo, clock::UnixTimestamp, program_error::ProgramError,
+        program_pack::IsInitialized, pubkey::Pubkey,
     },
 };
 
 use crate::{
     id,
     state::enums::HapiAccountType,
-    tools::account::{assert_is_valid_account, get_account_data, AccountMaxSize},
+    tools::{
+        account::{assert_is_valid_account, get_account_data, AccountBumpSeed, AccountMaxSize},
+        name::{get_name_hash, NameHash, MAX_NAME_LENGTH},
+    },
 };
 
 /// HAPI Community Account
-/// Account PDA seeds: ['community', name]
+/// Account PDA seeds: ['community', sha256(normalized_name)]
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
 pub struct Community {
@@ -28,8 +31,20 @@ pub struct Community {
     /// ID for the next reported case
     pub next_case_id: u64,
 
-    /// HAPI community name
+    /// HAPI community display name
     pub name: String,
+
+    /// Period in seconds for the address risk to decay by half, zero disables the decay
+    pub risk_decay_period: u64,
+
+    /// Maximum number of reports per reporter within a quota period, zero disables the quota
+    pub report_quota: u64,
+
+    /// Quota period in seconds, zero disables the quota
+    pub report_quota_period: u64,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
 }
 
 impl AccountMaxSize for Community {
@@ -38,11 +53,35 @@ impl AccountMaxSize for Community {
             std::mem::size_of::<u8>()
                 + std::mem::size_of::<Pubkey>()
                 + std::mem::size_of::<u64>()
-                + 32,
+                + std::mem::size_of::<u32>()
+                + MAX_NAME_LENGTH
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u8>(),
         )
     }
 }
 
+impl AccountBumpSeed for Community {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl Community {
+    /// Returns start time of the quota period that contains given time,
+    /// None if reports are not limited
+    pub fn quota_period_start(&self, now: UnixTimestamp) -> Option<UnixTimestamp> {
+        if self.report_quota == 0 || self.report_quota_period == 0 {
+            return None;
+        }
+
+        let period = self.report_quota_period.min(i64::MAX as u64) as i64;
+        Some(now - now.rem_euclid(period))
+    }
+}
+
 impl IsInitialized for Community {
     fn is_initialized(&self) -> bool {
         self.account_type == HapiAccountType::Community
@@ -60,11 +99,11 @@ pub fn get_community_data(community_info: &AccountInfo) -> Result<Community, Pro
 }
 
 /// Returns Community PDA seeds
-pub fn get_community_address_seeds(name: &str) -> [&[u8]; 2] {
-    [b"community", &name.as_bytes()]
+pub fn get_community_address_seeds(name_hash: &NameHash) -> [&[u8]; 2] {
+    [b"community", name_hash]
 }
 
 /// Returns Community PDA address
 pub fn get_community_address(name: &str) -> Pubkey {
-    Pubkey::find_program_address(&get_community_address_seeds(&name), &id()).0
+    Pubkey::find_program_address(&get_community_address_seeds(&get_name_hash(name)), &id()).0
 }
@@ -0,0 +1,174 @@
+//! HAPI Entity Account
+
+use {
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo, msg, program_error::ProgramError, program_pack::IsInitialized,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    id,
+    state::enums::{Category, HapiAccountType},
+    tools::{
+        account::{
+            assert_is_valid_account, get_account_data, is_program_address, AccountBumpSeed,
+            AccountMaxSize,
+        },
+        name::{get_name_hash, NameHash},
+    },
+};
+
+/// Maximum number of parent entity ancestors walked by UpdateEntity, limits instruction accounts
+pub const MAX_ENTITY_ANCESTORS: usize = 8;
+
+/// HAPI Entity Account: an owner of addresses, i.e. an exchange or a service
+/// Account PDA seeds: ['entity', community_address, sha256(normalized_name)]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Entity {
+    /// HAPI account type
+    pub account_type: HapiAccountType,
+
+    /// Entity reporter key
+    pub reporter_key: Pubkey,
+
+    /// Category
+    pub category: Category,
+
+    /// Risk score
+    pub risk: u8,
+
+    /// Parent entity account
+    pub parent: Option<Pubkey>,
+
+    /// Entity name
+    pub name: String,
+
+    /// Entity website
+    pub website: String,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
+}
+
+impl AccountMaxSize for Entity {
+    fn get_max_size(&self) -> Option<usize> {
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u32>()
+                + 32
+                + std::mem::size_of::<u32>()
+                + 64
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for Entity {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+impl IsInitialized for Entity {
+    fn is_initialized(&self) -> bool {
+        self.account_type == HapiAccountType::Entity
+    }
+}
+
+/// Checks whether entity account exists, is initialized and owned by HAPI program
+pub fn assert_is_valid_entity(entity_info: &AccountInfo) -> Result<(), ProgramError> {
+    assert_is_valid_account(entity_info, HapiAccountType::Entity, &id())
+}
+
+/// Checks whether entity account is a valid entity of the community
+pub fn assert_entity_belongs_to_community(
+    entity_info: &AccountInfo,
+    community_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    assert_is_valid_entity(entity_info)?;
+
+    let entity_data = get_entity_data(entity_info)?;
+    if !is_program_address(
+        entity_info.key,
+        &get_entity_address_seeds(community_info.key, &get_name_hash(&entity_data.name)),
+        entity_data.bump_seed,
+        &id(),
+    ) {
+        msg!("Entity doesn't match Entity account");
+        return Err(HapiError::InvalidEntity.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the entity isn't an ancestor of the parent entity by walking the parent chain
+/// through the ancestor accounts, nearest first
+pub fn assert_entity_is_not_ancestor(
+    entity_key: &Pubkey,
+    parent_info: &AccountInfo,
+    ancestor_infos: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let mut ancestor_key = get_entity_data(parent_info)?.parent;
+    let mut depth = 0;
+
+    while let Some(key) = ancestor_key {
+        if key == *entity_key {
+            msg!("Entity can't be an ancestor of itself");
+            return Err(HapiError::EntityParentCycle.into());
+        }
+
+        if depth == MAX_ENTITY_ANCESTORS {
+            msg!(
+                "Parent entity must not have more than {} ancestors",
+                MAX_ENTITY_ANCESTORS
+            );
+            return Err(HapiError::EntityParentChainTooLong.into());
+        }
+
+        let ancestor_info = ancestor_infos.get(depth).ok_or_else(|| {
+            msg!("Ancestor entity account #{} is missing", depth);
+            ProgramError::NotEnoughAccountKeys
+        })?;
+
+        if *ancestor_info.key != key {
+            msg!("Ancestor entity doesn't match the parent of the previous entity");
+            return Err(HapiError::InvalidEntity.into());
+        }
+
+        ancestor_key = get_entity_data(ancestor_info)?.parent;
+        depth += 1;
+    }
+
+    Ok(())
+}
+
+/// Deserializes account and checks owner program
+pub fn get_entity_data(entity_info: &AccountInfo) -> Result<Entity, ProgramError> {
+    get_account_data::<Entity>(entity_info, &id())
+}
+
+/// Returns Entity PDA seeds
+pub fn get_entity_address_seeds<'a>(
+    community_address: &'a Pubkey,
+    entity_name_hash: &'a NameHash,
+) -> [&'a [u8]; 3] {
+    [b"entity", community_address.as_ref(), entity_name_hash]
+}
+
+/// Returns Entity PDA address
+pub fn get_entity_address(community_address: &Pubkey, name: &str) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_entity_address_seeds(community_address, &get_name_hash(name)),
+        &id(),
+    )
+    .0
+}
@@ -27,6 +27,27 @@ pub enum HapiAccountType {
 
     /// Address account
     Address,
+
+    /// Entity account
+    Entity,
+
+    /// Transaction account
+    Transaction,
+
+    /// Asset account
+    Asset,
+
+    /// Address link account
+    AddressLink,
+
+    /// Address revision account
+    AddressRevision,
+
+    /// Address set account
+    AddressSet,
+
+    /// Address filter account
+    AddressFilter,
 }
 
 impl Default for HapiAccountType {
@@ -146,12 +167,19 @@ pub type CategorySet = u32;
 pub trait CategorySetBitmask {
     /// Checks if category set contains the category
     fn contains(self, category: Category) -> bool;
+
+    /// Checks if all categories of the set are contained in the other set
+    fn is_subset(&self, other: CategorySet) -> bool;
 }
 
 impl CategorySetBitmask for CategorySet {
     fn contains(self, category: Category) -> bool {
         self & category as u32 != 0
     }
+
+    fn is_subset(&self, other: CategorySet) -> bool {
+        *self & !other == 0
+    }
 }
 
 impl Default for Category {
@@ -235,3 +263,34 @@ impl Default for CaseStatus {
         CaseStatus::Open
     }
 }
+
+/// Relation between the source and the destination addresses of a link
+#[repr(C)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Ord,
+    PartialOrd,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+)]
+pub enum AddressLinkKind {
+    /// Source address was funded by the destination address
+    FundedBy,
+
+    /// Source and destination addresses have the same owner
+    SameOwner,
+
+    /// Source address is a deposit address of the destination address
+    DepositAddress,
+}
+
+impl Default for AddressLinkKind {
+    fn default() -> Self {
+        AddressLinkKind::FundedBy
+    }
+}
@@ -1,8 +1,15 @@
 //! Program accounts
 
 pub mod address;
+pub mod address_filter;
+pub mod address_link;
+pub mod address_revision;
+pub mod address_set;
+pub mod asset;
 pub mod case;
 pub mod community;
+pub mod entity;
 pub mod enums;
 pub mod network;
 pub mod reporter;
+pub mod transaction;
@@ -3,32 +3,53 @@
 use {
     borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
     solana_program::{
-        account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
+        account_info::AccountInfo, msg, program_error::ProgramError, program_pack::IsInitialized,
         pubkey::Pubkey,
     },
 };
 
 use crate::{
+    error::HapiError,
     id,
     state::enums::HapiAccountType,
-    tools::account::{assert_is_valid_account, get_account_data, AccountMaxSize},
+    tools::{
+        account::{
+            assert_is_valid_account, get_account_data, is_program_address, AccountBumpSeed,
+            AccountMaxSize,
+        },
+        name::{get_name_hash, NameHash, MAX_NAME_LENGTH},
+    },
 };
 
 /// HAPI Network Account
-/// Account PDA seeds: ['network', community_name, network_name]
+/// Account PDA seeds: ['network', community_account, sha256(normalized_network_name)]
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
 pub struct Network {
     /// HAPI account type
     pub account_type: HapiAccountType,
 
-    /// HAPI network name
+    /// HAPI network display name
     pub name: String,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
 }
 
 impl AccountMaxSize for Network {
     fn get_max_size(&self) -> Option<usize> {
-        Some(std::mem::size_of::<u8>() + 32)
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<u32>()
+                + MAX_NAME_LENGTH
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for Network {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
     }
 }
 
@@ -43,6 +64,27 @@ pub fn assert_is_valid_network(network_info: &AccountInfo) -> Result<(), Program
     assert_is_valid_account(network_info, HapiAccountType::Network, &id())
 }
 
+/// Checks whether network account is a valid network of the community
+pub fn assert_network_belongs_to_community(
+    network_info: &AccountInfo,
+    community_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    assert_is_valid_network(network_info)?;
+
+    let network_data = get_network_data(network_info)?;
+    if !is_program_address(
+        network_info.key,
+        &get_network_address_seeds(community_info.key, &get_name_hash(&network_data.name)),
+        network_data.bump_seed,
+        &id(),
+    ) {
+        msg!("Network doesn't match Network account");
+        return Err(HapiError::InvalidNetwork.into());
+    }
+
+    Ok(())
+}
+
 /// Deserializes account and checks owner program
 pub fn get_network_data(network_info: &AccountInfo) -> Result<Network, ProgramError> {
     get_account_data::<Network>(network_info, &id())
@@ -51,19 +93,15 @@ pub fn get_network_data(network_info: &AccountInfo) -> Result<Network, ProgramEr
 /// Returns Network PDA seeds
 pub fn get_network_address_seeds<'a>(
     community_address: &'a Pubkey,
-    network_name: &'a str,
+    network_name_hash: &'a NameHash,
 ) -> [&'a [u8]; 3] {
-    [
-        b"network",
-        &community_address.as_ref(),
-        &network_name.as_bytes(),
-    ]
+    [b"network", community_address.as_ref(), network_name_hash]
 }
 
 /// Returns Network PDA address
 pub fn get_network_address<'a>(community_address: &'a Pubkey, network_name: &'a str) -> Pubkey {
     Pubkey::find_program_address(
-        &get_network_address_seeds(community_address, network_name),
+        &get_network_address_seeds(community_address, &get_name_hash(network_name)),
         &id(),
     )
     .0
@@ -3,16 +3,27 @@
 use {
     borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
     solana_program::{
-        account_info::AccountInfo, msg, program_error::ProgramError, program_pack::IsInitialized,
+        account_info::AccountInfo,
+        clock::{Clock, UnixTimestamp},
+        msg,
+        program_error::ProgramError,
+        program_pack::IsInitialized,
         pubkey::Pubkey,
+        sysvar::Sysvar,
     },
 };
 
 use crate::{
     error::HapiError,
     id,
-    state::enums::{HapiAccountType, ReporterType},
-    tools::account::{assert_is_valid_account, get_account_data, AccountMaxSize},
+    state::{
+        community::Community,
+        enums::{HapiAccountType, ReporterType},
+    },
+    tools::account::{
+        assert_is_valid_account, get_account_data, is_program_address, AccountBumpSeed,
+        AccountMaxSize,
+    },
 };
 
 /// HAPI Reporter Account
@@ -28,11 +39,146 @@ pub struct Reporter {
 
     /// Reporter name
     pub name: String,
+
+    /// Number of reported cases
+    pub cases_created: u64,
+
+    /// Number of reported addresses
+    pub addresses_created: u64,
+
+    /// Number of address updates
+    pub addresses_updated: u64,
+
+    /// Number of disputes lost by the reporter
+    pub disputes_lost: u64,
+
+    /// Time of the last report
+    pub last_activity_at: UnixTimestamp,
+
+    /// Start time of the quota period of the last report
+    pub quota_period_start: UnixTimestamp,
+
+    /// Number of reports within the quota period
+    pub quota_period_reports: u64,
+
+    /// Key the reporter was created with, recorded as the reporter of its cases and entities
+    pub identity: Pubkey,
+
+    /// Current reporter key
+    pub key: Pubkey,
+
+    /// Additional key allowed to sign reports on behalf of the reporter
+    pub delegate: Option<Pubkey>,
+
+    /// Time when the delegate key expires
+    pub delegate_valid_until: UnixTimestamp,
+
+    /// Time when the reporter term ends, None if the term is not limited
+    pub active_until: Option<UnixTimestamp>,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
 }
 
 impl AccountMaxSize for Reporter {
     fn get_max_size(&self) -> Option<usize> {
-        Some(std::mem::size_of::<u8>() + std::mem::size_of::<u8>() + 32)
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u32>()
+                + 32
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<UnixTimestamp>()
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for Reporter {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+/// Number of reports a single lost dispute outweighs in the reputation score
+pub const DISPUTE_PENALTY: u64 = 10;
+
+impl Reporter {
+    /// Returns total number of reports
+    pub fn reports(&self) -> u64 {
+        self.cases_created
+            .saturating_add(self.addresses_created)
+            .saturating_add(self.addresses_updated)
+    }
+
+    /// Returns reputation score from 0 to 100: the share of reports among reports
+    /// and lost disputes, each lost dispute weighing as `DISPUTE_PENALTY` reports.
+    /// Reporters without reports have zero reputation.
+    pub fn reputation(&self) -> u8 {
+        let reports = self.reports() as u128;
+        if reports == 0 {
+            return 0;
+        }
+
+        let penalty = self.disputes_lost as u128 * DISPUTE_PENALTY as u128;
+        (reports * 100 / (reports + penalty)) as u8
+    }
+
+    /// Returns whether the reporter term is not over at the given time
+    pub fn is_active(&self, now: UnixTimestamp) -> bool {
+        self.active_until
+            .map_or(true, |active_until| now < active_until)
+    }
+
+    /// Returns number of reports left in the current quota period of the community,
+    /// None if reports are not limited
+    pub fn remaining_quota(&self, community: &Community, now: UnixTimestamp) -> Option<u64> {
+        let period_start = community.quota_period_start(now)?;
+
+        let reports = if self.quota_period_start == period_start {
+            self.quota_period_reports
+        } else {
+            0
+        };
+
+        Some(community.report_quota.saturating_sub(reports))
+    }
+
+    /// Counts a new report against the quota of the community
+    pub fn consume_quota(
+        &mut self,
+        community: &Community,
+        now: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        let period_start = match community.quota_period_start(now) {
+            Some(period_start) => period_start,
+            None => return Ok(()),
+        };
+
+        if self.remaining_quota(community, now) == Some(0) {
+            msg!("Reporter has exhausted its report quota");
+            return Err(HapiError::ReportQuotaExceeded.into());
+        }
+
+        if self.quota_period_start != period_start {
+            self.quota_period_start = period_start;
+            self.quota_period_reports = 0;
+        }
+        self.quota_period_reports += 1;
+
+        Ok(())
     }
 }
 
@@ -47,7 +193,8 @@ pub fn assert_is_valid_reporter(reporter_info: &AccountInfo) -> Result<(), Progr
     assert_is_valid_account(reporter_info, HapiAccountType::Reporter, &id())
 }
 
-/// Checks reporter against community and pubkey
+/// Checks reporter against community and pubkey, which is either the reporter key
+/// or an unexpired delegate key of the reporter
 pub fn assert_reporter_belongs_to_community(
     reporter_info: &AccountInfo,
     community_info: &AccountInfo,
@@ -55,10 +202,41 @@ pub fn assert_reporter_belongs_to_community(
 ) -> Result<(), ProgramError> {
     assert_is_valid_reporter(reporter_info)?;
 
-    let reporter_address = get_reporter_address(&community_info.key, &reporter_pubkey);
-    if reporter_address != *reporter_info.key {
-        msg!("Reporter doesn't match Reporter account");
-        return Err(HapiError::InvalidReporter.into());
+    let reporter_data = get_reporter_data(reporter_info)?;
+    if is_program_address(
+        reporter_info.key,
+        &get_reporter_address_seeds(community_info.key, reporter_pubkey),
+        reporter_data.bump_seed,
+        &id(),
+    ) {
+        return Ok(());
+    }
+
+    // Signer may be a delegate of the reporter
+    if reporter_data.delegate == Some(*reporter_pubkey)
+        && is_program_address(
+            reporter_info.key,
+            &get_reporter_address_seeds(community_info.key, &reporter_data.key),
+            reporter_data.bump_seed,
+            &id(),
+        )
+    {
+        if Clock::get()?.unix_timestamp >= reporter_data.delegate_valid_until {
+            msg!("Reporter delegate key has expired");
+            return Err(HapiError::DelegateExpired.into());
+        }
+        return Ok(());
+    }
+
+    msg!("Reporter doesn't match Reporter account");
+    Err(HapiError::InvalidReporter.into())
+}
+
+/// Checks that the reporter term is not over
+pub fn assert_reporter_is_active(reporter_data: &Reporter) -> Result<(), ProgramError> {
+    if !reporter_data.is_active(Clock::get()?.unix_timestamp) {
+        msg!("Reporter term has expired");
+        return Err(HapiError::ReporterTermExpired.into());
     }
 
     Ok(())
@@ -67,6 +245,7 @@ pub fn assert_reporter_belongs_to_community(
 /// Checks reporter's ability to report an address
 pub fn assert_reporter_can_create_address(reporter_info: &AccountInfo) -> Result<(), ProgramError> {
     let reporter_data = get_reporter_data(&reporter_info)?;
+    assert_reporter_is_active(&reporter_data)?;
     if reporter_data.reporter_type == ReporterType::Inactive {
         msg!("Reporter doesn't have a permission to report an address in this network");
         return Err(HapiError::ReportingNotPermitted.into());
@@ -77,16 +256,16 @@ pub fn assert_reporter_can_create_address(reporter_info: &AccountInfo) -> Result
 
 /// Checks reporter's ability to update the case
 pub fn assert_reporter_can_update_case(
-    reporter_key_info: &AccountInfo,
     reporter_info: &AccountInfo,
     case_reporter: &Pubkey,
 ) -> Result<(), ProgramError> {
     let reporter_data = get_reporter_data(&reporter_info)?;
+    assert_reporter_is_active(&reporter_data)?;
 
     match reporter_data.reporter_type {
         ReporterType::Authority => Ok(()),
         ReporterType::Full => {
-            if case_reporter != reporter_key_info.key {
+            if *case_reporter != reporter_data.identity {
                 msg!("Reporter doesn't have a permission to update this case");
                 return Err(HapiError::InvalidReporterPermissions.into());
             }
@@ -102,6 +281,7 @@ pub fn assert_reporter_can_update_case(
 /// Checks reporter's ability to report cases
 pub fn assert_reporter_can_create_case(reporter_info: &AccountInfo) -> Result<(), ProgramError> {
     let reporter_data = get_reporter_data(&reporter_info)?;
+    assert_reporter_is_active(&reporter_data)?;
 
     match reporter_data.reporter_type {
         ReporterType::Authority | ReporterType::Full => Ok(()),
@@ -112,6 +292,44 @@ pub fn assert_reporter_can_create_case(reporter_info: &AccountInfo) -> Result<()
     }
 }
 
+/// Checks reporter's ability to create entities
+pub fn assert_reporter_can_create_entity(reporter_info: &AccountInfo) -> Result<(), ProgramError> {
+    let reporter_data = get_reporter_data(reporter_info)?;
+    assert_reporter_is_active(&reporter_data)?;
+
+    match reporter_data.reporter_type {
+        ReporterType::Authority | ReporterType::Full => Ok(()),
+        _ => {
+            msg!("Reporter doesn't have a permission to create an entity");
+            Err(HapiError::InvalidReporterPermissions.into())
+        }
+    }
+}
+
+/// Checks reporter's ability to update the entity
+pub fn assert_reporter_can_update_entity(
+    reporter_info: &AccountInfo,
+    entity_reporter: &Pubkey,
+) -> Result<(), ProgramError> {
+    let reporter_data = get_reporter_data(reporter_info)?;
+    assert_reporter_is_active(&reporter_data)?;
+
+    match reporter_data.reporter_type {
+        ReporterType::Authority => Ok(()),
+        ReporterType::Full => {
+            if *entity_reporter != reporter_data.identity {
+                msg!("Reporter doesn't have a permission to update this entity");
+                return Err(HapiError::InvalidReporterPermissions.into());
+            }
+            Ok(())
+        }
+        _ => {
+            msg!("Reporter doesn't have a permission to update this entity");
+            Err(HapiError::InvalidReporterPermissions.into())
+        }
+    }
+}
+
 /// Deserializes account and checks owner program
 pub fn get_reporter_data(reporter_info: &AccountInfo) -> Result<Reporter, ProgramError> {
     get_account_data::<Reporter>(reporter_info, &id())
@@ -0,0 +1,177 @@
+//! HAPI Transaction Account
+
+use {
+    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
+        pubkey::Pubkey,
+    },
+};
+
+use crate::{
+    error::HapiError,
+    id,
+    state::enums::{Category, HapiAccountType},
+    tools::account::{
+        assert_is_valid_account, get_account_data, read_bytes, read_option_pubkey, read_u64,
+        read_u8, skip_option, AccountBumpSeed, AccountMaxSize,
+    },
+};
+
+/// Transaction hash, shorter hashes are padded with zeros
+pub type TransactionHash = [u8; 64];
+
+/// HAPI Transaction Account
+/// Account PDA seeds: ['transaction', network_account, tx_hash[..32], tx_hash[32..]]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Transaction {
+    /// HAPI account type
+    pub account_type: HapiAccountType,
+
+    /// Risk score
+    pub risk: u8,
+
+    /// Case ID
+    pub case_id: u64,
+
+    /// Category
+    pub category: Category,
+
+    /// Source address of the transaction
+    pub source: Option<Pubkey>,
+
+    /// Destination address of the transaction
+    pub destination: Option<Pubkey>,
+
+    /// Bump seed of the account PDA
+    pub bump_seed: u8,
+}
+
+impl AccountMaxSize for Transaction {
+    fn get_max_size(&self) -> Option<usize> {
+        Some(
+            std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u64>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u8>()
+                + std::mem::size_of::<Pubkey>()
+                + std::mem::size_of::<u8>(),
+        )
+    }
+}
+
+impl AccountBumpSeed for Transaction {
+    fn set_bump_seed(&mut self, bump_seed: u8) {
+        self.bump_seed = bump_seed;
+    }
+}
+
+/// Offset of the `source` field in a serialized Transaction account
+const TRANSACTION_SOURCE_OFFSET: usize = 11;
+
+/// Zero-copy view of a serialized Transaction account reading fields in place without allocation.
+/// Offsets past the optional fields are resolved once, the layout is the Borsh layout of Transaction.
+pub struct TransactionRef<'a> {
+    data: &'a [u8],
+    destination_offset: usize,
+}
+
+impl<'a> TransactionRef<'a> {
+    /// Checks the account type and resolves field offsets of the serialized Transaction
+    pub fn new(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if read_u8(data, 0)? != HapiAccountType::Transaction as u8 {
+            return Err(HapiError::InvalidAccountType.into());
+        }
+
+        let destination_offset = skip_option(
+            data,
+            TRANSACTION_SOURCE_OFFSET,
+            std::mem::size_of::<Pubkey>(),
+        )?;
+
+        Ok(Self {
+            data,
+            destination_offset,
+        })
+    }
+
+    /// Risk score
+    pub fn risk(&self) -> Result<u8, ProgramError> {
+        read_u8(self.data, 1)
+    }
+
+    /// Case ID
+    pub fn case_id(&self) -> Result<u64, ProgramError> {
+        read_u64(self.data, 2)
+    }
+
+    /// Category
+    pub fn category(&self) -> Result<Category, ProgramError> {
+        Ok(Category::try_from_slice(read_bytes(self.data, 10, 1)?)?)
+    }
+
+    /// Source address of the transaction
+    pub fn source(&self) -> Result<Option<Pubkey>, ProgramError> {
+        read_option_pubkey(self.data, TRANSACTION_SOURCE_OFFSET)
+    }
+
+    /// Destination address of the transaction
+    pub fn destination(&self) -> Result<Option<Pubkey>, ProgramError> {
+        read_option_pubkey(self.data, self.destination_offset)
+    }
+
+    /// Bump seed of the account PDA
+    pub fn bump_seed(&self) -> Result<u8, ProgramError> {
+        read_u8(
+            self.data,
+            skip_option(
+                self.data,
+                self.destination_offset,
+                std::mem::size_of::<Pubkey>(),
+            )?,
+        )
+    }
+}
+
+impl IsInitialized for Transaction {
+    fn is_initialized(&self) -> bool {
+        self.account_type == HapiAccountType::Transaction
+    }
+}
+
+/// Checks whether transaction account exists, is initialized and owned by HAPI program
+pub fn assert_is_valid_transaction(transaction_info: &AccountInfo) -> Result<(), ProgramError> {
+    assert_is_valid_account(transaction_info, HapiAccountType::Transaction, &id())
+}
+
+/// Deserializes account and checks owner program
+pub fn get_transaction_data(transaction_info: &AccountInfo) -> Result<Transaction, ProgramError> {
+    get_account_data::<Transaction>(transaction_info, &id())
+}
+
+/// Reads the PDA bump seed of the account in place
+pub fn get_transaction_bump_seed(transaction_info: &AccountInfo) -> Result<u8, ProgramError> {
+    TransactionRef::new(&transaction_info.data.borrow())?.bump_seed()
+}
+
+/// Returns Transaction PDA seeds
+pub fn get_transaction_address_seeds<'a>(
+    network: &'a Pubkey,
+    tx_hash: &'a TransactionHash,
+) -> [&'a [u8]; 4] {
+    [
+        b"transaction",
+        network.as_ref(),
+        &tx_hash[..32],
+        &tx_hash[32..],
+    ]
+}
+
+/// Returns Transaction PDA address
+pub fn get_transaction_address<'a>(network: &'a Pubkey, tx_hash: &'a TransactionHash) -> Pubkey {
+    Pubkey::find_program_address(&get_transaction_address_seeds(network, tx_hash), &id()).0
+}
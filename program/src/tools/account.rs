@@ -3,15 +3,18 @@
 use {
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
-        account_info::AccountInfo, borsh::try_from_slice_unchecked, msg, program::invoke_signed,
-        program_error::ProgramError, program_pack::IsInitialized, pubkey::Pubkey, rent::Rent,
-        system_instruction::create_account,
+        account_info::AccountInfo, borsh::try_from_slice_unchecked, msg, program::invoke,
+        program::invoke_signed, program_error::ProgramError, program_pack::IsInitialized,
+        pubkey::Pubkey, rent::Rent, system_instruction::create_account,
+        system_instruction::transfer,
     },
 };
 
 use crate::error::HapiError;
 
 /// Trait for accounts to return their max size
+/// Note: Max size is only preallocated on creation, fields can grow beyond it later
+/// with `serialize_and_realloc_account`
 pub trait AccountMaxSize {
     /// Returns max account size or None if max size is not known and actual instance size should be used
     fn get_max_size(&self) -> Option<usize> {
@@ -19,12 +22,22 @@ pub trait AccountMaxSize {
     }
 }
 
+/// Trait for PDA accounts to store the bump seed of their address
+pub trait AccountBumpSeed {
+    /// Sets the bump seed found when the account is created
+    fn set_bump_seed(&mut self, bump_seed: u8);
+}
+
 /// Creates a new account and serializes data into it using the provided seeds to invoke signed CPI call
 /// Note: This functions also checks the provided account PDA matches the supplied seeds
-pub fn create_and_serialize_account_signed<'a, T: BorshSerialize + AccountMaxSize>(
+/// and stores the found bump seed in the account data
+pub fn create_and_serialize_account_signed<
+    'a,
+    T: BorshSerialize + AccountMaxSize + AccountBumpSeed,
+>(
     payer_info: &AccountInfo<'a>,
     account_info: &AccountInfo<'a>,
-    account_data: &T,
+    account_data: &mut T,
     account_address_seeds: &[&[u8]],
     program_id: &Pubkey,
     system_info: &AccountInfo<'a>,
@@ -43,6 +56,8 @@ pub fn create_and_serialize_account_signed<'a, T: BorshSerialize + AccountMaxSiz
         return Err(ProgramError::InvalidSeeds);
     }
 
+    account_data.set_bump_seed(bump_seed);
+
     let (serialized_data, account_size) = if let Some(max_size) = account_data.get_max_size() {
         (None, max_size)
     } else {
@@ -85,6 +100,56 @@ pub fn create_and_serialize_account_signed<'a, T: BorshSerialize + AccountMaxSiz
     Ok(())
 }
 
+/// Serializes data into an existing account and grows the account if the data doesn't fit,
+/// the payer tops up the rent exemption of the extra space. Accounts never shrink
+pub fn serialize_and_realloc_account<'a, T: BorshSerialize>(
+    payer_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    account_data: &T,
+    system_info: &AccountInfo<'a>,
+    rent: &Rent,
+) -> Result<(), ProgramError> {
+    let serialized_data = account_data.try_to_vec()?;
+
+    if serialized_data.len() > account_info.data_len() {
+        let rent_top_up = rent
+            .minimum_balance(serialized_data.len())
+            .saturating_sub(account_info.lamports());
+
+        if rent_top_up > 0 {
+            invoke(
+                &transfer(payer_info.key, account_info.key, rent_top_up),
+                &[
+                    payer_info.clone(),
+                    account_info.clone(),
+                    system_info.clone(),
+                ],
+            )?;
+        }
+
+        account_info.realloc(serialized_data.len(), false)?;
+    }
+
+    account_info.data.borrow_mut()[..serialized_data.len()].copy_from_slice(&serialized_data);
+
+    Ok(())
+}
+
+/// Checks whether the address is the PDA of the seeds with the stored bump seed,
+/// which is much cheaper on-chain than finding the PDA
+pub fn is_program_address(
+    address: &Pubkey,
+    seeds: &[&[u8]],
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> bool {
+    let bump = [bump_seed];
+    let mut seeds_with_bump = seeds.to_vec();
+    seeds_with_bump.push(&bump);
+
+    Pubkey::create_program_address(&seeds_with_bump, program_id) == Ok(*address)
+}
+
 /// Deserializes account and checks it's initialized and owned by the specified program
 pub fn get_account_data<T: BorshDeserialize + IsInitialized>(
     account_info: &AccountInfo,
@@ -128,6 +193,56 @@ pub fn assert_is_valid_account<T: BorshDeserialize + PartialEq>(
     Ok(())
 }
 
+/// Returns the data slice of the given length at the offset of the account data
+pub fn read_bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8], ProgramError> {
+    data.get(offset..offset + len)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Reads u8 at the offset of the account data
+pub fn read_u8(data: &[u8], offset: usize) -> Result<u8, ProgramError> {
+    data.get(offset)
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Reads little-endian u32 at the offset of the account data
+pub fn read_u32(data: &[u8], offset: usize) -> Result<u32, ProgramError> {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(read_bytes(data, offset, 4)?);
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Reads little-endian u64 at the offset of the account data
+pub fn read_u64(data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(read_bytes(data, offset, 8)?);
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Reads Pubkey at the offset of the account data
+pub fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::new(read_bytes(data, offset, 32)?))
+}
+
+/// Reads Borsh serialized Option<Pubkey> at the offset of the account data
+pub fn read_option_pubkey(data: &[u8], offset: usize) -> Result<Option<Pubkey>, ProgramError> {
+    match read_u8(data, offset)? {
+        0 => Ok(None),
+        1 => Ok(Some(read_pubkey(data, offset + 1)?)),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Returns the offset past Borsh serialized Option with the value of the given size at the offset of the account data
+pub fn skip_option(data: &[u8], offset: usize, value_size: usize) -> Result<usize, ProgramError> {
+    match read_u8(data, offset)? {
+        0 => Ok(offset + 1),
+        1 => Ok(offset + 1 + value_size),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
 /// Asserts the given account is empty
 pub fn assert_is_empty_account(account_info: &AccountInfo) -> Result<(), ProgramError> {
     if !account_info.data_is_empty() {
@@ -0,0 +1,321 @@
+//! Declarative validation of instruction accounts
+
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    system_program, sysvar, sysvar::Sysvar,
+};
+
+use crate::{error::HapiError, tools::account::is_program_address};
+
+/// Reads the PDA bump seed stored in the account data
+pub type BumpSeedReader = fn(&AccountInfo) -> Result<u8, ProgramError>;
+
+/// Constraints an instruction account must satisfy
+#[derive(Clone, Copy, Default)]
+pub struct AccountConstraints<'s> {
+    is_signer: bool,
+    is_writable: bool,
+    is_initialized: bool,
+    address: Option<Pubkey>,
+    is_program: bool,
+    pda: Option<(&'s [&'s [u8]], BumpSeedReader)>,
+}
+
+impl<'s> AccountConstraints<'s> {
+    /// Returns constraints any account satisfies
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Account must sign the transaction
+    pub fn signer(mut self) -> Self {
+        self.is_signer = true;
+        self
+    }
+
+    /// Account must be writable
+    pub fn writable(mut self) -> Self {
+        self.is_writable = true;
+        self
+    }
+
+    /// Account must hold data owned by the program
+    pub fn initialized(mut self) -> Self {
+        self.is_initialized = true;
+        self
+    }
+
+    /// Account must have the address
+    pub fn address(mut self, address: &Pubkey) -> Self {
+        self.address = Some(*address);
+        self.is_program = false;
+        self
+    }
+
+    /// Account must be the program with the id
+    pub fn program(mut self, program_id: &Pubkey) -> Self {
+        self.address = Some(*program_id);
+        self.is_program = true;
+        self
+    }
+
+    /// Account must be the PDA of the seeds with the bump seed stored in its data,
+    /// implies that the account is initialized
+    pub fn pda(mut self, seeds: &'s [&'s [u8]], bump_seed: BumpSeedReader) -> Self {
+        self.is_initialized = true;
+        self.pda = Some((seeds, bump_seed));
+        self
+    }
+}
+
+/// Parses instruction accounts in order, checking each of them against its constraints.
+/// Errors are logged with the instruction, the account name and the account index.
+pub struct AccountContext<'a, 'b> {
+    instruction: &'static str,
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+    index: usize,
+}
+
+impl<'a, 'b> AccountContext<'a, 'b> {
+    /// Creates context for the accounts of the instruction
+    pub fn new(
+        instruction: &'static str,
+        program_id: &'a Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Self {
+        Self {
+            instruction,
+            program_id,
+            accounts,
+            index: 0,
+        }
+    }
+
+    /// Returns the next account checked against the constraints
+    pub fn next(
+        &mut self,
+        name: &str,
+        constraints: AccountConstraints<'_>,
+    ) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        match self.next_optional(name, constraints)? {
+            Some(account_info) => Ok(account_info),
+            None => {
+                msg!(
+                    "{}: {} account #{} is missing",
+                    self.instruction,
+                    name,
+                    self.index
+                );
+                Err(ProgramError::NotEnoughAccountKeys)
+            }
+        }
+    }
+
+    /// Returns the next account checked against the constraints or None if there are no more accounts
+    pub fn next_optional(
+        &mut self,
+        name: &str,
+        constraints: AccountConstraints<'_>,
+    ) -> Result<Option<&'a AccountInfo<'b>>, ProgramError> {
+        let account_info = match self.accounts.get(self.index) {
+            Some(account_info) => account_info,
+            None => return Ok(None),
+        };
+
+        self.check(name, self.index, account_info, &constraints)?;
+        self.index += 1;
+
+        Ok(Some(account_info))
+    }
+
+    /// Returns the given number of next accounts, each checked against the constraints
+    pub fn next_many(
+        &mut self,
+        name: &str,
+        count: usize,
+        constraints: AccountConstraints<'_>,
+    ) -> Result<&'a [AccountInfo<'b>], ProgramError> {
+        let account_infos = match self.accounts.get(self.index..self.index + count) {
+            Some(account_infos) => account_infos,
+            None => {
+                msg!(
+                    "{}: {} accounts from #{} are missing",
+                    self.instruction,
+                    name,
+                    self.index
+                );
+                return Err(ProgramError::NotEnoughAccountKeys);
+            }
+        };
+
+        for (offset, account_info) in account_infos.iter().enumerate() {
+            self.check(name, self.index + offset, account_info, &constraints)?;
+        }
+        self.index += count;
+
+        Ok(account_infos)
+    }
+
+    /// Returns all remaining accounts, each checked against the constraints
+    pub fn next_remaining(
+        &mut self,
+        name: &str,
+        constraints: AccountConstraints<'_>,
+    ) -> Result<&'a [AccountInfo<'b>], ProgramError> {
+        let count = self.accounts.len().saturating_sub(self.index);
+        self.next_many(name, count, constraints)
+    }
+
+    /// Returns the next account as the payer of created accounts if it signs the transaction,
+    /// otherwise the default payer, which is the signer of the instruction at the given index.
+    /// PDAs can't sign, so the payer can't be confused with optional program accounts that follow it
+    pub fn next_payer(
+        &mut self,
+        default_payer_index: usize,
+    ) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        let constraints = AccountConstraints::new().signer().writable();
+
+        if let Some(payer_info) = self
+            .accounts
+            .get(self.index)
+            .filter(|account_info| account_info.is_signer)
+        {
+            self.check("Payer", self.index, payer_info, &constraints)?;
+            self.index += 1;
+            return Ok(payer_info);
+        }
+
+        let default_payer_info = self
+            .accounts
+            .get(default_payer_index)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        self.check(
+            "Payer",
+            default_payer_index,
+            default_payer_info,
+            &constraints,
+        )?;
+
+        Ok(default_payer_info)
+    }
+
+    /// Returns the next account checked to be the System program
+    pub fn next_system_program(&mut self) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        self.next(
+            "System",
+            AccountConstraints::new().program(&system_program::id()),
+        )
+    }
+
+    /// Returns Rent read from the next account checked to be the Rent sysvar
+    pub fn next_rent(&mut self) -> Result<Rent, ProgramError> {
+        let rent_sysvar_info = self.next(
+            "Sysvar Rent",
+            AccountConstraints::new().address(&sysvar::rent::id()),
+        )?;
+
+        Rent::from_account_info(rent_sysvar_info)
+    }
+
+    fn check(
+        &self,
+        name: &str,
+        index: usize,
+        account_info: &AccountInfo,
+        constraints: &AccountConstraints<'_>,
+    ) -> Result<(), ProgramError> {
+        if constraints.is_signer && !account_info.is_signer {
+            msg!(
+                "{}: {} account #{} must sign",
+                self.instruction,
+                name,
+                index
+            );
+            return Err(HapiError::SignatureMissing.into());
+        }
+
+        if constraints.is_writable && !account_info.is_writable {
+            msg!(
+                "{}: {} account #{} must be writable",
+                self.instruction,
+                name,
+                index
+            );
+            return Err(HapiError::AccountNotWritable.into());
+        }
+
+        if constraints.is_initialized {
+            if account_info.data_is_empty() {
+                msg!(
+                    "{}: {} account #{} is not initialized",
+                    self.instruction,
+                    name,
+                    index
+                );
+                return Err(ProgramError::UninitializedAccount);
+            }
+
+            if account_info.owner != self.program_id {
+                msg!(
+                    "{}: {} account #{} is not owned by the program",
+                    self.instruction,
+                    name,
+                    index
+                );
+                return Err(HapiError::InvalidAccountOwner.into());
+            }
+        }
+
+        if let Some(address) = constraints.address {
+            if *account_info.key != address {
+                msg!(
+                    "{}: {} account #{} has unexpected address",
+                    self.instruction,
+                    name,
+                    index
+                );
+                return Err(if constraints.is_program {
+                    ProgramError::IncorrectProgramId
+                } else {
+                    HapiError::InvalidAccountAddress.into()
+                });
+            }
+        }
+
+        if let Some((seeds, bump_seed)) = constraints.pda {
+            if !is_program_address(
+                account_info.key,
+                seeds,
+                bump_seed(account_info)?,
+                self.program_id,
+            ) {
+                msg!(
+                    "{}: {} account #{} doesn't match its PDA seeds",
+                    self.instruction,
+                    name,
+                    index
+                );
+                return Err(ProgramError::InvalidSeeds);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the account is the PDA of the seeds with the bump seed stored in the account data
+pub fn assert_account_address(
+    name: &str,
+    account_info: &AccountInfo,
+    seeds: &[&[u8]],
+    bump_seed: u8,
+    program_id: &Pubkey,
+) -> Result<(), ProgramError> {
+    if !is_program_address(account_info.key, seeds, bump_seed, program_id) {
+        msg!("{} account doesn't match its PDA seeds", name);
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Merkle tree of hashed leaves with sorted pair hashing
+
+use solana_program::hash::hashv;
+
+/// Merkle tree node hash
+pub type MerkleHash = [u8; 32];
+
+/// Domain prefix of leaf hashes, keeps leaves and inner nodes apart
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Domain prefix of inner node hashes
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hashes leaf values into a tree leaf
+pub fn hash_merkle_leaf(values: &[&[u8]]) -> MerkleHash {
+    let mut data = vec![LEAF_PREFIX];
+    data.extend_from_slice(values);
+    hashv(&data).to_bytes()
+}
+
+/// Hashes a pair of nodes, the pair is sorted so proofs don't need node positions
+pub fn hash_merkle_nodes(left: &MerkleHash, right: &MerkleHash) -> MerkleHash {
+    if left <= right {
+        hashv(&[NODE_PREFIX, left, right]).to_bytes()
+    } else {
+        hashv(&[NODE_PREFIX, right, left]).to_bytes()
+    }
+}
+
+/// Hashes the next level of the tree, the odd node is carried up unchanged
+fn get_merkle_level(nodes: &[MerkleHash]) -> Vec<MerkleHash> {
+    nodes
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_merkle_nodes(left, right),
+            [node] => *node,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Returns the root of the tree, the root of an empty tree is zeroed
+pub fn get_merkle_root(leaves: &[MerkleHash]) -> MerkleHash {
+    if leaves.is_empty() {
+        return [0; 32];
+    }
+
+    let mut nodes = leaves.to_vec();
+    while nodes.len() > 1 {
+        nodes = get_merkle_level(&nodes);
+    }
+
+    nodes[0]
+}
+
+/// Returns the proof of the leaf at the index or None if the index is out of the tree
+pub fn get_merkle_proof(leaves: &[MerkleHash], index: usize) -> Option<Vec<MerkleHash>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut proof = vec![];
+    let mut nodes = leaves.to_vec();
+    let mut index = index;
+    while nodes.len() > 1 {
+        if let Some(sibling) = nodes.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        nodes = get_merkle_level(&nodes);
+        index /= 2;
+    }
+
+    Some(proof)
+}
+
+/// Checks whether the proof leads from the leaf to the root
+pub fn verify_merkle_proof(leaf: &MerkleHash, proof: &[MerkleHash], root: &MerkleHash) -> bool {
+    let node = proof
+        .iter()
+        .fold(*leaf, |node, sibling| hash_merkle_nodes(&node, sibling));
+
+    node == *root
+}
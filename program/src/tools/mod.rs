@@ -1,6 +1,9 @@
 //! Utility functions
 
 pub mod account;
+pub mod account_context;
+pub mod merkle;
+pub mod name;
 
 use thiserror::Error;
 
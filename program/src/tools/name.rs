@@ -0,0 +1,41 @@
+//! Community and network name utility functions
+
+use {solana_program::hash::hashv, thiserror::Error};
+
+/// Maximum length of community and network names in bytes
+pub const MAX_NAME_LENGTH: usize = 64;
+
+/// Hash of a normalized name used as a PDA seed
+pub type NameHash = [u8; 32];
+
+/// Error for names that can't be used for a community or a network
+#[derive(Error, Debug)]
+pub enum NameError {
+    #[error(
+        "Name must be 1 to {} printable characters other than '/'",
+        MAX_NAME_LENGTH
+    )]
+    /// Name is empty, too long or has characters that are not allowed
+    InvalidName,
+}
+
+/// Checks that the name is not empty, fits MAX_NAME_LENGTH and consists of printable ASCII characters
+/// other than '/', which separates community and network names in network paths
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_NAME_LENGTH
+        && name
+            .bytes()
+            .all(|c| (c == b' ' || c.is_ascii_graphic()) && c != b'/')
+}
+
+/// Returns the name folded to lower case, names that differ only in case are the same name
+pub fn normalize_name(name: &str) -> String {
+    name.to_ascii_lowercase()
+}
+
+/// Returns the hash of the normalized name, which is used for PDA seeds instead of the name itself
+/// so that names are not limited by the maximum seed length
+pub fn get_name_hash(name: &str) -> NameHash {
+    hashv(&[normalize_name(name).as_bytes()]).to_bytes()
+}
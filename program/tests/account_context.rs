@@ -0,0 +1,300 @@
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        id,
+        tools::account_context::{assert_account_address, AccountConstraints, AccountContext},
+    },
+    solana_program::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, system_program,
+    },
+};
+
+/// Returns account info with the given flags, data size and owner, leaking its storage
+fn account_info(
+    is_signer: bool,
+    is_writable: bool,
+    data_len: usize,
+    owner: Pubkey,
+) -> AccountInfo<'static> {
+    AccountInfo::new(
+        Box::leak(Box::new(Pubkey::new_unique())),
+        is_signer,
+        is_writable,
+        Box::leak(Box::new(0)),
+        Box::leak(vec![0; data_len].into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )
+}
+
+fn system_program_info() -> AccountInfo<'static> {
+    AccountInfo::new(
+        Box::leak(Box::new(system_program::id())),
+        false,
+        false,
+        Box::leak(Box::new(0)),
+        &mut [],
+        Box::leak(Box::new(Pubkey::default())),
+        true,
+        0,
+    )
+}
+
+#[test]
+fn test_accounts_parsed() {
+    // Arrange
+    let program_id = id();
+    let accounts = [
+        account_info(true, true, 0, system_program::id()),
+        account_info(false, true, 10, id()),
+        account_info(false, false, 0, system_program::id()),
+        system_program_info(),
+    ];
+    let mut context = AccountContext::new("Test", &program_id, &accounts);
+
+    // Act
+    let payer_info = context
+        .next("Payer", AccountConstraints::new().signer().writable())
+        .unwrap();
+    let data_info = context
+        .next("Data", AccountConstraints::new().writable().initialized())
+        .unwrap();
+    let key_info = context.next("Key", AccountConstraints::new()).unwrap();
+    let system_info = context.next_system_program().unwrap();
+    let extra_info = context
+        .next_optional("Extra", AccountConstraints::new())
+        .unwrap();
+
+    // Assert
+    assert_eq!(accounts[0].key, payer_info.key);
+    assert_eq!(accounts[1].key, data_info.key);
+    assert_eq!(accounts[2].key, key_info.key);
+    assert_eq!(accounts[3].key, system_info.key);
+    assert!(
+        extra_info.is_none(),
+        "Missing optional account must be None"
+    );
+}
+
+#[test]
+fn test_signer_and_writable_constraints() {
+    // Arrange
+    let program_id = id();
+    let accounts = [
+        account_info(false, true, 0, system_program::id()),
+        account_info(true, false, 0, system_program::id()),
+    ];
+
+    // Act
+    let signer_err = AccountContext::new("Test", &program_id, &accounts)
+        .next("Payer", AccountConstraints::new().signer())
+        .err()
+        .unwrap();
+    let writable_err = AccountContext::new("Test", &program_id, &accounts[1..])
+        .next("Payer", AccountConstraints::new().signer().writable())
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(ProgramError::from(HapiError::SignatureMissing), signer_err);
+    assert_eq!(
+        ProgramError::from(HapiError::AccountNotWritable),
+        writable_err
+    );
+}
+
+#[test]
+fn test_initialized_constraint() {
+    // Arrange
+    let program_id = id();
+    let accounts = [
+        account_info(false, false, 0, id()),
+        account_info(false, false, 10, system_program::id()),
+    ];
+
+    // Act
+    let empty_err = AccountContext::new("Test", &program_id, &accounts)
+        .next("Data", AccountConstraints::new().initialized())
+        .err()
+        .unwrap();
+    let owner_err = AccountContext::new("Test", &program_id, &accounts[1..])
+        .next("Data", AccountConstraints::new().initialized())
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(ProgramError::UninitializedAccount, empty_err);
+    assert_eq!(
+        ProgramError::from(HapiError::InvalidAccountOwner),
+        owner_err
+    );
+}
+
+#[test]
+fn test_address_constraints() {
+    // Arrange
+    let program_id = id();
+    let accounts = [account_info(false, false, 0, system_program::id())];
+
+    // Act
+    let program_err = AccountContext::new("Test", &program_id, &accounts)
+        .next_system_program()
+        .err()
+        .unwrap();
+    let address_err = AccountContext::new("Test", &program_id, &accounts)
+        .next(
+            "Data",
+            AccountConstraints::new().address(&Pubkey::new_unique()),
+        )
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(ProgramError::IncorrectProgramId, program_err);
+    assert_eq!(
+        ProgramError::from(HapiError::InvalidAccountAddress),
+        address_err
+    );
+}
+
+#[test]
+fn test_missing_accounts() {
+    // Arrange
+    let program_id = id();
+    let accounts = [
+        account_info(false, true, 0, system_program::id()),
+        account_info(false, true, 0, system_program::id()),
+    ];
+    let mut context = AccountContext::new("Test", &program_id, &accounts);
+
+    // Act
+    let many_err = context
+        .next_many("Address", 3, AccountConstraints::new().writable())
+        .err()
+        .unwrap();
+    let address_infos = context
+        .next_many("Address", 2, AccountConstraints::new().writable())
+        .unwrap();
+    let next_err = context
+        .next("Address", AccountConstraints::new())
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(ProgramError::NotEnoughAccountKeys, many_err);
+    assert_eq!(2, address_infos.len());
+    assert_eq!(ProgramError::NotEnoughAccountKeys, next_err);
+}
+
+#[test]
+fn test_account_address() {
+    // Arrange
+    let seeds: &[&[u8]] = &[b"test"];
+    let (address, bump_seed) = Pubkey::find_program_address(seeds, &id());
+    let mut lamports = 0;
+    let mut data = [];
+    let owner = id();
+    let pda_info = AccountInfo::new(
+        &address,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let result = assert_account_address("Test", &pda_info, seeds, bump_seed, &id());
+    let err = assert_account_address("Test", &pda_info, &[b"other"], bump_seed, &id())
+        .err()
+        .unwrap();
+
+    // Assert
+    assert!(result.is_ok(), "PDA must match its seeds");
+    assert_eq!(ProgramError::InvalidSeeds, err);
+}
+
+#[test]
+fn test_pda_constraint() {
+    // Arrange
+    let program_id = id();
+    let seeds: &[&[u8]] = &[b"test"];
+    let (address, bump_seed) = Pubkey::find_program_address(seeds, &id());
+    let mut lamports = 0;
+    let mut data = [bump_seed];
+    let owner = id();
+    let pda_info = AccountInfo::new(
+        &address,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    let accounts = [pda_info];
+    let stored_bump_seed = |account_info: &AccountInfo| Ok(account_info.data.borrow()[0]);
+
+    // Act
+    let result = AccountContext::new("Test", &program_id, &accounts).next(
+        "Data",
+        AccountConstraints::new().pda(seeds, stored_bump_seed),
+    );
+    let err = AccountContext::new("Test", &program_id, &accounts)
+        .next(
+            "Data",
+            AccountConstraints::new().pda(&[b"other"], stored_bump_seed),
+        )
+        .err()
+        .unwrap();
+
+    // Assert
+    assert!(result.is_ok(), "PDA must match its seeds");
+    assert_eq!(ProgramError::InvalidSeeds, err);
+}
+
+#[test]
+fn test_payer() {
+    // Arrange
+    let program_id = id();
+    let accounts = [
+        account_info(true, true, 0, system_program::id()),
+        account_info(true, true, 0, system_program::id()),
+        account_info(false, false, 10, id()),
+    ];
+    let readonly_accounts = [
+        account_info(true, false, 0, system_program::id()),
+        account_info(false, false, 10, id()),
+    ];
+
+    // Act
+    let mut context = AccountContext::new("Test", &program_id, &accounts);
+    context
+        .next("Signer", AccountConstraints::new().signer())
+        .unwrap();
+    let payer_info = context.next_payer(0).unwrap();
+    let default_payer_info = context.next_payer(0).unwrap();
+    let next_info = context.next("Data", AccountConstraints::new()).unwrap();
+
+    let mut context = AccountContext::new("Test", &program_id, &readonly_accounts);
+    context
+        .next("Signer", AccountConstraints::new().signer())
+        .unwrap();
+    let readonly_err = context.next_payer(0).err().unwrap();
+
+    // Assert
+    assert_eq!(accounts[1].key, payer_info.key);
+    assert_eq!(
+        accounts[0].key, default_payer_info.key,
+        "Signer must pay if the next account doesn't sign"
+    );
+    assert_eq!(accounts[2].key, next_info.key);
+    assert_eq!(
+        ProgramError::from(HapiError::AccountNotWritable),
+        readonly_err
+    );
+}
@@ -0,0 +1,160 @@
+use {
+    borsh::BorshSerialize,
+    hapi_core_solana::{
+        error::HapiError,
+        state::{
+            address::{Address, AddressRef},
+            asset::{Asset, AssetRef},
+            enums::{Category, HapiAccountType},
+            transaction::{Transaction, TransactionRef},
+        },
+    },
+    solana_program::{program_error::ProgramError, pubkey::Pubkey},
+};
+
+fn address(entity: Option<Pubkey>, primary_category: Option<Category>) -> Address {
+    Address {
+        account_type: HapiAccountType::Address,
+        risk: 7,
+        case_id: 42,
+        categories: Category::Mixer | Category::Sanctions,
+        entity,
+        primary_category,
+        updated_at: 1_000_000,
+        expires_at: Some(2_000_000),
+        stale: true,
+        revision: 3,
+        bump_seed: 251,
+    }
+}
+
+fn assert_address_ref(address: &Address) {
+    let data = address.try_to_vec().unwrap();
+    let address_ref = AddressRef::new(&data).unwrap();
+
+    assert_eq!(address.risk, address_ref.risk().unwrap());
+    assert_eq!(address.case_id, address_ref.case_id().unwrap());
+    assert_eq!(address.categories, address_ref.categories().unwrap());
+    assert_eq!(address.entity, address_ref.entity().unwrap());
+    assert_eq!(
+        address.primary_category,
+        address_ref.primary_category().unwrap()
+    );
+    assert_eq!(address.updated_at, address_ref.updated_at().unwrap());
+    assert_eq!(address.expires_at, address_ref.expires_at().unwrap());
+    assert_eq!(address.stale, address_ref.stale().unwrap());
+    assert_eq!(address.revision, address_ref.revision().unwrap());
+    assert_eq!(address.bump_seed, address_ref.bump_seed().unwrap());
+}
+
+#[test]
+fn test_address_ref_matches_address() {
+    assert_address_ref(&address(None, None));
+    assert_address_ref(&address(Some(Pubkey::new_unique()), None));
+    assert_address_ref(&address(None, Some(Category::Sanctions)));
+    assert_address_ref(&address(Some(Pubkey::new_unique()), Some(Category::Mixer)));
+    assert_address_ref(&Address {
+        expires_at: None,
+        ..address(Some(Pubkey::new_unique()), Some(Category::Mixer))
+    });
+}
+
+#[test]
+fn test_address_ref_reads_max_size_account() {
+    let address = address(None, Some(Category::Mixer));
+    let mut data = vec![0; 128];
+    address.serialize(&mut &mut data[..]).unwrap();
+
+    let address_ref = AddressRef::new(&data).unwrap();
+
+    assert_eq!(address.revision, address_ref.revision().unwrap());
+    assert_eq!(address.bump_seed, address_ref.bump_seed().unwrap());
+}
+
+#[test]
+fn test_address_ref_invalid_data() {
+    let data = Asset {
+        account_type: HapiAccountType::Asset,
+        risk: 7,
+        case_id: 42,
+        category: Category::Scam,
+        bump_seed: 255,
+    }
+    .try_to_vec()
+    .unwrap();
+    assert_eq!(
+        ProgramError::from(HapiError::InvalidAccountType),
+        AddressRef::new(&data).err().unwrap(),
+        "Address account type must be invalid"
+    );
+
+    let data = address(Some(Pubkey::new_unique()), None)
+        .try_to_vec()
+        .unwrap();
+    assert_eq!(
+        ProgramError::InvalidAccountData,
+        AddressRef::new(&data[..20]).err().unwrap(),
+        "Truncated address data must be invalid"
+    );
+
+    let mut data = address(None, Some(Category::Mixer)).try_to_vec().unwrap();
+    // Primary category option tag follows the None entity
+    data[15] = 2;
+    assert_eq!(
+        ProgramError::InvalidAccountData,
+        AddressRef::new(&data).err().unwrap(),
+        "Invalid option tag must be rejected"
+    );
+}
+
+#[test]
+fn test_asset_ref_matches_asset() {
+    let asset = Asset {
+        account_type: HapiAccountType::Asset,
+        risk: 9,
+        case_id: 13,
+        category: Category::Scam,
+        bump_seed: 250,
+    };
+    let data = asset.try_to_vec().unwrap();
+
+    let asset_ref = AssetRef::new(&data).unwrap();
+
+    assert_eq!(asset.risk, asset_ref.risk().unwrap());
+    assert_eq!(asset.case_id, asset_ref.case_id().unwrap());
+    assert_eq!(asset.category, asset_ref.category().unwrap());
+    assert_eq!(asset.bump_seed, asset_ref.bump_seed().unwrap());
+}
+
+#[test]
+fn test_transaction_ref_matches_transaction() {
+    for (source, destination) in [
+        (None, None),
+        (Some(Pubkey::new_unique()), None),
+        (None, Some(Pubkey::new_unique())),
+        (Some(Pubkey::new_unique()), Some(Pubkey::new_unique())),
+    ] {
+        let transaction = Transaction {
+            account_type: HapiAccountType::Transaction,
+            risk: 6,
+            case_id: 5,
+            category: Category::Theft,
+            source,
+            destination,
+            bump_seed: 249,
+        };
+        let data = transaction.try_to_vec().unwrap();
+
+        let transaction_ref = TransactionRef::new(&data).unwrap();
+
+        assert_eq!(transaction.risk, transaction_ref.risk().unwrap());
+        assert_eq!(transaction.case_id, transaction_ref.case_id().unwrap());
+        assert_eq!(transaction.category, transaction_ref.category().unwrap());
+        assert_eq!(transaction.source, transaction_ref.source().unwrap());
+        assert_eq!(
+            transaction.destination,
+            transaction_ref.destination().unwrap()
+        );
+        assert_eq!(transaction.bump_seed, transaction_ref.bump_seed().unwrap());
+    }
+}
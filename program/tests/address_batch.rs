@@ -0,0 +1,65 @@
+use {
+    hapi_core_solana::instruction::{
+        create_addresses_chunked, AddressReport, MAX_ADDRESSES_PER_INSTRUCTION,
+    },
+    solana_program::{message::Message, pubkey::Pubkey},
+    solana_sdk::{
+        packet::PACKET_DATA_SIZE,
+        signature::{Keypair, Signer},
+    },
+};
+
+fn address_reports(count: usize) -> Vec<AddressReport> {
+    (0..count)
+        .map(|_| AddressReport {
+            address: Pubkey::new_unique(),
+            risk: 10,
+            categories: u32::MAX,
+            primary_category: None,
+            expires_at: Some(i64::MAX),
+        })
+        .collect()
+}
+
+#[test]
+fn test_addresses_split_into_chunks() {
+    let reporter = Pubkey::new_unique();
+    let addresses = address_reports(2 * MAX_ADDRESSES_PER_INSTRUCTION + 1);
+
+    let instructions =
+        create_addresses_chunked(&reporter, "hapi.one/ethereum", 1, &addresses).unwrap();
+
+    assert_eq!(3, instructions.len());
+    assert_eq!(
+        8 + MAX_ADDRESSES_PER_INSTRUCTION,
+        instructions[0].accounts.len()
+    );
+    assert_eq!(
+        8 + MAX_ADDRESSES_PER_INSTRUCTION,
+        instructions[1].accounts.len()
+    );
+    assert_eq!(9, instructions[2].accounts.len());
+}
+
+#[test]
+fn test_address_chunk_fits_transaction() {
+    let reporter = Keypair::new();
+    let addresses = address_reports(MAX_ADDRESSES_PER_INSTRUCTION);
+
+    let instructions = create_addresses_chunked(
+        &reporter.pubkey(),
+        "hapi.one/ethereum",
+        u64::MAX,
+        &addresses,
+    )
+    .unwrap();
+    let message = Message::new(&instructions, Some(&reporter.pubkey()));
+
+    // Compact signature count, signatures and message
+    let size = 1 + 64 * message.header.num_required_signatures as usize + message.serialize().len();
+    assert!(
+        size <= PACKET_DATA_SIZE,
+        "Transaction of {} bytes must fit a packet",
+        size
+    );
+}
@@ -0,0 +1,80 @@
+use {
+    borsh::BorshSerialize,
+    hapi_core_solana::{
+        consumer::is_address_maybe_reported,
+        id,
+        state::address_filter::{add_to_address_filter, get_address_filter_address, AddressFilter},
+    },
+    solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+};
+
+#[test]
+fn test_address_filter_contains_added_addresses() {
+    // Arrange
+    let mut address_filter = AddressFilter::new(&Pubkey::new_unique());
+    let addresses: Vec<Pubkey> = (0..1000).map(|_| Pubkey::new_unique()).collect();
+
+    // Act
+    for address in addresses.iter() {
+        address_filter.insert(address);
+    }
+
+    // Assert
+    assert_eq!(1000, address_filter.count);
+    assert!(
+        addresses
+            .iter()
+            .all(|address| address_filter.contains(address)),
+        "Filter must contain all added addresses"
+    );
+
+    let false_positives = (0..1000)
+        .filter(|_| address_filter.contains(&Pubkey::new_unique()))
+        .count();
+    assert!(
+        false_positives < 10,
+        "False positive rate must be low, got {} of 1000",
+        false_positives
+    );
+}
+
+#[test]
+fn test_address_maybe_reported_checked() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let key = get_address_filter_address(&network);
+    let owner = id();
+    let mut lamports = 0;
+    let mut data = AddressFilter::new(&network).try_to_vec().unwrap();
+    let address_filter_info = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let reported_before = is_address_maybe_reported(&address_filter_info, &network, &address);
+    add_to_address_filter(&address_filter_info, &network, &address).unwrap();
+    let reported_after = is_address_maybe_reported(&address_filter_info, &network, &address);
+    let other_network =
+        is_address_maybe_reported(&address_filter_info, &Pubkey::new_unique(), &address);
+
+    // Assert
+    assert_eq!(
+        Ok(false),
+        reported_before,
+        "Address must not be in the filter"
+    );
+    assert_eq!(Ok(true), reported_after, "Address must be in the filter");
+    assert_eq!(
+        Err(ProgramError::InvalidAccountData),
+        other_network,
+        "Filter of another network must be rejected"
+    );
+}
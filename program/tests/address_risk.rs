@@ -0,0 +1,51 @@
+use hapi_core_solana::state::{
+    address::Address,
+    enums::{Category, HapiAccountType},
+};
+
+const DAY: i64 = 86400;
+
+fn address(risk: u8, expires_at: Option<i64>, stale: bool) -> Address {
+    Address {
+        account_type: HapiAccountType::Address,
+        risk,
+        case_id: 1,
+        categories: Category::Theft as u32,
+        entity: None,
+        primary_category: Some(Category::Theft),
+        updated_at: 1000 * DAY,
+        expires_at,
+        stale,
+        revision: 0,
+        bump_seed: 0,
+    }
+}
+
+#[test]
+fn test_effective_risk_without_decay() {
+    let address = address(8, None, false);
+
+    assert_eq!(8, address.effective_risk(2000 * DAY, 0));
+}
+
+#[test]
+fn test_effective_risk_halves_every_decay_period() {
+    let address = address(8, None, false);
+
+    assert_eq!(8, address.effective_risk(1000 * DAY, 30 * DAY as u64));
+    assert_eq!(8, address.effective_risk(1029 * DAY, 30 * DAY as u64));
+    assert_eq!(4, address.effective_risk(1030 * DAY, 30 * DAY as u64));
+    assert_eq!(1, address.effective_risk(1090 * DAY, 30 * DAY as u64));
+    assert_eq!(0, address.effective_risk(1120 * DAY, 30 * DAY as u64));
+    assert_eq!(0, address.effective_risk(i64::MAX, 1));
+}
+
+#[test]
+fn test_effective_risk_of_expired_and_stale_address() {
+    let expired = address(8, Some(1500 * DAY), false);
+    let stale = address(8, None, true);
+
+    assert_eq!(8, expired.effective_risk(1499 * DAY, 0));
+    assert_eq!(0, expired.effective_risk(1500 * DAY, 0));
+    assert_eq!(0, stale.effective_risk(1000 * DAY, 0));
+}
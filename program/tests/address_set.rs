@@ -0,0 +1,139 @@
+use {
+    borsh::BorshSerialize,
+    hapi_core_solana::{
+        consumer::is_address_in_set,
+        id,
+        state::{
+            address_set::{get_address_set_leaf, AddressSet},
+            enums::{Category, HapiAccountType},
+        },
+        tools::merkle::{get_merkle_proof, get_merkle_root, verify_merkle_proof, MerkleHash},
+    },
+    solana_program::{account_info::AccountInfo, pubkey::Pubkey},
+};
+
+fn leaves(count: usize) -> Vec<MerkleHash> {
+    (0..count)
+        .map(|_| {
+            get_address_set_leaf(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                5,
+                Category::Theft,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_merkle_proof_verified() {
+    for count in 1..=17 {
+        // Arrange
+        let leaves = leaves(count);
+        let root = get_merkle_root(&leaves);
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            // Act
+            let proof = get_merkle_proof(&leaves, index).unwrap();
+
+            // Assert
+            assert!(
+                verify_merkle_proof(leaf, &proof, &root),
+                "Leaf {} of {} must be in the tree",
+                index,
+                count
+            );
+        }
+    }
+}
+
+#[test]
+fn test_merkle_proof_rejected() {
+    // Arrange
+    let leaves = leaves(6);
+    let root = get_merkle_root(&leaves);
+    let proof = get_merkle_proof(&leaves, 2).unwrap();
+
+    // Act
+    let result = verify_merkle_proof(&leaves[3], &proof, &root);
+
+    // Assert
+    assert!(!result, "Proof must not match another leaf");
+    assert_eq!(
+        None,
+        get_merkle_proof(&leaves, 6),
+        "Leaf must be out of the tree"
+    );
+    assert_eq!(
+        [0; 32],
+        get_merkle_root(&[]),
+        "Empty tree root must be zeroed"
+    );
+}
+
+#[test]
+fn test_address_in_set_checked() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let mut leaves = leaves(4);
+    leaves.push(get_address_set_leaf(
+        &network,
+        &address,
+        9,
+        Category::Sanctions,
+    ));
+    let proof = get_merkle_proof(&leaves, 4).unwrap();
+
+    let key = Pubkey::new_unique();
+    let owner = id();
+    let mut lamports = 0;
+    let mut data = AddressSet {
+        account_type: HapiAccountType::AddressSet,
+        case_id: 1,
+        root: get_merkle_root(&leaves),
+        size: leaves.len() as u64,
+        reporter_key: Pubkey::new_unique(),
+        updated_at: 0,
+        bump_seed: 0,
+    }
+    .try_to_vec()
+    .unwrap();
+    let address_set_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let in_set = is_address_in_set(
+        &address_set_info,
+        &network,
+        &address,
+        9,
+        Category::Sanctions,
+        &proof,
+    )
+    .unwrap();
+    let in_set_with_other_category = is_address_in_set(
+        &address_set_info,
+        &network,
+        &address,
+        9,
+        Category::Theft,
+        &proof,
+    )
+    .unwrap();
+
+    // Assert
+    assert!(in_set, "Address must be in the set");
+    assert!(
+        !in_set_with_other_category,
+        "Address must not be in the set with another category"
+    );
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        id,
+        instruction::check_address,
+        state::{
+            address::{get_address_address_seeds, Address},
+            enums::{Category, HapiAccountType},
+            network::Network,
+        },
+    },
+    solana_program::{instruction::InstructionError, program_error::ProgramError, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::{
+        account::Account,
+        signature::Signer,
+        transaction::{Transaction, TransactionError},
+        transport::TransportError,
+    },
+};
+
+mod program_test;
+
+use program_test::{tools::get_program_account, *};
+
+/// Highest bump seed of the test address PDA, finding it takes at least 9 PDA derivations
+const MAX_ADDRESS_BUMP_SEED: u8 = 247;
+
+/// Compute units available to CheckAddress, fewer than 9 PDA derivations cost
+const CHECK_ADDRESS_MAX_UNITS: u64 = 10_000;
+
+/// Returns reported address accounts of the network with the address PDA bump seed
+fn reported_address_accounts(bump_seed: Option<u8>) -> (Pubkey, Pubkey, Vec<(Pubkey, Account)>) {
+    let network = Pubkey::new_unique();
+
+    // Find an address that takes many tries to derive its PDA
+    let (value, address_address, address_bump_seed) = loop {
+        let value = Pubkey::new_unique();
+        let (address_address, address_bump_seed) =
+            Pubkey::find_program_address(&get_address_address_seeds(&network, &value), &id());
+        if address_bump_seed <= MAX_ADDRESS_BUMP_SEED {
+            break (value, address_address, address_bump_seed);
+        }
+    };
+
+    let network_account = get_program_account(&Network {
+        account_type: HapiAccountType::Network,
+        name: "Network".to_string(),
+        bump_seed: 0,
+    });
+
+    let address_account = get_program_account(&Address {
+        account_type: HapiAccountType::Address,
+        risk: 5,
+        case_id: 0,
+        categories: Category::WalletService as u32,
+        entity: None,
+        primary_category: Some(Category::WalletService),
+        updated_at: 0,
+        expires_at: None,
+        stale: false,
+        revision: 0,
+        bump_seed: bump_seed.unwrap_or(address_bump_seed),
+    });
+
+    (
+        network,
+        value,
+        vec![
+            (network, network_account),
+            (address_address, address_account),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn test_address_check_uses_stored_bump_seed() {
+    // Arrange
+    let (network, value, accounts) = reported_address_accounts(None);
+    let mut hapi_test =
+        HapiProgramTest::start_with_accounts(accounts, Some(CHECK_ADDRESS_MAX_UNITS)).await;
+
+    let check_address_ix = check_address(&network, &value, 5, &0).unwrap();
+
+    // Act
+    let result = hapi_test
+        .process_transaction(&[check_address_ix], None)
+        .await;
+
+    // Assert
+    assert!(
+        result.is_ok(),
+        "Address check must fit the compute budget without searching the bump seed"
+    );
+}
+
+#[tokio::test]
+async fn test_unreported_address_check_exceeds_stored_bump_seed_units() {
+    // Arrange
+    let (network, value, mut accounts) = reported_address_accounts(None);
+    // Unreported address PDA is validated by searching its bump seed with find_program_address
+    accounts.pop();
+    let mut hapi_test =
+        HapiProgramTest::start_with_accounts(accounts, Some(CHECK_ADDRESS_MAX_UNITS)).await;
+
+    let check_address_ix = check_address(&network, &value, 5, &0).unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[check_address_ix],
+        Some(&hapi_test.context.payer.pubkey()),
+        &[&hapi_test.context.payer],
+        hapi_test.context.last_blockhash,
+    );
+
+    // Act
+    let err = hapi_test
+        .context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert!(
+        matches!(
+            err,
+            TransportError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::ComputationalBudgetExceeded
+            ))
+        ),
+        "Searching the bump seed must take more compute units than the stored bump seed check"
+    );
+}
+
+#[tokio::test]
+async fn test_address_check_invalid_bump_seed() {
+    // Arrange
+    let (network, value, accounts) = reported_address_accounts(Some(255));
+    let mut hapi_test = HapiProgramTest::start_with_accounts(accounts, None).await;
+
+    let check_address_ix = check_address(&network, &value, 5, &0).unwrap();
+
+    // Act
+    let err = hapi_test
+        .process_transaction(&[check_address_ix], None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::InvalidSeeds,
+        "Address PDA must be invalid"
+    );
+}
@@ -0,0 +1,257 @@
+use {
+    borsh::BorshSerialize,
+    hapi_core_solana::{
+        consumer::{get_address_view, AddressView},
+        error::HapiError,
+        id,
+        state::{
+            address::{get_address_address, get_address_address_seeds, Address},
+            enums::{Category, HapiAccountType},
+        },
+    },
+    solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+};
+
+fn address_data(account_type: HapiAccountType, network: &Pubkey, address: &Pubkey) -> Vec<u8> {
+    address_account(account_type, network, address)
+        .try_to_vec()
+        .unwrap()
+}
+
+fn address_account(account_type: HapiAccountType, network: &Pubkey, address: &Pubkey) -> Address {
+    Address {
+        account_type,
+        risk: 7,
+        case_id: 1,
+        categories: Category::Mixer | Category::Sanctions,
+        entity: None,
+        primary_category: Some(Category::Mixer),
+        updated_at: 0,
+        expires_at: None,
+        stale: false,
+        revision: 0,
+        bump_seed: Pubkey::find_program_address(
+            &get_address_address_seeds(network, address),
+            &id(),
+        )
+        .1,
+    }
+}
+
+#[test]
+fn test_address_view_returned() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let key = get_address_address(&network, &address);
+    let owner = id();
+    let mut lamports = 0;
+    let mut data = address_data(HapiAccountType::Address, &network, &address);
+    let address_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let address_view = get_address_view(&address_info, &network, &address, 0, 0)
+        .unwrap()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        AddressView {
+            risk: 7,
+            case_id: 1,
+            categories: Category::Mixer | Category::Sanctions,
+            primary_category: Some(Category::Mixer),
+            expires_at: None,
+            stale: false,
+            effective_risk: 7,
+        },
+        address_view,
+        "Address view must match expectations"
+    );
+    assert!(address_view.is_risky(7));
+    assert!(!address_view.is_risky(8));
+    assert!(address_view.has_category(Category::Mixer));
+    assert!(address_view.has_category(Category::Sanctions));
+    assert!(!address_view.has_category(Category::Theft));
+    assert!(address_view.has_any_category(&(Category::Mixer | Category::Theft)));
+}
+
+#[test]
+fn test_address_view_effective_risk() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let key = get_address_address(&network, &address);
+    let owner = id();
+    let mut lamports = 0;
+    let mut account = address_account(HapiAccountType::Address, &network, &address);
+    account.expires_at = Some(300);
+    let mut data = account.try_to_vec().unwrap();
+    let address_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let decayed_view = get_address_view(&address_info, &network, &address, 200, 100)
+        .unwrap()
+        .unwrap();
+    let expired_view = get_address_view(&address_info, &network, &address, 300, 100)
+        .unwrap()
+        .unwrap();
+
+    // Assert
+    assert_eq!(7, decayed_view.risk, "Address risk must be kept");
+    assert_eq!(
+        1, decayed_view.effective_risk,
+        "Address risk must be halved every decay period"
+    );
+    assert_eq!(Some(300), decayed_view.expires_at);
+    assert!(!decayed_view.is_risky(2));
+    assert_eq!(
+        0, expired_view.effective_risk,
+        "Expired address must bear no risk"
+    );
+    assert!(!expired_view.is_risky(1));
+}
+
+#[test]
+fn test_unreported_address_view_is_none() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let key = get_address_address(&network, &address);
+    let owner = solana_program::system_program::id();
+    let mut lamports = 0;
+    let mut data = vec![];
+    let address_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let address_view = get_address_view(&address_info, &network, &address, 0, 0).unwrap();
+
+    // Assert
+    assert_eq!(None, address_view, "Unreported address must have no view");
+}
+
+#[test]
+fn test_address_view_invalid_address() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let key = get_address_address(&network, &Pubkey::new_unique());
+    let owner = id();
+    let mut lamports = 0;
+    let mut data = address_data(HapiAccountType::Address, &network, &address);
+    let address_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let err = get_address_view(&address_info, &network, &address, 0, 0)
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::InvalidSeeds,
+        "Address PDA must be invalid"
+    );
+}
+
+#[test]
+fn test_address_view_invalid_owner() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let key = get_address_address(&network, &address);
+    let owner = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = address_data(HapiAccountType::Address, &network, &address);
+    let address_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let err = get_address_view(&address_info, &network, &address, 0, 0)
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidAccountOwner.into(),
+        "Address owner must be invalid"
+    );
+}
+
+#[test]
+fn test_address_view_invalid_account_type() {
+    // Arrange
+    let network = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let key = get_address_address(&network, &address);
+    let owner = id();
+    let mut lamports = 0;
+    let mut data = address_data(HapiAccountType::Case, &network, &address);
+    let address_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Act
+    let err = get_address_view(&address_info, &network, &address, 0, 0)
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidAccountType.into(),
+        "Address account type must be invalid"
+    );
+}
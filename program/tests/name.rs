@@ -0,0 +1,52 @@
+use hapi_core_solana::{
+    instruction::{create_community, create_network},
+    state::{community::get_community_address, network::get_network_address},
+    tools::name::{get_name_hash, is_valid_name, normalize_name, MAX_NAME_LENGTH},
+};
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_name_normalized() {
+    assert_eq!("hapi.one", normalize_name("Hapi.One"));
+    assert_eq!(get_name_hash("HAPI.ONE"), get_name_hash("hapi.one"));
+    assert_ne!(get_name_hash("hapi.one"), get_name_hash("hapi.two"));
+}
+
+#[test]
+fn test_name_validity() {
+    assert!(is_valid_name("hapi.one"));
+    assert!(is_valid_name("Community #1"));
+    assert!(is_valid_name(&"a".repeat(MAX_NAME_LENGTH)));
+
+    assert!(!is_valid_name(""));
+    assert!(!is_valid_name(&"a".repeat(MAX_NAME_LENGTH + 1)));
+    assert!(!is_valid_name("hapi/one"));
+    assert!(!is_valid_name("hapi\none"));
+    assert!(!is_valid_name("hapi.оne"));
+}
+
+#[test]
+fn test_names_differing_in_case_share_address() {
+    let community_address = get_community_address("Hapi.One");
+
+    assert_eq!(community_address, get_community_address("hapi.one"));
+    assert_eq!(
+        get_network_address(&community_address, "Solana"),
+        get_network_address(&community_address, "solana")
+    );
+}
+
+#[test]
+fn test_long_names_accepted() {
+    let name = "a".repeat(MAX_NAME_LENGTH);
+
+    assert!(create_community(&Pubkey::new_unique(), &name).is_ok());
+    assert!(create_network(&Pubkey::new_unique(), &format!("{}/{}", name, name)).is_ok());
+}
+
+#[test]
+fn test_invalid_names_rejected() {
+    assert!(create_community(&Pubkey::new_unique(), "").is_err());
+    assert!(create_network(&Pubkey::new_unique(), "hapi.one/").is_err());
+    assert!(create_network(&Pubkey::new_unique(), "hapi.one/solana/mainnet").is_err());
+}
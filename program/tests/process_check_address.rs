@@ -0,0 +1,211 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        state::enums::{Category, CategorySet},
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_address_check_passed() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+    let forbidden_categories: CategorySet = Category::Mixer | Category::Sanctions;
+
+    // Act
+    let result = hapi_test
+        .check_address(
+            &network_cookie,
+            &address_cookie.value,
+            5,
+            &forbidden_categories,
+        )
+        .await;
+
+    // Assert
+    assert!(result.is_ok(), "Address check must pass");
+}
+
+#[tokio::test]
+async fn test_unreported_address_check_passed() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+
+    // Act
+    let result = hapi_test
+        .check_address(&network_cookie, &Pubkey::new_unique(), 0, &u32::MAX)
+        .await;
+
+    // Assert
+    assert!(result.is_ok(), "Unreported address check must pass");
+}
+
+#[tokio::test]
+async fn test_expired_address_check_passed() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    let mut address = address_cookie.account.clone();
+    address.expires_at = Some(address.updated_at - 1);
+
+    hapi_test
+        .update_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &address_cookie,
+            &address,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let result = hapi_test
+        .check_address(&network_cookie, &address_cookie.value, 4, &u32::MAX)
+        .await;
+
+    // Assert
+    assert!(result.is_ok(), "Expired address must bear no risk");
+}
+
+#[tokio::test]
+async fn test_address_check_failed_risk() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    // Act
+    let err = hapi_test
+        .check_address(&network_cookie, &address_cookie.value, 4, &0)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::HighRiskAddress.into(),
+        "Address risk must be too high"
+    );
+}
+
+#[tokio::test]
+async fn test_address_check_failed_category() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+    let forbidden_categories: CategorySet = Category::WalletService | Category::Mixer;
+
+    // Act
+    let err = hapi_test
+        .check_address(
+            &network_cookie,
+            &address_cookie.value,
+            10,
+            &forbidden_categories,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::HighRiskAddress.into(),
+        "Address category must be forbidden"
+    );
+}
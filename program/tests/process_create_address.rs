@@ -1,6 +1,18 @@
 //#![cfg(feature = "test-bpf")]
 
-use solana_program_test::*;
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::{create_address, AddressReport},
+        state::{
+            address::get_address_address,
+            enums::{Category, CategorySet},
+        },
+    },
+    solana_program::{program_error::ProgramError, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
 
 mod program_test;
 
@@ -42,8 +54,199 @@ async fn test_address_reported() {
     );
 
     assert_eq!(
-        24,
+        112,
         std::mem::size_of_val(&address_account),
         "Unpacked account size must be correct"
     );
+
+    let reporter_account = hapi_test
+        .get_reporter_account(&reporter_cookie.address)
+        .await;
+    assert_eq!(1, reporter_account.cases_created, "Reported cases must be counted");
+    assert_eq!(1, reporter_account.addresses_created, "Reported addresses must be counted");
+    assert_eq!(100, reporter_account.reputation(), "Reporter reputation must be correct");
+}
+
+#[tokio::test]
+async fn test_address_report_without_address_filter_account_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let value = Pubkey::new_unique();
+    let mut create_address_ix = create_address(
+        &reporter_cookie.reporter_keypair.pubkey(),
+        &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+        case_cookie.id,
+        &AddressReport {
+            address: value,
+            risk: 5,
+            categories: Category::WalletService as CategorySet,
+            primary_category: None,
+            expires_at: None,
+        },
+    )
+    .unwrap();
+    // Omitting the filter account would skip the filter of the network once it exists
+    create_address_ix.accounts.pop();
+
+    // Act
+    let err = hapi_test
+        .process_transaction(
+            &[create_address_ix],
+            Some(&[&reporter_cookie.reporter_keypair]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::NotEnoughAccountKeys,
+        "Address report without address filter account must be rejected"
+    );
+    assert!(
+        hapi_test
+            .get_account(&get_address_address(&network_cookie.address, &value))
+            .await
+            .is_none(),
+        "Address must not be reported"
+    );
+}
+
+#[tokio::test]
+async fn test_address_category_not_in_case_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let categories: CategorySet = Category::WalletService | Category::Mixer;
+
+    // Act
+    let err = hapi_test
+        .create_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &categories,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::CategoryNotInCase.into(),
+        "Address categories must be reported in the case"
+    );
+}
+
+#[tokio::test]
+async fn test_address_invalid_primary_category_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let categories: CategorySet = Category::WalletService as u32;
+
+    // Act
+    let err = hapi_test
+        .create_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &categories,
+            Some(Category::Safe),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidPrimaryCategory.into(),
+        "Primary category must be one of address categories"
+    );
+}
+
+#[tokio::test]
+async fn test_address_report_quota_exceeded_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    hapi_test
+        .update_community(&authority_keypair, None, &community_cookie, 0, 1, 86400)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    // Act
+    let err = hapi_test
+        .create_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &case_cookie.account.categories,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::ReportQuotaExceeded.into(),
+        "Reports beyond the quota must be rejected"
+    );
 }
@@ -0,0 +1,119 @@
+//#![cfg(feature = "test-bpf")]
+
+use {hapi_core_solana::error::HapiError, solana_program::pubkey::Pubkey, solana_program_test::*};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_address_link_created() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    // Act
+    let address_link_cookie = hapi_test
+        .with_address_link(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &source,
+            &destination,
+            80,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let address_link_account = hapi_test
+        .get_address_link_account(&address_link_cookie.address)
+        .await;
+    assert_eq!(
+        address_link_cookie.account, address_link_account,
+        "Address link account must match expectations"
+    );
+}
+
+#[tokio::test]
+async fn test_address_link_with_invalid_confidence_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .with_address_link(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            101,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidConfidence.into(),
+        "Link confidence must not exceed 100"
+    );
+}
+
+#[tokio::test]
+async fn test_address_link_to_itself_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let address = Pubkey::new_unique();
+
+    // Act
+    let err = hapi_test
+        .with_address_link(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &address,
+            &address,
+            80,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidAddressLink.into(),
+        "Address must not be linked to itself"
+    );
+}
@@ -0,0 +1,137 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::AddressReport,
+        state::{
+            address::get_address_address,
+            enums::{Category, CategorySet},
+        },
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+};
+
+mod program_test;
+
+use program_test::*;
+
+fn address_report(risk: u8, categories: CategorySet) -> AddressReport {
+    AddressReport {
+        address: Pubkey::new_unique(),
+        risk,
+        categories,
+        primary_category: None,
+        expires_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_addresses_reported() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let addresses = vec![
+        address_report(3, Category::Safe as u32),
+        address_report(5, Category::WalletService as u32),
+        address_report(7, Category::Safe | Category::WalletService),
+    ];
+
+    // Act
+    hapi_test
+        .create_addresses(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &addresses,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    for report in &addresses {
+        let address_account = hapi_test
+            .get_address_account(&get_address_address(
+                &network_cookie.address,
+                &report.address,
+            ))
+            .await;
+        assert_eq!(
+            report.risk, address_account.risk,
+            "Address risk must be correct"
+        );
+        assert_eq!(
+            report.categories, address_account.categories,
+            "Address categories must be correct"
+        );
+        assert_eq!(
+            case_cookie.id, address_account.case_id,
+            "Address case must be correct"
+        );
+    }
+
+    let reporter_account = hapi_test
+        .get_reporter_account(&reporter_cookie.address)
+        .await;
+    assert_eq!(
+        3, reporter_account.addresses_created,
+        "Reported addresses must be counted"
+    );
+}
+
+#[tokio::test]
+async fn test_addresses_category_not_in_case_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let addresses = vec![
+        address_report(3, Category::Safe as u32),
+        address_report(5, Category::Mixer as u32),
+    ];
+
+    // Act
+    let err = hapi_test
+        .create_addresses(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &addresses,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::CategoryNotInCase.into(),
+        "Address categories must be reported in the case"
+    );
+}
@@ -0,0 +1,82 @@
+//#![cfg(feature = "test-bpf")]
+
+use solana_program_test::*;
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_asset_reported() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    // Act
+    let asset_cookie = hapi_test
+        .with_asset(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            None,
+        )
+        .await;
+
+    // Assert
+    let asset_account = hapi_test.get_asset_account(&asset_cookie.address).await;
+    assert_eq!(
+        asset_cookie.account, asset_account,
+        "Asset account must match expectations"
+    );
+}
+
+#[tokio::test]
+async fn test_asset_with_token_id_reported() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let mut token_id = [0u8; 32];
+    token_id[31] = 42;
+
+    // Act
+    let asset_cookie = hapi_test
+        .with_asset(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            Some(token_id),
+        )
+        .await;
+
+    // Assert
+    let asset_account = hapi_test.get_asset_account(&asset_cookie.address).await;
+    assert_eq!(
+        asset_cookie.account, asset_account,
+        "Asset account must match expectations"
+    );
+}
@@ -1,6 +1,16 @@
 //#![cfg(feature = "test-bpf")]
 
-use solana_program_test::*;
+use {
+    hapi_core_solana::{
+        instruction::{create_case, with_payer},
+        state::{
+            case::get_case_address,
+            enums::{CaseStatus, Category},
+        },
+    },
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
 
 mod program_test;
 
@@ -28,3 +38,66 @@ async fn test_case_reported() {
     let community_account = hapi_test.get_community_account(&community_cookie.address).await;
     assert_eq!(community_account.next_case_id, 1, "Next case ID should be incremented");
 }
+
+#[tokio::test]
+async fn test_case_reported_with_payer() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let payer_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let reporter_key = reporter_cookie.reporter_keypair.pubkey();
+    let reporter_lamports = hapi_test.get_account(&reporter_key).await.unwrap().lamports;
+    let payer_lamports = hapi_test
+        .get_account(&payer_keypair.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    let case_id = community_cookie.account.next_case_id;
+    let create_case_ix = create_case(
+        &reporter_key,
+        &community_cookie.name,
+        case_id,
+        "Case paid by payer",
+        CaseStatus::Open,
+        &(Category::Safe as u32),
+        None,
+    )
+    .unwrap();
+
+    // Act
+    hapi_test
+        .process_transaction(
+            &[with_payer(create_case_ix, &payer_keypair.pubkey())],
+            Some(&[&reporter_cookie.reporter_keypair, &payer_keypair]),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let case_address = get_case_address(&community_cookie.address, &case_id.to_le_bytes());
+    let case_account = hapi_test.get_account(&case_address).await.unwrap();
+    let case_data = hapi_test.get_case_account(&case_address).await;
+    assert_eq!(
+        reporter_key, case_data.reporter_key,
+        "Reporter key must report the case"
+    );
+    let reporter_account = hapi_test.get_account(&reporter_key).await.unwrap();
+    assert_eq!(
+        reporter_lamports, reporter_account.lamports,
+        "Reporter key must not pay for the case account"
+    );
+    let payer_account = hapi_test
+        .get_account(&payer_keypair.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        payer_lamports - case_account.lamports,
+        payer_account.lamports,
+        "Payer must pay for the case account"
+    );
+}
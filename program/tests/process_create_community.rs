@@ -1,6 +1,11 @@
 //#![cfg(feature = "test-bpf")]
 
-use solana_program_test::*;
+use {
+    hapi_core_solana::{instruction::create_community, state::community::get_community_address},
+    solana_program::program_error::ProgramError,
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
 
 mod program_test;
 
@@ -20,5 +25,57 @@ async fn test_network_created() {
 
     assert_eq!(community_cookie.account, community_account, "Community account must match expectations");
 
-    assert_eq!(72, std::mem::size_of_val(&community_account), "Account size must be correct");
+    assert_eq!(104, std::mem::size_of_val(&community_account), "Account size must be correct");
+}
+
+#[tokio::test]
+async fn test_community_created_with_long_name() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let name = "HAPI Protocol community of security data reporters".to_string();
+
+    let create_community_ix = create_community(&authority_keypair.pubkey(), &name).unwrap();
+
+    // Act
+    hapi_test
+        .process_transaction(&[create_community_ix], Some(&[&authority_keypair]))
+        .await
+        .unwrap();
+
+    // Assert
+    let community_account = hapi_test
+        .get_community_account(&get_community_address(&name))
+        .await;
+
+    assert_eq!(name, community_account.name, "Community display name must be stored");
+}
+
+#[tokio::test]
+async fn test_community_name_case_insensitive() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+
+    let create_community_ix = create_community(&authority_keypair.pubkey(), "Hapi.One").unwrap();
+    hapi_test
+        .process_transaction(&[create_community_ix], Some(&[&authority_keypair]))
+        .await
+        .unwrap();
+
+    let create_community_ix = create_community(&authority_keypair.pubkey(), "hapi.one").unwrap();
+
+    // Act
+    let err = hapi_test
+        .process_transaction(&[create_community_ix], Some(&[&authority_keypair]))
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::AccountAlreadyInitialized,
+        "Community name must be unique regardless of case"
+    );
 }
@@ -0,0 +1,114 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        state::{
+            enums::{HapiAccountType, ReporterType},
+            reporter::Reporter,
+        },
+    },
+    solana_program_test::*,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_entity_created() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+
+    // Act
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    // Assert
+    let entity_account = hapi_test.get_entity_account(&entity_cookie.address).await;
+    assert_eq!(
+        entity_cookie.account, entity_account,
+        "Entity account must match expectations"
+    );
+}
+
+#[tokio::test]
+async fn test_entity_created_with_parent() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let parent_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    // Act
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, Some(&parent_cookie))
+        .await
+        .unwrap();
+
+    // Assert
+    let entity_account = hapi_test.get_entity_account(&entity_cookie.address).await;
+    assert_eq!(
+        Some(parent_cookie.address),
+        entity_account.parent,
+        "Entity parent must be set"
+    );
+}
+
+#[tokio::test]
+async fn test_entity_tracer_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+
+    let reporter = Reporter {
+        account_type: HapiAccountType::Reporter,
+        reporter_type: ReporterType::Tracer,
+        name: reporter_cookie.name.clone(),
+        ..reporter_cookie.account.clone()
+    };
+
+    hapi_test
+        .update_reporter(
+            &authority_keypair,
+            &community_cookie,
+            &reporter_cookie,
+            &reporter,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidReporterPermissions.into(),
+        "Tracer must not be able to create an entity"
+    );
+}
@@ -0,0 +1,45 @@
+//#![cfg(feature = "test-bpf")]
+
+use solana_program_test::*;
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_transaction_reported() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    // Act
+    let transaction_cookie = hapi_test
+        .with_transaction(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            7,
+        )
+        .await;
+
+    // Assert
+    let transaction_account = hapi_test
+        .get_transaction_account(&transaction_cookie.address)
+        .await;
+    assert_eq!(
+        transaction_cookie.account, transaction_account,
+        "Transaction account must match expectations"
+    );
+}
@@ -0,0 +1,115 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::state::{address_filter::get_address_filter_address, enums::HapiAccountType},
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_address_filter_rebuilt_and_updated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    let unreported_address = Pubkey::new_unique();
+
+    // Act
+    hapi_test
+        .rebuild_address_filter(
+            &authority_keypair,
+            &community_cookie,
+            &network_cookie,
+            true,
+            &[address_cookie.value, unreported_address],
+        )
+        .await
+        .unwrap();
+
+    let new_address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            7,
+        )
+        .await;
+
+    // Assert
+    let address_filter_account = hapi_test
+        .get_address_filter_account(&get_address_filter_address(&network_cookie.address))
+        .await;
+
+    assert_eq!(
+        HapiAccountType::AddressFilter,
+        address_filter_account.account_type
+    );
+    assert_eq!(network_cookie.address, address_filter_account.network);
+    assert_eq!(
+        2, address_filter_account.count,
+        "Only reported addresses must be added"
+    );
+    assert!(
+        address_filter_account.contains(&address_cookie.value),
+        "Rebuilt filter must contain the address"
+    );
+    assert!(
+        address_filter_account.contains(&new_address_cookie.value),
+        "New address must be added to the filter"
+    );
+}
+
+#[tokio::test]
+async fn test_address_filter_rebuild_by_reporter_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .rebuild_address_filter(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            true,
+            &[],
+        )
+        .await
+        .err();
+
+    // Assert
+    assert!(err.is_some(), "Only authority can rebuild address filter");
+}
@@ -0,0 +1,59 @@
+//#![cfg(feature = "test-bpf")]
+
+use {hapi_core_solana::error::HapiError, solana_program_test::*};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_lost_dispute_recorded() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    // Act
+    hapi_test
+        .record_lost_dispute(&authority_keypair, &community_cookie, &reporter_cookie)
+        .await
+        .unwrap();
+
+    // Assert
+    let reporter_account = hapi_test
+        .get_reporter_account(&reporter_cookie.address)
+        .await;
+
+    assert_eq!(1, reporter_account.disputes_lost, "Lost dispute must be counted");
+    assert_eq!(9, reporter_account.reputation(), "Reporter reputation must be lowered");
+}
+
+#[tokio::test]
+async fn test_lost_dispute_not_recorded_invalid_authority() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let real_authority = hapi_test.create_funded_keypair().await;
+    let rando_authority = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&real_authority).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&real_authority, &community_cookie)
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .record_lost_dispute(&rando_authority, &community_cookie, &reporter_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(err, HapiError::InvalidNetworkAuthority.into(), "Authority must be invalid");
+}
@@ -0,0 +1,157 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        state::enums::{CaseStatus, Category},
+    },
+    solana_program_test::*,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_expired_address_marked_stale() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    let mut address = address_cookie.account.clone();
+    address.expires_at = Some(address.updated_at - 1);
+
+    hapi_test
+        .update_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &address_cookie,
+            &address,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    hapi_test
+        .refresh_address(&community_cookie, &network_cookie, &address_cookie)
+        .await
+        .unwrap();
+
+    // Assert
+    let address_account = hapi_test.get_address_account(&address_cookie.address).await;
+    assert!(address_account.stale, "Expired address must be stale");
+}
+
+#[tokio::test]
+async fn test_address_of_expired_case_marked_stale() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    hapi_test
+        .update_case(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &case_cookie,
+            &(Category::Safe | Category::WalletService),
+            CaseStatus::Closed,
+            Some(address_cookie.account.updated_at - 1),
+        )
+        .await
+        .unwrap();
+
+    // Act
+    hapi_test
+        .refresh_address(&community_cookie, &network_cookie, &address_cookie)
+        .await
+        .unwrap();
+
+    // Assert
+    let address_account = hapi_test.get_address_account(&address_cookie.address).await;
+    assert!(
+        address_account.stale,
+        "Address of an expired case must be stale"
+    );
+}
+
+#[tokio::test]
+async fn test_address_not_expired_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    // Act
+    let err = hapi_test
+        .refresh_address(&community_cookie, &network_cookie, &address_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::ReportNotExpired.into(),
+        "Address without expiry must not be refreshed"
+    );
+}
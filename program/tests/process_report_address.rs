@@ -0,0 +1,233 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::{report_address, AddressReport},
+        state::{
+            address::{get_address_address, Address},
+            address_revision::get_address_revision_address,
+            enums::{Category, HapiAccountType},
+        },
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
+
+mod program_test;
+
+use program_test::*;
+
+fn address(case_id: u64, risk: u8) -> Address {
+    Address {
+        account_type: HapiAccountType::Address,
+        risk,
+        case_id,
+        categories: Category::WalletService as u32,
+        entity: None,
+        primary_category: None,
+        updated_at: 0,
+        expires_at: None,
+        stale: false,
+        revision: 0,
+        bump_seed: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_address_created_and_updated_by_report() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let value = Pubkey::new_unique();
+    let address_address = get_address_address(&network_cookie.address, &value);
+
+    // Act
+    hapi_test
+        .report_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &value,
+            &address(case_cookie.id, 3),
+            0,
+        )
+        .await
+        .unwrap();
+
+    hapi_test
+        .report_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &value,
+            &address(case_cookie.id, 7),
+            0,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let address_account = hapi_test.get_address_account(&address_address).await;
+    assert_eq!(7, address_account.risk, "Address risk must be updated");
+    assert_eq!(
+        1, address_account.revision,
+        "Address revision must be incremented"
+    );
+
+    let revision_account = hapi_test
+        .get_address_revision_account(&get_address_revision_address(&address_address, 0))
+        .await;
+    assert_eq!(3, revision_account.risk, "Replaced risk must be recorded");
+
+    let reporter_account = hapi_test
+        .get_reporter_account(&reporter_cookie.address)
+        .await;
+    assert_eq!(
+        1, reporter_account.addresses_created,
+        "Reported addresses must be counted"
+    );
+    assert_eq!(
+        1, reporter_account.addresses_updated,
+        "Address updates must be counted"
+    );
+}
+
+#[tokio::test]
+async fn test_address_same_report_ignored() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let value = Pubkey::new_unique();
+    let reported_address = address(case_cookie.id, 5);
+
+    hapi_test
+        .report_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &value,
+            &reported_address,
+            0,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    hapi_test
+        .report_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &value,
+            &reported_address,
+            0,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let address_account = hapi_test
+        .get_address_account(&get_address_address(&network_cookie.address, &value))
+        .await;
+    assert_eq!(0, address_account.revision, "Address must not be updated");
+}
+
+#[tokio::test]
+async fn test_address_update_by_report_without_revision_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let value = Pubkey::new_unique();
+    let address_address = get_address_address(&network_cookie.address, &value);
+
+    hapi_test
+        .report_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &value,
+            &address(case_cookie.id, 3),
+            0,
+        )
+        .await
+        .unwrap();
+
+    let reported_address = address(case_cookie.id, 7);
+    let mut report_address_ix = report_address(
+        &reporter_cookie.reporter_keypair.pubkey(),
+        &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+        reported_address.case_id,
+        &AddressReport {
+            address: value,
+            risk: reported_address.risk,
+            categories: reported_address.categories,
+            primary_category: reported_address.primary_category,
+            expires_at: reported_address.expires_at,
+        },
+        0,
+    )
+    .unwrap();
+    report_address_ix.accounts.pop();
+
+    // Act
+    let err = hapi_test
+        .process_transaction(
+            &[report_address_ix],
+            Some(&[&reporter_cookie.reporter_keypair]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::AddressRevisionMissing.into(),
+        "Address update without revision must be rejected"
+    );
+
+    let address_account = hapi_test.get_address_account(&address_address).await;
+    assert_eq!(3, address_account.risk, "Address must not be updated");
+    assert_eq!(
+        0, address_account.revision,
+        "Address revision must not change"
+    );
+}
@@ -0,0 +1,84 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::error::HapiError,
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_reporter_key_rotated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+
+    // Act
+    let rotated_cookie = hapi_test
+        .rotate_reporter_key(&authority_keypair, &community_cookie, &reporter_cookie)
+        .await
+        .unwrap();
+
+    // Assert
+    let reporter_account = hapi_test
+        .get_reporter_account(&rotated_cookie.address)
+        .await;
+    assert_eq!(
+        rotated_cookie.account, reporter_account,
+        "Reporter account must match expectations"
+    );
+    assert_eq!(
+        reporter_cookie.reporter_keypair.pubkey(),
+        reporter_account.identity,
+        "Reporter identity must be kept"
+    );
+    assert!(
+        hapi_test.get_account(&reporter_cookie.address).await.is_none(),
+        "Old reporter account must be disposed"
+    );
+
+    let case_cookie = hapi_test
+        .with_case(&rotated_cookie, &community_cookie)
+        .await;
+    let case_account = hapi_test.get_case_account(&case_cookie.address).await;
+    assert_eq!(
+        reporter_cookie.reporter_keypair.pubkey(),
+        case_account.reporter_key,
+        "Case must be reported by the reporter identity"
+    );
+}
+
+#[tokio::test]
+async fn test_reporter_key_not_rotated_invalid_authority() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let real_authority = hapi_test.create_funded_keypair().await;
+    let rando_authority = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&real_authority).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&real_authority, &community_cookie)
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .rotate_reporter_key(&rando_authority, &community_cookie, &reporter_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(err, HapiError::InvalidNetworkAuthority.into(), "Authority must be invalid");
+    assert!(
+        hapi_test.get_account(&reporter_cookie.address).await.is_some(),
+        "Reporter account must stay in place"
+    );
+}
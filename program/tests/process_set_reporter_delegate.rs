@@ -0,0 +1,156 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::{create_case, sign_with_delegate},
+        state::{
+            case::get_case_address,
+            enums::{CaseStatus, Category},
+        },
+    },
+    solana_program::program_error::ProgramError,
+    solana_program_test::*,
+    solana_sdk::signature::{Keypair, Signer},
+};
+
+mod program_test;
+
+use program_test::{
+    cookies::{CommunityCookie, ReporterCookie},
+    *,
+};
+
+async fn create_case_with_delegate(
+    hapi_test: &mut HapiProgramTest,
+    reporter_cookie: &ReporterCookie,
+    community_cookie: &CommunityCookie,
+    delegate: &Keypair,
+) -> Result<(), ProgramError> {
+    let create_case_ix = create_case(
+        &reporter_cookie.reporter_keypair.pubkey(),
+        &community_cookie.name,
+        community_cookie.account.next_case_id,
+        "Delegated case",
+        CaseStatus::Open,
+        &(Category::Safe as u32),
+        None,
+    )
+    .unwrap();
+
+    hapi_test
+        .process_transaction(
+            &[sign_with_delegate(create_case_ix, &delegate.pubkey())],
+            Some(&[delegate]),
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_delegate_case_reported() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let delegate_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let valid_until = hapi_test.get_clock().await.unix_timestamp + 3600;
+    hapi_test
+        .set_reporter_delegate(
+            &reporter_cookie,
+            &community_cookie,
+            Some(&delegate_keypair.pubkey()),
+            valid_until,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    create_case_with_delegate(
+        &mut hapi_test,
+        &reporter_cookie,
+        &community_cookie,
+        &delegate_keypair,
+    )
+    .await
+    .unwrap();
+
+    // Assert
+    let case_address = get_case_address(
+        &community_cookie.address,
+        &community_cookie.account.next_case_id.to_le_bytes(),
+    );
+    let case_account = hapi_test.get_case_account(&case_address).await;
+    assert_eq!(
+        reporter_cookie.reporter_keypair.pubkey(),
+        case_account.reporter_key,
+        "Case must be reported by the reporter identity"
+    );
+}
+
+#[tokio::test]
+async fn test_expired_delegate_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let delegate_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let valid_until = hapi_test.get_clock().await.unix_timestamp;
+    hapi_test
+        .set_reporter_delegate(
+            &reporter_cookie,
+            &community_cookie,
+            Some(&delegate_keypair.pubkey()),
+            valid_until,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let err = create_case_with_delegate(
+        &mut hapi_test,
+        &reporter_cookie,
+        &community_cookie,
+        &delegate_keypair,
+    )
+    .await
+    .err()
+    .unwrap();
+
+    // Assert
+    assert_eq!(err, HapiError::DelegateExpired.into(), "Delegate must be expired");
+}
+
+#[tokio::test]
+async fn test_unknown_delegate_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let rando_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+
+    // Act
+    let err = create_case_with_delegate(
+        &mut hapi_test,
+        &reporter_cookie,
+        &community_cookie,
+        &rando_keypair,
+    )
+    .await
+    .err()
+    .unwrap();
+
+    // Assert
+    assert_eq!(err, HapiError::InvalidReporter.into(), "Reporter must be invalid");
+}
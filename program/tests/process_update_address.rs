@@ -0,0 +1,231 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::{update_address, AddressReport},
+        state::{
+            address_revision::{
+                get_address_revision_address, get_address_revision_address_seeds, AddressRevision,
+            },
+            enums::{Category, CategorySet, HapiAccountType},
+        },
+    },
+    solana_program::program_error::ProgramError,
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
+
+mod program_test;
+
+use program_test::{tools::get_bump_seed, *};
+
+#[tokio::test]
+async fn test_address_updated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    let mut address = address_cookie.account.clone();
+    address.risk = 2;
+    address.categories = Category::Safe | Category::WalletService;
+    address.primary_category = Some(Category::Safe);
+    address.expires_at = Some(address.updated_at + 86400);
+
+    // Act
+    hapi_test
+        .update_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &address_cookie,
+            &address,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    address.updated_at = hapi_test.get_clock().await.unix_timestamp;
+    address.revision = 1;
+    let address_account = hapi_test.get_address_account(&address_cookie.address).await;
+    assert_eq!(address, address_account, "Address account must be updated");
+
+    let address_revision_account = hapi_test
+        .get_address_revision_account(&get_address_revision_address(&address_cookie.address, 0))
+        .await;
+    assert_eq!(
+        AddressRevision {
+            account_type: HapiAccountType::AddressRevision,
+            address: address_cookie.address,
+            revision: 0,
+            risk: address_cookie.account.risk,
+            case_id: address_cookie.account.case_id,
+            categories: address_cookie.account.categories,
+            primary_category: address_cookie.account.primary_category,
+            updater_key: reporter_cookie.reporter_keypair.pubkey(),
+            updated_at: address.updated_at,
+            bump_seed: get_bump_seed(&get_address_revision_address_seeds(
+                &address_cookie.address,
+                &0u64.to_le_bytes()
+            )),
+        },
+        address_revision_account,
+        "Address revision must record previous values"
+    );
+}
+
+#[tokio::test]
+async fn test_address_update_category_not_in_case_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    let categories: CategorySet = Category::Sanctions as u32;
+    let mut address = address_cookie.account.clone();
+    address.categories = categories;
+    address.primary_category = None;
+
+    // Act
+    let err = hapi_test
+        .update_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &address_cookie,
+            &address,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::CategoryNotInCase.into(),
+        "Address categories must be reported in the case"
+    );
+}
+
+#[tokio::test]
+async fn test_address_update_by_other_community_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+
+    let other_community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let other_network_cookie = hapi_test
+        .with_network(&authority_keypair, &other_community_cookie)
+        .await;
+    let other_reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &other_community_cookie)
+        .await
+        .unwrap();
+    let other_case_cookie = hapi_test
+        .with_case(&other_reporter_cookie, &other_community_cookie)
+        .await;
+
+    let address = &address_cookie.account;
+    let mut update_address_ix = update_address(
+        &other_reporter_cookie.reporter_keypair.pubkey(),
+        &format!(
+            "{}/{}",
+            &other_community_cookie.name, &other_network_cookie.name
+        ),
+        other_case_cookie.id,
+        &AddressReport {
+            address: address_cookie.value,
+            risk: 0,
+            categories: address.categories,
+            primary_category: address.primary_category,
+            expires_at: address.expires_at,
+        },
+        address.revision,
+    )
+    .unwrap();
+    update_address_ix.accounts[5].pubkey = address_cookie.address;
+    update_address_ix.accounts[6].pubkey =
+        get_address_revision_address(&address_cookie.address, address.revision);
+
+    // Act
+    let err = hapi_test
+        .process_transaction(
+            &[update_address_ix],
+            Some(&[&other_reporter_cookie.reporter_keypair]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::InvalidSeeds,
+        "Address of another network must be rejected"
+    );
+
+    let address_account = hapi_test.get_address_account(&address_cookie.address).await;
+    assert_eq!(
+        address_cookie.account, address_account,
+        "Address of another network must not be updated"
+    );
+}
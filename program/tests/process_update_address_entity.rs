@@ -0,0 +1,119 @@
+//#![cfg(feature = "test-bpf")]
+
+use solana_program_test::*;
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_address_entity_updated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    // Act
+    hapi_test
+        .update_address_entity(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &address_cookie,
+            Some(&entity_cookie.name),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let address_account = hapi_test.get_address_account(&address_cookie.address).await;
+    assert_eq!(
+        Some(entity_cookie.address),
+        address_account.entity,
+        "Address must be attached to the entity"
+    );
+}
+
+#[tokio::test]
+async fn test_address_entity_detached() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let address_cookie = hapi_test
+        .with_address(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            5,
+        )
+        .await;
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    hapi_test
+        .update_address_entity(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &address_cookie,
+            Some(&entity_cookie.name),
+        )
+        .await
+        .unwrap();
+
+    // Act
+    hapi_test
+        .update_address_entity(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &address_cookie,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let address_account = hapi_test.get_address_account(&address_cookie.address).await;
+    assert_eq!(
+        None, address_account.entity,
+        "Address must be detached from the entity"
+    );
+}
@@ -0,0 +1,107 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::state::{address_set::get_address_set_address, enums::HapiAccountType},
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_address_set_created_and_updated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    // Act
+    hapi_test
+        .update_address_set(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &case_cookie,
+            &[1; 32],
+            1,
+        )
+        .await
+        .unwrap();
+
+    hapi_test
+        .update_address_set(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &case_cookie,
+            &[2; 32],
+            2,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let address_set_account = hapi_test
+        .get_address_set_account(&get_address_set_address(&case_cookie.address))
+        .await;
+
+    assert_eq!(
+        HapiAccountType::AddressSet,
+        address_set_account.account_type
+    );
+    assert_eq!(case_cookie.id, address_set_account.case_id);
+    assert_eq!([2; 32], address_set_account.root, "Root must be updated");
+    assert_eq!(2, address_set_account.size, "Size must be updated");
+    assert_eq!(
+        reporter_cookie.reporter_keypair.pubkey(),
+        address_set_account.reporter_key
+    );
+}
+
+#[tokio::test]
+async fn test_address_set_update_by_other_reporter_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let other_reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    // Act
+    let err = hapi_test
+        .update_address_set(
+            &other_reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &case_cookie,
+            &[1; 32],
+            1,
+        )
+        .await
+        .err();
+
+    // Assert
+    assert!(err.is_some(), "Only case reporter can update address set");
+    assert!(
+        hapi_test
+            .get_account(&get_address_set_address(&case_cookie.address))
+            .await
+            .is_none(),
+        "Address set must not be created"
+    );
+}
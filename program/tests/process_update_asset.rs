@@ -0,0 +1,186 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::{update_asset, AssetReport},
+        state::enums::Category,
+    },
+    solana_program::program_error::ProgramError,
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_asset_updated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let asset_cookie = hapi_test
+        .with_asset(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            None,
+        )
+        .await;
+
+    let mut asset = asset_cookie.account.clone();
+    asset.risk = 0;
+    asset.category = Category::Safe;
+
+    // Act
+    hapi_test
+        .update_asset(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &asset_cookie,
+            &asset,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let asset_account = hapi_test.get_asset_account(&asset_cookie.address).await;
+    assert_eq!(asset, asset_account, "Asset account must be updated");
+}
+
+#[tokio::test]
+async fn test_asset_update_category_not_in_case_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let asset_cookie = hapi_test
+        .with_asset(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            None,
+        )
+        .await;
+
+    let mut asset = asset_cookie.account.clone();
+    asset.category = Category::Counterfeit;
+
+    // Act
+    let err = hapi_test
+        .update_asset(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &asset_cookie,
+            &asset,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::CategoryNotInCase.into(),
+        "Asset category must be reported in the case"
+    );
+}
+
+#[tokio::test]
+async fn test_asset_update_by_other_community_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let asset_cookie = hapi_test
+        .with_asset(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            None,
+        )
+        .await;
+
+    let other_community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let other_network_cookie = hapi_test
+        .with_network(&authority_keypair, &other_community_cookie)
+        .await;
+    let other_reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &other_community_cookie)
+        .await
+        .unwrap();
+    let other_case_cookie = hapi_test
+        .with_case(&other_reporter_cookie, &other_community_cookie)
+        .await;
+
+    let mut update_asset_ix = update_asset(
+        &other_reporter_cookie.reporter_keypair.pubkey(),
+        &format!(
+            "{}/{}",
+            &other_community_cookie.name, &other_network_cookie.name
+        ),
+        other_case_cookie.id,
+        &AssetReport {
+            asset: asset_cookie.asset,
+            token_id: asset_cookie.token_id,
+            risk: 0,
+            category: asset_cookie.account.category,
+        },
+    )
+    .unwrap();
+    update_asset_ix.accounts[5].pubkey = asset_cookie.address;
+
+    // Act
+    let err = hapi_test
+        .process_transaction(
+            &[update_asset_ix],
+            Some(&[&other_reporter_cookie.reporter_keypair]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::InvalidSeeds,
+        "Asset of another network must be rejected"
+    );
+}
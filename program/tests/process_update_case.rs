@@ -6,7 +6,14 @@ mod program_test;
 
 use program_test::*;
 
-use hapi_core_solana::state::enums::{CaseStatus, Category, CategorySet};
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::update_case,
+        state::enums::{CaseStatus, Category, CategorySet},
+    },
+    solana_sdk::signature::Signer,
+};
 
 #[tokio::test]
 async fn test_case_reported() {
@@ -32,6 +39,7 @@ async fn test_case_reported() {
             &case_cookie,
             &categories,
             CaseStatus::Open,
+            None,
         )
         .await
         .unwrap();
@@ -87,6 +95,7 @@ async fn test_case_reported_with_all_categories() {
             &case_cookie,
             &categories,
             CaseStatus::Open,
+            None,
         )
         .await
         .unwrap();
@@ -123,8 +132,93 @@ async fn test_case_reported_with_all_categories() {
     );
 
     assert_eq!(
-        72,
+        96,
         std::mem::size_of_val(&updated_account),
         "Account size must be correct"
     );
 }
+
+#[tokio::test]
+async fn test_case_account_grown_for_longer_name() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let name = "C".repeat(64);
+    let update_case_ix = update_case(
+        &reporter_cookie.reporter_keypair.pubkey(),
+        &community_cookie.name,
+        case_cookie.id,
+        &name,
+        CaseStatus::Open,
+        &case_cookie.account.categories,
+        None,
+    )
+    .unwrap();
+
+    // Act
+    hapi_test
+        .process_transaction(
+            &[update_case_ix],
+            Some(&[&reporter_cookie.reporter_keypair]),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let updated_account = hapi_test.get_case_account(&case_cookie.address).await;
+
+    assert_eq!(name, updated_account.name, "Case name should be correct");
+}
+
+#[tokio::test]
+async fn test_case_update_with_readonly_case_account() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let mut update_case_ix = update_case(
+        &reporter_cookie.reporter_keypair.pubkey(),
+        &community_cookie.name,
+        case_cookie.id,
+        &case_cookie.name,
+        CaseStatus::Closed,
+        &(Category::Safe as u32),
+        None,
+    )
+    .unwrap();
+    update_case_ix.accounts[3].is_writable = false;
+
+    // Act
+    let err = hapi_test
+        .process_transaction(
+            &[update_case_ix],
+            Some(&[&reporter_cookie.reporter_keypair]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::AccountNotWritable.into(),
+        "Case account must be writable"
+    );
+}
@@ -17,7 +17,7 @@ async fn test_community_updated() {
 
     // Act
     hapi_test
-        .update_community(&authority_keypair, None, &community_cookie)
+        .update_community(&authority_keypair, None, &community_cookie, 86400, 100, 3600)
         .await
         .unwrap();
 
@@ -31,4 +31,13 @@ async fn test_community_updated() {
         updated_account.authority,
         "Community authority should be updated"
     );
+    assert_eq!(
+        86400, updated_account.risk_decay_period,
+        "Community risk decay period should be updated"
+    );
+    assert_eq!(100, updated_account.report_quota, "Community report quota should be updated");
+    assert_eq!(
+        3600, updated_account.report_quota_period,
+        "Community report quota period should be updated"
+    );
 }
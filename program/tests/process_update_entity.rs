@@ -0,0 +1,221 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{error::HapiError, state::enums::Category},
+    solana_program::program_error::ProgramError,
+    solana_program_test::*,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_entity_updated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let parent_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    let mut entity = entity_cookie.account.clone();
+    entity.category = Category::Mixer;
+    entity.risk = 8;
+    entity.website = "https://mixer.example".to_string();
+    entity.parent = Some(parent_cookie.address);
+
+    // Act
+    hapi_test
+        .update_entity(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &entity_cookie,
+            &entity,
+            &[&parent_cookie.name],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let updated_account = hapi_test.get_entity_account(&entity_cookie.address).await;
+    assert_eq!(entity, updated_account, "Entity account must be updated");
+}
+
+#[tokio::test]
+async fn test_entity_self_parent_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .update_entity(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &entity_cookie,
+            &entity_cookie.account,
+            &[&entity_cookie.name],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidEntity.into(),
+        "Entity must not be a parent of itself"
+    );
+}
+
+#[tokio::test]
+async fn test_entity_parent_cycle_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+    let child_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, Some(&entity_cookie))
+        .await
+        .unwrap();
+    let grandchild_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, Some(&child_cookie))
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .update_entity(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &entity_cookie,
+            &entity_cookie.account,
+            &[&grandchild_cookie.name, &child_cookie.name],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::EntityParentCycle.into(),
+        "Entity must not be an ancestor of itself"
+    );
+}
+
+#[tokio::test]
+async fn test_entity_parent_without_ancestor_accounts_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let grandparent_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+    let parent_cookie = hapi_test
+        .with_entity(
+            &reporter_cookie,
+            &community_cookie,
+            Some(&grandparent_cookie),
+        )
+        .await
+        .unwrap();
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .update_entity(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &entity_cookie,
+            &entity_cookie.account,
+            &[&parent_cookie.name],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::NotEnoughAccountKeys,
+        "Parent chain must be walked to its root"
+    );
+}
+
+#[tokio::test]
+async fn test_entity_update_by_another_reporter_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let another_reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let entity_cookie = hapi_test
+        .with_entity(&reporter_cookie, &community_cookie, None)
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .update_entity(
+            &another_reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &entity_cookie,
+            &entity_cookie.account,
+            &[],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::InvalidReporterPermissions.into(),
+        "Only the entity reporter must be able to update it"
+    );
+}
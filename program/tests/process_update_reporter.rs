@@ -1,6 +1,6 @@
 //#![cfg(feature = "test-bpf")]
 
-use solana_program_test::*;
+use {hapi_core_solana::error::HapiError, solana_program_test::*};
 
 mod program_test;
 
@@ -26,6 +26,7 @@ async fn test_reporter_updated() {
         account_type: HapiAccountType::Reporter,
         reporter_type: ReporterType::Inactive,
         name: "Updated".to_string(),
+        ..reporter_cookie.account.clone()
     };
 
     // Act
@@ -47,3 +48,88 @@ async fn test_reporter_updated() {
     assert_eq!(reporter.name, updated_account.name, "Reporter name should be correct");
     assert_eq!(reporter.reporter_type, updated_account.reporter_type, "Reporter type should be correct");
 }
+
+#[tokio::test]
+async fn test_reporter_account_grown_for_longer_name() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+
+    let reporter = Reporter {
+        name: "R".repeat(64),
+        ..reporter_cookie.account.clone()
+    };
+
+    // Act
+    hapi_test
+        .update_reporter(
+            &authority_keypair,
+            &community_cookie,
+            &reporter_cookie,
+            &reporter,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let updated_account = hapi_test
+        .get_reporter_account(&reporter_cookie.address)
+        .await;
+
+    assert_eq!(reporter.name, updated_account.name, "Reporter name should be correct");
+}
+
+#[tokio::test]
+async fn test_expired_reporter_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let reporter = Reporter {
+        active_until: Some(hapi_test.get_clock().await.unix_timestamp),
+        ..reporter_cookie.account.clone()
+    };
+
+    hapi_test
+        .update_reporter(
+            &authority_keypair,
+            &community_cookie,
+            &reporter_cookie,
+            &reporter,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .create_address(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &case_cookie.account.categories,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(err, HapiError::ReporterTermExpired.into(), "Reporter term must be expired");
+}
@@ -0,0 +1,194 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        instruction::{update_transaction, TransactionReport},
+        state::enums::Category,
+    },
+    solana_program::{program_error::ProgramError, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::signature::Signer,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_transaction_updated() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let transaction_cookie = hapi_test
+        .with_transaction(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            7,
+        )
+        .await;
+
+    let mut transaction = transaction_cookie.account.clone();
+    transaction.risk = 3;
+    transaction.category = Category::Safe;
+    transaction.destination = Some(Pubkey::new_unique());
+
+    // Act
+    hapi_test
+        .update_transaction(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &transaction_cookie,
+            &transaction,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let transaction_account = hapi_test
+        .get_transaction_account(&transaction_cookie.address)
+        .await;
+    assert_eq!(
+        transaction, transaction_account,
+        "Transaction account must be updated"
+    );
+}
+
+#[tokio::test]
+async fn test_transaction_update_category_not_in_case_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let transaction_cookie = hapi_test
+        .with_transaction(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            7,
+        )
+        .await;
+
+    let mut transaction = transaction_cookie.account.clone();
+    transaction.category = Category::Ransomware;
+
+    // Act
+    let err = hapi_test
+        .update_transaction(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &network_cookie,
+            &transaction_cookie,
+            &transaction,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::CategoryNotInCase.into(),
+        "Transaction category must be reported in the case"
+    );
+}
+
+#[tokio::test]
+async fn test_transaction_update_by_other_community_rejected() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+    let transaction_cookie = hapi_test
+        .with_transaction(
+            &reporter_cookie,
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            7,
+        )
+        .await;
+
+    let other_community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let other_network_cookie = hapi_test
+        .with_network(&authority_keypair, &other_community_cookie)
+        .await;
+    let other_reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &other_community_cookie)
+        .await
+        .unwrap();
+    let other_case_cookie = hapi_test
+        .with_case(&other_reporter_cookie, &other_community_cookie)
+        .await;
+
+    let transaction = &transaction_cookie.account;
+    let mut update_transaction_ix = update_transaction(
+        &other_reporter_cookie.reporter_keypair.pubkey(),
+        &format!(
+            "{}/{}",
+            &other_community_cookie.name, &other_network_cookie.name
+        ),
+        other_case_cookie.id,
+        &TransactionReport {
+            tx_hash: transaction_cookie.tx_hash,
+            risk: 0,
+            category: transaction.category,
+            source: transaction.source,
+            destination: transaction.destination,
+        },
+    )
+    .unwrap();
+    update_transaction_ix.accounts[5].pubkey = transaction_cookie.address;
+
+    // Act
+    let err = hapi_test
+        .process_transaction(
+            &[update_transaction_ix],
+            Some(&[&other_reporter_cookie.reporter_keypair]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        ProgramError::InvalidSeeds,
+        "Transaction of another network must be rejected"
+    );
+}
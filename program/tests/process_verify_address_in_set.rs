@@ -0,0 +1,127 @@
+//#![cfg(feature = "test-bpf")]
+
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        state::{address_set::get_address_set_leaf, enums::Category},
+        tools::merkle::{get_merkle_proof, get_merkle_root},
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+};
+
+mod program_test;
+
+use program_test::*;
+
+#[tokio::test]
+async fn test_address_in_set_verified() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let addresses: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    let leaves: Vec<_> = addresses
+        .iter()
+        .map(|address| {
+            get_address_set_leaf(&network_cookie.address, address, 8, Category::Sanctions)
+        })
+        .collect();
+
+    hapi_test
+        .update_address_set(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &case_cookie,
+            &get_merkle_root(&leaves),
+            leaves.len() as u64,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let result = hapi_test
+        .verify_address_in_set(
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &addresses[3],
+            8,
+            Category::Sanctions,
+            &get_merkle_proof(&leaves, 3).unwrap(),
+        )
+        .await;
+
+    // Assert
+    assert!(result.is_ok(), "Address must be in the set");
+}
+
+#[tokio::test]
+async fn test_address_in_set_verification_failed() {
+    // Arrange
+    let mut hapi_test = HapiProgramTest::start_new().await;
+    let authority_keypair = hapi_test.create_funded_keypair().await;
+    let community_cookie = hapi_test.with_community(&authority_keypair).await;
+    let network_cookie = hapi_test
+        .with_network(&authority_keypair, &community_cookie)
+        .await;
+    let reporter_cookie = hapi_test
+        .with_reporter(&authority_keypair, &community_cookie)
+        .await
+        .unwrap();
+    let case_cookie = hapi_test
+        .with_case(&reporter_cookie, &community_cookie)
+        .await;
+
+    let addresses: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    let leaves: Vec<_> = addresses
+        .iter()
+        .map(|address| {
+            get_address_set_leaf(&network_cookie.address, address, 8, Category::Sanctions)
+        })
+        .collect();
+
+    hapi_test
+        .update_address_set(
+            &reporter_cookie.reporter_keypair,
+            &community_cookie,
+            &case_cookie,
+            &get_merkle_root(&leaves),
+            leaves.len() as u64,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let err = hapi_test
+        .verify_address_in_set(
+            &community_cookie,
+            &network_cookie,
+            &case_cookie,
+            &addresses[3],
+            2,
+            Category::Sanctions,
+            &get_merkle_proof(&leaves, 3).unwrap(),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        HapiError::AddressNotInSet.into(),
+        "Address must not be in the set with another risk"
+    );
+}
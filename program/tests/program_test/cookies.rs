@@ -1,7 +1,15 @@
 use {
     hapi_core_solana::state::{
-        address::Address, case::Case, community::Community, enums::ReporterType, network::Network,
+        address::Address,
+        address_link::AddressLink,
+        asset::{Asset, TokenId},
+        case::Case,
+        community::Community,
+        entity::Entity,
+        enums::ReporterType,
+        network::Network,
         reporter::Reporter,
+        transaction::{Transaction, TransactionHash},
     },
     solana_program::pubkey::Pubkey,
     solana_sdk::signature::Keypair,
@@ -45,3 +53,31 @@ pub struct AddressCookie {
     pub account: Address,
     pub value: Pubkey,
 }
+
+#[derive(Debug)]
+pub struct EntityCookie {
+    pub address: Pubkey,
+    pub account: Entity,
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct TransactionCookie {
+    pub address: Pubkey,
+    pub account: Transaction,
+    pub tx_hash: TransactionHash,
+}
+
+#[derive(Debug)]
+pub struct AssetCookie {
+    pub address: Pubkey,
+    pub account: Asset,
+    pub asset: Pubkey,
+    pub token_id: Option<TokenId>,
+}
+
+#[derive(Debug)]
+pub struct AddressLinkCookie {
+    pub address: Pubkey,
+    pub account: AddressLink,
+}
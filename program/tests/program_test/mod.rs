@@ -2,6 +2,7 @@ use {
     borsh::BorshDeserialize,
     solana_program::{
         borsh::try_from_slice_unchecked,
+        clock::{Clock, UnixTimestamp},
         instruction::Instruction,
         program_error::ProgramError,
         program_pack::{IsInitialized, Pack},
@@ -20,25 +21,45 @@ use {
 
 use hapi_core_solana::{
     instruction::{
-        create_address, create_case, create_community, create_network, create_reporter,
-        update_case, update_community, update_network, update_reporter,
+        check_address, create_address, create_address_link, create_addresses, create_asset,
+        create_case, create_community, create_entity, create_network, create_reporter,
+        create_transaction, rebuild_address_filter, record_lost_dispute, refresh_address,
+        report_address, rotate_reporter_key, set_reporter_delegate, update_address,
+        update_address_entity, update_address_set, update_asset, update_case, update_community,
+        update_entity, update_network, update_reporter, update_transaction, verify_address_in_set,
+        AddressReport, AssetReport, TransactionReport,
     },
     processor::process,
     state::{
-        address::{get_address_address, Address},
-        case::{get_case_address, Case},
-        community::{get_community_address, Community},
-        enums::{CaseStatus, Category, CategorySet, HapiAccountType, ReporterType},
-        network::{get_network_address, Network},
-        reporter::{get_reporter_address, Reporter},
+        address::{get_address_address, get_address_address_seeds, Address},
+        address_filter::AddressFilter,
+        address_link::{get_address_link_address, get_address_link_address_seeds, AddressLink},
+        address_revision::AddressRevision,
+        address_set::AddressSet,
+        asset::{get_asset_address, get_asset_address_seeds, Asset, TokenId},
+        case::{get_case_address, get_case_address_seeds, Case},
+        community::{get_community_address, get_community_address_seeds, Community},
+        entity::{get_entity_address, get_entity_address_seeds, Entity},
+        enums::{
+            AddressLinkKind, CaseStatus, Category, CategorySet, HapiAccountType, ReporterType,
+        },
+        network::{get_network_address, get_network_address_seeds, Network},
+        reporter::{get_reporter_address, get_reporter_address_seeds, Reporter},
+        transaction::{
+            get_transaction_address, get_transaction_address_seeds, Transaction as HapiTransaction,
+        },
     },
+    tools::{merkle::MerkleHash, name::get_name_hash},
 };
 
 pub mod cookies;
-use self::cookies::{AddressCookie, CaseCookie, CommunityCookie, NetworkCookie, ReporterCookie};
+use self::cookies::{
+    AddressCookie, AddressLinkCookie, AssetCookie, CaseCookie, CommunityCookie, EntityCookie,
+    NetworkCookie, ReporterCookie, TransactionCookie,
+};
 
 pub mod tools;
-use self::tools::map_transaction_error;
+use self::tools::{get_bump_seed, map_transaction_error};
 
 pub struct HapiProgramTest {
     pub context: ProgramTestContext,
@@ -47,16 +68,37 @@ pub struct HapiProgramTest {
     pub next_network_id: u8,
     pub next_reporter_id: u8,
     pub next_case_id: u8,
+    pub next_entity_id: u8,
 }
 
 impl HapiProgramTest {
+    #[allow(dead_code)]
     pub async fn start_new() -> Self {
-        let program_test = ProgramTest::new(
+        Self::start_with_accounts(vec![], None).await
+    }
+
+    /// Starts the program with genesis accounts and optionally limited BPF compute units,
+    /// the BPF program is used then as the native processor doesn't meter compute units
+    #[allow(dead_code)]
+    pub async fn start_with_accounts(
+        accounts: Vec<(Pubkey, Account)>,
+        bpf_compute_max_units: Option<u64>,
+    ) -> Self {
+        let mut program_test = ProgramTest::new(
             "hapi_core_solana",
             hapi_core_solana::id(),
             processor!(process),
         );
 
+        if let Some(max_units) = bpf_compute_max_units {
+            program_test.prefer_bpf(true);
+            program_test.set_bpf_compute_max_units(max_units);
+        }
+
+        for (address, account) in accounts {
+            program_test.add_account(address, account);
+        }
+
         let mut context = program_test.start_with_context().await;
         let rent = context.banks_client.get_rent().await.unwrap();
 
@@ -67,6 +109,7 @@ impl HapiProgramTest {
             next_network_id: 0,
             next_reporter_id: 0,
             next_case_id: 0,
+            next_entity_id: 0,
         }
     }
 
@@ -135,6 +178,10 @@ impl HapiProgramTest {
             authority: authority.pubkey(),
             name: name.clone(),
             next_case_id: 0,
+            risk_decay_period: 0,
+            report_quota: 0,
+            report_quota_period: 0,
+            bump_seed: get_bump_seed(&get_community_address_seeds(&get_name_hash(&name))),
         };
 
         let address = get_community_address(&name);
@@ -168,6 +215,10 @@ impl HapiProgramTest {
         let account = Network {
             account_type: HapiAccountType::Network,
             name: name.clone(),
+            bump_seed: get_bump_seed(&get_network_address_seeds(
+                &community_cookie.address,
+                &get_name_hash(&name),
+            )),
         };
 
         let network_address = get_network_address(&community_cookie.address, &name);
@@ -203,6 +254,7 @@ impl HapiProgramTest {
             &name,
             &reporter_keypair.pubkey(),
             reporter_type.clone(),
+            None,
         )
         .unwrap();
 
@@ -213,6 +265,22 @@ impl HapiProgramTest {
             account_type: HapiAccountType::Reporter,
             name: name.clone(),
             reporter_type: reporter_type.clone(),
+            cases_created: 0,
+            addresses_created: 0,
+            addresses_updated: 0,
+            disputes_lost: 0,
+            last_activity_at: 0,
+            quota_period_start: 0,
+            quota_period_reports: 0,
+            identity: reporter_keypair.pubkey(),
+            key: reporter_keypair.pubkey(),
+            delegate: None,
+            delegate_valid_until: 0,
+            active_until: None,
+            bump_seed: get_bump_seed(&get_reporter_address_seeds(
+                &community_cookie.address,
+                &reporter_keypair.pubkey(),
+            )),
         };
 
         let reporter_address =
@@ -241,7 +309,7 @@ impl HapiProgramTest {
 
         let case_address = get_case_address(&community.address, &case_id.to_le_bytes());
 
-        let categories: CategorySet = Category::Safe as u32;
+        let categories: CategorySet = Category::Safe | Category::WalletService;
 
         let create_case_ix = create_case(
             &reporter.reporter_keypair.pubkey(),
@@ -250,6 +318,7 @@ impl HapiProgramTest {
             &name,
             CaseStatus::Open,
             &categories,
+            None,
         )
         .unwrap();
 
@@ -263,6 +332,11 @@ impl HapiProgramTest {
             reporter_key: reporter.reporter_keypair.pubkey(),
             categories,
             status: CaseStatus::Open,
+            expires_at: None,
+            bump_seed: get_bump_seed(&get_case_address_seeds(
+                &community.address,
+                &case_id.to_le_bytes(),
+            )),
         };
 
         CaseCookie {
@@ -283,17 +357,22 @@ impl HapiProgramTest {
         risk: u8,
     ) -> AddressCookie {
         let value = Pubkey::new_unique();
-        let category = Category::WalletService;
+        let categories: CategorySet = Category::WalletService as u32;
+        let primary_category = Some(Category::WalletService);
 
         let address_address = get_address_address(&network.address, &value);
 
         let create_address_ix = create_address(
             &reporter.reporter_keypair.pubkey(),
             &format!("{}/{}", &community.name, &network.name),
-            &value,
             case.id,
-            risk,
-            category,
+            &AddressReport {
+                address: value,
+                risk,
+                categories,
+                primary_category,
+                expires_at: None,
+            },
         )
         .unwrap();
 
@@ -301,11 +380,20 @@ impl HapiProgramTest {
             .await
             .unwrap();
 
+        let updated_at = self.get_clock().await.unix_timestamp;
+
         let address = Address {
             account_type: HapiAccountType::Address,
             risk,
             case_id: case.id,
-            category,
+            categories,
+            entity: None,
+            primary_category,
+            updated_at,
+            expires_at: None,
+            stale: false,
+            revision: 0,
+            bump_seed: get_bump_seed(&get_address_address_seeds(&network.address, &value)),
         };
 
         AddressCookie {
@@ -315,6 +403,217 @@ impl HapiProgramTest {
         }
     }
 
+    #[allow(dead_code)]
+    pub async fn with_entity(
+        &mut self,
+        reporter: &ReporterCookie,
+        community: &CommunityCookie,
+        parent: Option<&EntityCookie>,
+    ) -> Result<EntityCookie, ProgramError> {
+        let name = format!("Entity #{}", self.next_entity_id).to_string();
+        self.next_entity_id += 1;
+
+        let website = "https://hapi.one".to_string();
+        let category = Category::LowRiskExchange;
+        let risk = 1;
+
+        let entity_address = get_entity_address(&community.address, &name);
+
+        let create_entity_ix = create_entity(
+            &reporter.reporter_keypair.pubkey(),
+            &community.name,
+            &name,
+            &website,
+            category,
+            risk,
+            parent.map(|p| p.name.as_str()),
+        )
+        .unwrap();
+
+        self.process_transaction(&[create_entity_ix], Some(&[&reporter.reporter_keypair]))
+            .await?;
+
+        let entity = Entity {
+            account_type: HapiAccountType::Entity,
+            reporter_key: reporter.reporter_keypair.pubkey(),
+            category,
+            risk,
+            parent: parent.map(|p| p.address),
+            name: name.clone(),
+            website,
+            bump_seed: get_bump_seed(&get_entity_address_seeds(
+                &community.address,
+                &get_name_hash(&name),
+            )),
+        };
+
+        Ok(EntityCookie {
+            address: entity_address,
+            account: entity,
+            name,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_transaction(
+        &mut self,
+        reporter: &ReporterCookie,
+        community: &CommunityCookie,
+        network: &NetworkCookie,
+        case: &CaseCookie,
+        risk: u8,
+    ) -> TransactionCookie {
+        let mut tx_hash = [0u8; 64];
+        tx_hash[..32].copy_from_slice(Pubkey::new_unique().as_ref());
+        tx_hash[32..].copy_from_slice(Pubkey::new_unique().as_ref());
+        let category = Category::WalletService;
+        let source = Some(Pubkey::new_unique());
+
+        let transaction_address = get_transaction_address(&network.address, &tx_hash);
+
+        let create_transaction_ix = create_transaction(
+            &reporter.reporter_keypair.pubkey(),
+            &format!("{}/{}", &community.name, &network.name),
+            case.id,
+            &TransactionReport {
+                tx_hash,
+                risk,
+                category,
+                source,
+                destination: None,
+            },
+        )
+        .unwrap();
+
+        self.process_transaction(
+            &[create_transaction_ix],
+            Some(&[&reporter.reporter_keypair]),
+        )
+        .await
+        .unwrap();
+
+        let transaction = HapiTransaction {
+            account_type: HapiAccountType::Transaction,
+            risk,
+            case_id: case.id,
+            category,
+            source,
+            destination: None,
+            bump_seed: get_bump_seed(&get_transaction_address_seeds(&network.address, &tx_hash)),
+        };
+
+        TransactionCookie {
+            address: transaction_address,
+            account: transaction,
+            tx_hash,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_asset(
+        &mut self,
+        reporter: &ReporterCookie,
+        community: &CommunityCookie,
+        network: &NetworkCookie,
+        case: &CaseCookie,
+        token_id: Option<TokenId>,
+    ) -> AssetCookie {
+        let asset = Pubkey::new_unique();
+        let category = Category::WalletService;
+        let risk = 8;
+
+        let asset_address = get_asset_address(&network.address, &asset, &token_id);
+
+        let create_asset_ix = create_asset(
+            &reporter.reporter_keypair.pubkey(),
+            &format!("{}/{}", &community.name, &network.name),
+            case.id,
+            &AssetReport {
+                asset,
+                token_id,
+                risk,
+                category,
+            },
+        )
+        .unwrap();
+
+        self.process_transaction(&[create_asset_ix], Some(&[&reporter.reporter_keypair]))
+            .await
+            .unwrap();
+
+        let account = Asset {
+            account_type: HapiAccountType::Asset,
+            risk,
+            case_id: case.id,
+            category,
+            bump_seed: get_bump_seed(&get_asset_address_seeds(
+                &network.address,
+                &asset,
+                &token_id,
+            )),
+        };
+
+        AssetCookie {
+            address: asset_address,
+            account,
+            asset,
+            token_id,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_address_link(
+        &mut self,
+        reporter: &ReporterCookie,
+        community: &CommunityCookie,
+        network: &NetworkCookie,
+        source: &Pubkey,
+        destination: &Pubkey,
+        confidence: u8,
+    ) -> Result<AddressLinkCookie, ProgramError> {
+        let kind = AddressLinkKind::FundedBy;
+
+        let address_link_address =
+            get_address_link_address(&network.address, source, destination, kind);
+
+        let create_address_link_ix = create_address_link(
+            &reporter.reporter_keypair.pubkey(),
+            &format!("{}/{}", &community.name, &network.name),
+            source,
+            destination,
+            kind,
+            confidence,
+        )
+        .unwrap();
+
+        self.process_transaction(
+            &[create_address_link_ix],
+            Some(&[&reporter.reporter_keypair]),
+        )
+        .await?;
+
+        let account = AddressLink {
+            account_type: HapiAccountType::AddressLink,
+            network: network.address,
+            source: *source,
+            destination: *destination,
+            kind,
+            confidence,
+            reporter_key: reporter.reporter_keypair.pubkey(),
+            bump_seed: get_bump_seed(&get_address_link_address_seeds(
+                &network.address,
+                source,
+                destination,
+                &[kind as u8],
+            )),
+        };
+
+        Ok(AddressLinkCookie {
+            address: address_link_address,
+            account,
+        })
+    }
+
     #[allow(dead_code)]
     pub async fn get_community_account(&mut self, address: &Pubkey) -> Community {
         self.get_borsh_account::<Community>(address).await
@@ -340,6 +639,46 @@ impl HapiProgramTest {
         self.get_borsh_account::<Address>(address).await
     }
 
+    #[allow(dead_code)]
+    pub async fn get_entity_account(&mut self, address: &Pubkey) -> Entity {
+        self.get_borsh_account::<Entity>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_transaction_account(&mut self, address: &Pubkey) -> HapiTransaction {
+        self.get_borsh_account::<HapiTransaction>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_asset_account(&mut self, address: &Pubkey) -> Asset {
+        self.get_borsh_account::<Asset>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_address_link_account(&mut self, address: &Pubkey) -> AddressLink {
+        self.get_borsh_account::<AddressLink>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_address_revision_account(&mut self, address: &Pubkey) -> AddressRevision {
+        self.get_borsh_account::<AddressRevision>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_address_filter_account(&mut self, address: &Pubkey) -> AddressFilter {
+        self.get_borsh_account::<AddressFilter>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_address_set_account(&mut self, address: &Pubkey) -> AddressSet {
+        self.get_borsh_account::<AddressSet>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_clock(&mut self) -> Clock {
+        self.context.banks_client.get_clock().await.unwrap()
+    }
+
     #[allow(dead_code)]
     async fn get_packed_account<T: Pack + IsInitialized>(&mut self, address: &Pubkey) -> T {
         self.context
@@ -371,9 +710,19 @@ impl HapiProgramTest {
         authority: &Keypair,
         new_authority: Option<&Pubkey>,
         community_cookie: &CommunityCookie,
+        risk_decay_period: u64,
+        report_quota: u64,
+        report_quota_period: u64,
     ) -> Result<(), ProgramError> {
-        let update_community_ix =
-            update_community(&authority.pubkey(), new_authority, &community_cookie.name).unwrap();
+        let update_community_ix = update_community(
+            &authority.pubkey(),
+            new_authority,
+            &community_cookie.name,
+            risk_decay_period,
+            report_quota,
+            report_quota_period,
+        )
+        .unwrap();
 
         self.process_transaction(&[update_community_ix], Some(&[&authority]))
             .await?;
@@ -415,6 +764,7 @@ impl HapiProgramTest {
             &updated_reporter.name,
             &reporter_cookie.reporter_keypair.pubkey(),
             updated_reporter.reporter_type.clone(),
+            updated_reporter.active_until,
         )
         .unwrap();
 
@@ -424,6 +774,98 @@ impl HapiProgramTest {
         Ok(())
     }
 
+    #[allow(dead_code)]
+    pub async fn rotate_reporter_key(
+        &mut self,
+        authority: &Keypair,
+        community_cookie: &CommunityCookie,
+        reporter_cookie: &ReporterCookie,
+    ) -> Result<ReporterCookie, ProgramError> {
+        let new_reporter_keypair = Keypair::new();
+
+        let fund_reporter_ix = system_instruction::transfer(
+            &self.context.payer.pubkey(),
+            &new_reporter_keypair.pubkey(),
+            1000000000,
+        );
+
+        let rotate_reporter_key_ix = rotate_reporter_key(
+            &authority.pubkey(),
+            &community_cookie.name,
+            &reporter_cookie.reporter_keypair.pubkey(),
+            &new_reporter_keypair.pubkey(),
+        )
+        .unwrap();
+
+        self.process_transaction(
+            &[fund_reporter_ix, rotate_reporter_key_ix],
+            Some(&[&authority]),
+        )
+        .await?;
+
+        let account = Reporter {
+            key: new_reporter_keypair.pubkey(),
+            ..reporter_cookie.account.clone()
+        };
+
+        Ok(ReporterCookie {
+            address: get_reporter_address(
+                &community_cookie.address,
+                &new_reporter_keypair.pubkey(),
+            ),
+            community_address: community_cookie.address,
+            reporter_keypair: new_reporter_keypair,
+            reporter_type: reporter_cookie.reporter_type.clone(),
+            account,
+            name: reporter_cookie.name.clone(),
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_reporter_delegate(
+        &mut self,
+        reporter_cookie: &ReporterCookie,
+        community_cookie: &CommunityCookie,
+        delegate: Option<&Pubkey>,
+        valid_until: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        let set_reporter_delegate_ix = set_reporter_delegate(
+            &reporter_cookie.reporter_keypair.pubkey(),
+            &community_cookie.name,
+            delegate,
+            valid_until,
+        )
+        .unwrap();
+
+        self.process_transaction(
+            &[set_reporter_delegate_ix],
+            Some(&[&reporter_cookie.reporter_keypair]),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn record_lost_dispute(
+        &mut self,
+        authority: &Keypair,
+        community_cookie: &CommunityCookie,
+        reporter_cookie: &ReporterCookie,
+    ) -> Result<(), ProgramError> {
+        let record_lost_dispute_ix = record_lost_dispute(
+            &authority.pubkey(),
+            &community_cookie.name,
+            &reporter_cookie.reporter_keypair.pubkey(),
+        )
+        .unwrap();
+
+        self.process_transaction(&[record_lost_dispute_ix], Some(&[&authority]))
+            .await?;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn update_case(
         &mut self,
@@ -432,13 +874,16 @@ impl HapiProgramTest {
         case_cookie: &CaseCookie,
         categories: &CategorySet,
         status: CaseStatus,
+        expires_at: Option<UnixTimestamp>,
     ) -> Result<(), ProgramError> {
         let update_case_ix = update_case(
             &reporter.pubkey(),
             &community_cookie.name,
             case_cookie.id,
+            &case_cookie.name,
             status,
             categories,
+            expires_at,
         )
         .unwrap();
 
@@ -447,4 +892,344 @@ impl HapiProgramTest {
 
         Ok(())
     }
+
+    #[allow(dead_code)]
+    pub async fn check_address(
+        &mut self,
+        network_cookie: &NetworkCookie,
+        address: &Pubkey,
+        max_risk: u8,
+        forbidden_categories: &CategorySet,
+    ) -> Result<(), ProgramError> {
+        let check_address_ix = check_address(
+            &network_cookie.address,
+            address,
+            max_risk,
+            forbidden_categories,
+        )
+        .unwrap();
+
+        self.process_transaction(&[check_address_ix], None).await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_entity(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        entity_cookie: &EntityCookie,
+        updated_entity: &Entity,
+        parent_names: &[&str],
+    ) -> Result<(), ProgramError> {
+        let update_entity_ix = update_entity(
+            &reporter.pubkey(),
+            &community_cookie.name,
+            &entity_cookie.name,
+            &updated_entity.website,
+            updated_entity.category,
+            updated_entity.risk,
+            parent_names,
+        )
+        .unwrap();
+
+        self.process_transaction(&[update_entity_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_address_entity(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        address_cookie: &AddressCookie,
+        entity_name: Option<&str>,
+    ) -> Result<(), ProgramError> {
+        let update_address_entity_ix = update_address_entity(
+            &reporter.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            &address_cookie.value,
+            entity_name,
+        )
+        .unwrap();
+
+        self.process_transaction(&[update_address_entity_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn create_address(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        case_cookie: &CaseCookie,
+        categories: &CategorySet,
+        primary_category: Option<Category>,
+    ) -> Result<(), ProgramError> {
+        let create_address_ix = create_address(
+            &reporter.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            case_cookie.id,
+            &AddressReport {
+                address: Pubkey::new_unique(),
+                risk: 5,
+                categories: *categories,
+                primary_category,
+                expires_at: None,
+            },
+        )
+        .unwrap();
+
+        self.process_transaction(&[create_address_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn create_addresses(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        case_cookie: &CaseCookie,
+        addresses: &[AddressReport],
+    ) -> Result<(), ProgramError> {
+        let create_addresses_ix = create_addresses(
+            &reporter.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            case_cookie.id,
+            addresses,
+        )
+        .unwrap();
+
+        self.process_transaction(&[create_addresses_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_address(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        address_cookie: &AddressCookie,
+        updated_address: &Address,
+    ) -> Result<(), ProgramError> {
+        let revision = self
+            .get_address_account(&address_cookie.address)
+            .await
+            .revision;
+
+        let update_address_ix = update_address(
+            &reporter.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            updated_address.case_id,
+            &AddressReport {
+                address: address_cookie.value,
+                risk: updated_address.risk,
+                categories: updated_address.categories,
+                primary_category: updated_address.primary_category,
+                expires_at: updated_address.expires_at,
+            },
+            revision,
+        )
+        .unwrap();
+
+        self.process_transaction(&[update_address_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn report_address(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        value: &Pubkey,
+        reported_address: &Address,
+        revision: u64,
+    ) -> Result<(), ProgramError> {
+        let report_address_ix = report_address(
+            &reporter.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            reported_address.case_id,
+            &AddressReport {
+                address: *value,
+                risk: reported_address.risk,
+                categories: reported_address.categories,
+                primary_category: reported_address.primary_category,
+                expires_at: reported_address.expires_at,
+            },
+            revision,
+        )
+        .unwrap();
+
+        self.process_transaction(&[report_address_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn refresh_address(
+        &mut self,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        address_cookie: &AddressCookie,
+    ) -> Result<(), ProgramError> {
+        let refresh_address_ix = refresh_address(
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            &address_cookie.value,
+            address_cookie.account.case_id,
+        )
+        .unwrap();
+
+        self.process_transaction(&[refresh_address_ix], None)
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_transaction(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        transaction_cookie: &TransactionCookie,
+        updated_transaction: &HapiTransaction,
+    ) -> Result<(), ProgramError> {
+        let update_transaction_ix = update_transaction(
+            &reporter.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            updated_transaction.case_id,
+            &TransactionReport {
+                tx_hash: transaction_cookie.tx_hash,
+                risk: updated_transaction.risk,
+                category: updated_transaction.category,
+                source: updated_transaction.source,
+                destination: updated_transaction.destination,
+            },
+        )
+        .unwrap();
+
+        self.process_transaction(&[update_transaction_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_asset(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        asset_cookie: &AssetCookie,
+        updated_asset: &Asset,
+    ) -> Result<(), ProgramError> {
+        let update_asset_ix = update_asset(
+            &reporter.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            updated_asset.case_id,
+            &AssetReport {
+                asset: asset_cookie.asset,
+                token_id: asset_cookie.token_id,
+                risk: updated_asset.risk,
+                category: updated_asset.category,
+            },
+        )
+        .unwrap();
+
+        self.process_transaction(&[update_asset_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_address_set(
+        &mut self,
+        reporter: &Keypair,
+        community_cookie: &CommunityCookie,
+        case_cookie: &CaseCookie,
+        root: &MerkleHash,
+        size: u64,
+    ) -> Result<(), ProgramError> {
+        let update_address_set_ix = update_address_set(
+            &reporter.pubkey(),
+            &community_cookie.name,
+            case_cookie.id,
+            root,
+            size,
+        )
+        .unwrap();
+
+        self.process_transaction(&[update_address_set_ix], Some(&[&reporter]))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn verify_address_in_set(
+        &mut self,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        case_cookie: &CaseCookie,
+        address: &Pubkey,
+        risk: u8,
+        category: Category,
+        proof: &[MerkleHash],
+    ) -> Result<(), ProgramError> {
+        let verify_address_in_set_ix = verify_address_in_set(
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            case_cookie.id,
+            address,
+            risk,
+            category,
+            proof,
+        )
+        .unwrap();
+
+        self.process_transaction(&[verify_address_in_set_ix], None)
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn rebuild_address_filter(
+        &mut self,
+        authority: &Keypair,
+        community_cookie: &CommunityCookie,
+        network_cookie: &NetworkCookie,
+        reset: bool,
+        addresses: &[Pubkey],
+    ) -> Result<(), ProgramError> {
+        let rebuild_address_filter_ix = rebuild_address_filter(
+            &authority.pubkey(),
+            &format!("{}/{}", &community_cookie.name, &network_cookie.name),
+            reset,
+            addresses,
+        )
+        .unwrap();
+
+        self.process_transaction(&[rebuild_address_filter_ix], Some(&[&authority]))
+            .await?;
+
+        Ok(())
+    }
 }
@@ -1,6 +1,10 @@
 use {
-    solana_program::{instruction::InstructionError, program_error::ProgramError},
-    solana_sdk::{transaction::TransactionError, transport::TransportError},
+    borsh::BorshSerialize,
+    hapi_core_solana::id,
+    solana_program::{
+        instruction::InstructionError, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    },
+    solana_sdk::{account::Account, transaction::TransactionError, transport::TransportError},
     std::convert::TryFrom,
 };
 
@@ -16,6 +20,26 @@ impl From<ProgramInstructionError> for ProgramError {
     }
 }
 
+/// Returns the bump seed of the HAPI program PDA with the seeds
+#[allow(dead_code)]
+pub fn get_bump_seed(seeds: &[&[u8]]) -> u8 {
+    Pubkey::find_program_address(seeds, &id()).1
+}
+
+/// Returns a rent exempt HAPI program account with the serialized data
+#[allow(dead_code)]
+pub fn get_program_account<T: BorshSerialize>(data: &T) -> Account {
+    let data = data.try_to_vec().unwrap();
+
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
 pub fn map_transaction_error(transport_error: TransportError) -> ProgramError {
     match transport_error {
         TransportError::TransactionError(TransactionError::InstructionError(
@@ -0,0 +1,57 @@
+use {
+    hapi_core_solana::state::{
+        enums::{HapiAccountType, ReporterType},
+        reporter::Reporter,
+    },
+    solana_program::pubkey::Pubkey,
+};
+
+fn reporter(cases_created: u64, addresses_created: u64, disputes_lost: u64) -> Reporter {
+    Reporter {
+        account_type: HapiAccountType::Reporter,
+        reporter_type: ReporterType::Full,
+        name: "Reporter".to_string(),
+        cases_created,
+        addresses_created,
+        addresses_updated: 0,
+        disputes_lost,
+        last_activity_at: 0,
+        quota_period_start: 0,
+        quota_period_reports: 0,
+        identity: Pubkey::default(),
+        key: Pubkey::default(),
+        delegate: None,
+        delegate_valid_until: 0,
+        active_until: None,
+        bump_seed: 0,
+    }
+}
+
+#[test]
+fn test_reputation_without_reports() {
+    assert_eq!(0, reporter(0, 0, 0).reputation());
+    assert_eq!(0, reporter(0, 0, 3).reputation());
+}
+
+#[test]
+fn test_reputation_without_disputes() {
+    assert_eq!(100, reporter(1, 0, 0).reputation());
+    assert_eq!(100, reporter(u64::MAX, u64::MAX, 0).reputation());
+}
+
+#[test]
+fn test_reputation_lowered_by_lost_disputes() {
+    assert_eq!(50, reporter(2, 8, 1).reputation());
+    assert_eq!(90, reporter(10, 80, 1).reputation());
+    assert_eq!(0, reporter(1, 0, u64::MAX).reputation());
+}
+
+#[test]
+fn test_reporter_term() {
+    let mut reporter = reporter(1, 0, 0);
+    assert!(reporter.is_active(i64::MAX), "Reporter without term must stay active");
+
+    reporter.active_until = Some(1000);
+    assert!(reporter.is_active(999), "Reporter must be active within the term");
+    assert!(!reporter.is_active(1000), "Reporter must be inactive after the term");
+}
@@ -0,0 +1,89 @@
+use {
+    hapi_core_solana::{
+        error::HapiError,
+        state::{
+            community::Community,
+            enums::{HapiAccountType, ReporterType},
+            reporter::Reporter,
+        },
+    },
+    solana_program::pubkey::Pubkey,
+};
+
+const DAY: i64 = 86400;
+
+fn community(report_quota: u64, report_quota_period: u64) -> Community {
+    Community {
+        account_type: HapiAccountType::Community,
+        authority: Pubkey::new_unique(),
+        next_case_id: 0,
+        name: "hapi.one".to_string(),
+        risk_decay_period: 0,
+        report_quota,
+        report_quota_period,
+        bump_seed: 0,
+    }
+}
+
+fn reporter() -> Reporter {
+    Reporter {
+        account_type: HapiAccountType::Reporter,
+        reporter_type: ReporterType::Tracer,
+        name: "Reporter".to_string(),
+        cases_created: 0,
+        addresses_created: 0,
+        addresses_updated: 0,
+        disputes_lost: 0,
+        last_activity_at: 0,
+        quota_period_start: 0,
+        quota_period_reports: 0,
+        identity: Pubkey::default(),
+        key: Pubkey::default(),
+        delegate: None,
+        delegate_valid_until: 0,
+        active_until: None,
+        bump_seed: 0,
+    }
+}
+
+#[test]
+fn test_quota_disabled() {
+    let mut reporter = reporter();
+
+    for community in &[community(0, DAY as u64), community(2, 0)] {
+        for _ in 0..3 {
+            reporter.consume_quota(community, 100 * DAY).unwrap();
+        }
+        assert_eq!(None, reporter.remaining_quota(community, 100 * DAY));
+    }
+}
+
+#[test]
+fn test_quota_exceeded_within_period() {
+    let community = community(2, DAY as u64);
+    let mut reporter = reporter();
+
+    assert_eq!(Some(2), reporter.remaining_quota(&community, 100 * DAY));
+    reporter.consume_quota(&community, 100 * DAY).unwrap();
+    reporter.consume_quota(&community, 100 * DAY + 1).unwrap();
+    assert_eq!(Some(0), reporter.remaining_quota(&community, 100 * DAY + 2));
+
+    assert_eq!(
+        Err(HapiError::ReportQuotaExceeded.into()),
+        reporter.consume_quota(&community, 101 * DAY - 1)
+    );
+}
+
+#[test]
+fn test_quota_restored_in_next_period() {
+    let community = community(1, DAY as u64);
+    let mut reporter = reporter();
+
+    reporter.consume_quota(&community, 100 * DAY + 5).unwrap();
+    assert_eq!(Some(0), reporter.remaining_quota(&community, 100 * DAY + 6));
+    assert_eq!(Some(1), reporter.remaining_quota(&community, 101 * DAY));
+
+    reporter.consume_quota(&community, 101 * DAY).unwrap();
+    assert_eq!(101 * DAY, reporter.quota_period_start);
+    assert_eq!(1, reporter.quota_period_reports);
+}